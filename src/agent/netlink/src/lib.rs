@@ -29,6 +29,7 @@ use protobuf::RepeatedField;
 use protocols::types::{IPAddress, IPFamily, Interface, Route};
 use rustjail::errors::*;
 use std::clone::Clone;
+use std::convert::TryFrom;
 use std::default::Default;
 use std::fmt;
 use std::mem;
@@ -1910,7 +1911,7 @@ impl RtnlHandle {
 
         // add new ip addresses in request
         for grpc_addr in &iface.IPAddresses {
-            let rtip = RtIPAddr::from(grpc_addr.clone());
+            let rtip = RtIPAddr::try_from(grpc_addr.clone())?;
             self.add_one_address(&ifinfo, &rtip)?;
         }
 
@@ -2042,7 +2043,7 @@ impl RtnlHandle {
             }
         }
 
-        Err(ErrorKind::ErrorCode("no name".to_string()).into())
+        Err(ErrorKind::NotFound(format!("name of link {}", index)).into())
     }
 
     pub fn list_routes(&mut self) -> Result<Vec<Route>> {
@@ -2452,7 +2453,7 @@ impl RtnlHandle {
 
         for grpcroute in rt {
             if grpcroute.gateway.as_str() == "" {
-                let r = RtRoute::from(grpcroute.clone());
+                let r = RtRoute::try_from(grpcroute.clone())?;
                 if r.index == -1 {
                     continue;
                 }
@@ -2462,7 +2463,7 @@ impl RtnlHandle {
 
         for grpcroute in rt {
             if grpcroute.gateway.as_str() != "" {
-                let r = RtRoute::from(grpcroute.clone());
+                let r = RtRoute::try_from(grpcroute.clone())?;
                 if r.index == -1 {
                     continue;
                 }
@@ -2770,12 +2771,14 @@ fn parse_cider(s: &str) -> Result<(Vec<u8>, u8)> {
     Ok((parse_ipaddr(addr.as_str())?, mask))
 }
 
-impl From<Route> for RtRoute {
-    fn from(r: Route) -> Self {
+impl TryFrom<Route> for RtRoute {
+    type Error = Error;
+
+    fn try_from(r: Route) -> Result<Self> {
         // only handle ipv4
 
         let index = {
-            let mut rh = RtnlHandle::new(NETLINK_ROUTE, 0).unwrap();
+            let mut rh = RtnlHandle::new(NETLINK_ROUTE, 0)?;
             match rh.find_link_by_name(r.device.as_str()) {
                 Ok(ifi) => ifi.ifi_index,
                 Err(_) => -1,
@@ -2785,21 +2788,25 @@ impl From<Route> for RtRoute {
         let (dest, dst_len) = if r.dest.is_empty() {
             (Some(vec![0 as u8; 4]), 0)
         } else {
-            let (dst, mask) = parse_cider(r.dest.as_str()).unwrap();
+            let (dst, mask) = parse_cider(r.dest.as_str())
+                .chain_err(|| ErrorKind::InvalidArgument(format!("route dest {}", r.dest)))?;
             (Some(dst), mask)
         };
 
         let (source, src_len) = if r.source.is_empty() {
             (None, 0)
         } else {
-            let (src, mask) = parse_cider(r.source.as_str()).unwrap();
+            let (src, mask) = parse_cider(r.source.as_str())
+                .chain_err(|| ErrorKind::InvalidArgument(format!("route source {}", r.source)))?;
             (Some(src), mask)
         };
 
         let gateway = if r.gateway.is_empty() {
             None
         } else {
-            Some(parse_ipaddr(r.gateway.as_str()).unwrap())
+            let gw = parse_ipaddr(r.gateway.as_str())
+                .chain_err(|| ErrorKind::InvalidArgument(format!("route gateway {}", r.gateway)))?;
+            Some(gw)
         };
 
         /*
@@ -2809,7 +2816,7 @@ impl From<Route> for RtRoute {
                     (tdest, tdst_len)
                 };
         */
-        Self {
+        Ok(Self {
             dest,
             source,
             src_len,
@@ -2818,7 +2825,7 @@ impl From<Route> for RtRoute {
             gateway,
             scope: r.scope as u8,
             protocol: RTPROTO_UNSPEC,
-        }
+        })
     }
 }
 
@@ -2828,23 +2835,27 @@ pub struct RtIPAddr {
     pub addr: Vec<u8>,
 }
 
-impl From<IPAddress> for RtIPAddr {
-    fn from(ipi: IPAddress) -> Self {
+impl TryFrom<IPAddress> for RtIPAddr {
+    type Error = Error;
+
+    fn try_from(ipi: IPAddress) -> Result<Self> {
         let ip_family = if ipi.family == IPFamily::v4 {
             libc::AF_INET
         } else {
             libc::AF_INET6
         } as __u8;
 
-        let ip_mask = scan_fmt!(ipi.mask.as_str(), "{}", u8).unwrap();
+        let ip_mask = scan_fmt!(ipi.mask.as_str(), "{}", u8)
+            .chain_err(|| ErrorKind::InvalidArgument(format!("address mask {}", ipi.mask)))?;
 
-        let addr = parse_ipaddr(ipi.address.as_ref()).unwrap();
+        let addr = parse_ipaddr(ipi.address.as_ref())
+            .chain_err(|| ErrorKind::InvalidArgument(format!("address {}", ipi.address)))?;
 
-        Self {
+        Ok(Self {
             ip_family,
             ip_mask,
             addr,
-        }
+        })
     }
 }
 
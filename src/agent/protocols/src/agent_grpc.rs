@@ -32,7 +32,7 @@ const METHOD_AGENT_SERVICE_START_CONTAINER: ::grpcio::Method<super::agent::Start
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
-const METHOD_AGENT_SERVICE_REMOVE_CONTAINER: ::grpcio::Method<super::agent::RemoveContainerRequest, super::empty::Empty> = ::grpcio::Method {
+const METHOD_AGENT_SERVICE_REMOVE_CONTAINER: ::grpcio::Method<super::agent::RemoveContainerRequest, super::agent::RemoveContainerResponse> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/RemoveContainer",
     req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
@@ -95,6 +95,20 @@ const METHOD_AGENT_SERVICE_RESUME_CONTAINER: ::grpcio::Method<super::agent::Resu
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_CHECKPOINT_CONTAINER: ::grpcio::Method<super::agent::CheckpointContainerRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/CheckpointContainer",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_RESTORE_CONTAINER: ::grpcio::Method<super::agent::RestoreContainerRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/RestoreContainer",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_WRITE_STDIN: ::grpcio::Method<super::agent::WriteStreamRequest, super::agent::WriteStreamResponse> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/WriteStdin",
@@ -130,6 +144,27 @@ const METHOD_AGENT_SERVICE_TTY_WIN_RESIZE: ::grpcio::Method<super::agent::TtyWin
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_STREAM_STDOUT: ::grpcio::Method<super::agent::ReadStreamRequest, super::agent::ReadStreamResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/grpc.AgentService/StreamStdout",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_STREAM_STDERR: ::grpcio::Method<super::agent::ReadStreamRequest, super::agent::ReadStreamResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/grpc.AgentService/StreamStderr",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_STREAM_STDIN: ::grpcio::Method<super::agent::WriteStreamRequest, super::agent::WriteStreamResponse> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ClientStreaming,
+    name: "/grpc.AgentService/StreamStdin",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_UPDATE_INTERFACE: ::grpcio::Method<super::agent::UpdateInterfaceRequest, super::types::Interface> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/UpdateInterface",
@@ -158,6 +193,13 @@ const METHOD_AGENT_SERVICE_LIST_ROUTES: ::grpcio::Method<super::agent::ListRoute
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_UPDATE_DNS: ::grpcio::Method<super::agent::UpdateDNSRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/UpdateDNS",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_START_TRACING: ::grpcio::Method<super::agent::StartTracingRequest, super::empty::Empty> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/StartTracing",
@@ -186,6 +228,13 @@ const METHOD_AGENT_SERVICE_DESTROY_SANDBOX: ::grpcio::Method<super::agent::Destr
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_UPDATE_HOSTNAME: ::grpcio::Method<super::agent::UpdateHostnameRequest, super::empty::Empty> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/UpdateHostname",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_AGENT_SERVICE_ONLINE_CPU_MEM: ::grpcio::Method<super::agent::OnlineCPUMemRequest, super::empty::Empty> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/grpc.AgentService/OnlineCPUMem",
@@ -228,6 +277,20 @@ const METHOD_AGENT_SERVICE_COPY_FILE: ::grpcio::Method<super::agent::CopyFileReq
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_AGENT_SERVICE_GET_OOM_EVENT: ::grpcio::Method<super::agent::GetOOMEventRequest, super::agent::OOMEvent> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/grpc.AgentService/GetOOMEvent",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_AGENT_SERVICE_SUBSCRIBE_MEMORY_PRESSURE: ::grpcio::Method<super::agent::MemoryPressureRequest, super::agent::MemoryPressureEvent> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/grpc.AgentService/SubscribeMemoryPressure",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 #[derive(Clone)]
 pub struct AgentServiceClient {
     client: ::grpcio::Client,
@@ -272,19 +335,19 @@ impl AgentServiceClient {
         self.start_container_async_opt(req, ::grpcio::CallOption::default())
     }
 
-    pub fn remove_container_opt(&self, req: &super::agent::RemoveContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+    pub fn remove_container_opt(&self, req: &super::agent::RemoveContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::RemoveContainerResponse> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_REMOVE_CONTAINER, req, opt)
     }
 
-    pub fn remove_container(&self, req: &super::agent::RemoveContainerRequest) -> ::grpcio::Result<super::empty::Empty> {
+    pub fn remove_container(&self, req: &super::agent::RemoveContainerRequest) -> ::grpcio::Result<super::agent::RemoveContainerResponse> {
         self.remove_container_opt(req, ::grpcio::CallOption::default())
     }
 
-    pub fn remove_container_async_opt(&self, req: &super::agent::RemoveContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+    pub fn remove_container_async_opt(&self, req: &super::agent::RemoveContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::RemoveContainerResponse>> {
         self.client.unary_call_async(&METHOD_AGENT_SERVICE_REMOVE_CONTAINER, req, opt)
     }
 
-    pub fn remove_container_async(&self, req: &super::agent::RemoveContainerRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+    pub fn remove_container_async(&self, req: &super::agent::RemoveContainerRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::RemoveContainerResponse>> {
         self.remove_container_async_opt(req, ::grpcio::CallOption::default())
     }
 
@@ -416,6 +479,38 @@ impl AgentServiceClient {
         self.resume_container_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn checkpoint_container_opt(&self, req: &super::agent::CheckpointContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_CHECKPOINT_CONTAINER, req, opt)
+    }
+
+    pub fn checkpoint_container(&self, req: &super::agent::CheckpointContainerRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.checkpoint_container_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn checkpoint_container_async_opt(&self, req: &super::agent::CheckpointContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_CHECKPOINT_CONTAINER, req, opt)
+    }
+
+    pub fn checkpoint_container_async(&self, req: &super::agent::CheckpointContainerRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.checkpoint_container_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn restore_container_opt(&self, req: &super::agent::RestoreContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_RESTORE_CONTAINER, req, opt)
+    }
+
+    pub fn restore_container(&self, req: &super::agent::RestoreContainerRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.restore_container_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn restore_container_async_opt(&self, req: &super::agent::RestoreContainerRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_RESTORE_CONTAINER, req, opt)
+    }
+
+    pub fn restore_container_async(&self, req: &super::agent::RestoreContainerRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.restore_container_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn write_stdin_opt(&self, req: &super::agent::WriteStreamRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::WriteStreamResponse> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_WRITE_STDIN, req, opt)
     }
@@ -496,6 +591,30 @@ impl AgentServiceClient {
         self.tty_win_resize_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn stream_stdout_opt(&self, req: &super::agent::ReadStreamRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::ReadStreamResponse>> {
+        self.client.server_streaming(&METHOD_AGENT_SERVICE_STREAM_STDOUT, req, opt)
+    }
+
+    pub fn stream_stdout(&self, req: &super::agent::ReadStreamRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::ReadStreamResponse>> {
+        self.stream_stdout_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn stream_stderr_opt(&self, req: &super::agent::ReadStreamRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::ReadStreamResponse>> {
+        self.client.server_streaming(&METHOD_AGENT_SERVICE_STREAM_STDERR, req, opt)
+    }
+
+    pub fn stream_stderr(&self, req: &super::agent::ReadStreamRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::ReadStreamResponse>> {
+        self.stream_stderr_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn stream_stdin_opt(&self, opt: ::grpcio::CallOption) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::agent::WriteStreamRequest>, ::grpcio::ClientCStreamReceiver<super::agent::WriteStreamResponse>)> {
+        self.client.client_streaming(&METHOD_AGENT_SERVICE_STREAM_STDIN, opt)
+    }
+
+    pub fn stream_stdin(&self) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::agent::WriteStreamRequest>, ::grpcio::ClientCStreamReceiver<super::agent::WriteStreamResponse>)> {
+        self.stream_stdin_opt(::grpcio::CallOption::default())
+    }
+
     pub fn update_interface_opt(&self, req: &super::agent::UpdateInterfaceRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::types::Interface> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_UPDATE_INTERFACE, req, opt)
     }
@@ -560,6 +679,22 @@ impl AgentServiceClient {
         self.list_routes_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn update_dns_opt(&self, req: &super::agent::UpdateDNSRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_UPDATE_DNS, req, opt)
+    }
+
+    pub fn update_dns(&self, req: &super::agent::UpdateDNSRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.update_dns_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn update_dns_async_opt(&self, req: &super::agent::UpdateDNSRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_UPDATE_DNS, req, opt)
+    }
+
+    pub fn update_dns_async(&self, req: &super::agent::UpdateDNSRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.update_dns_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn start_tracing_opt(&self, req: &super::agent::StartTracingRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_START_TRACING, req, opt)
     }
@@ -624,6 +759,22 @@ impl AgentServiceClient {
         self.destroy_sandbox_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn update_hostname_opt(&self, req: &super::agent::UpdateHostnameRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_UPDATE_HOSTNAME, req, opt)
+    }
+
+    pub fn update_hostname(&self, req: &super::agent::UpdateHostnameRequest) -> ::grpcio::Result<super::empty::Empty> {
+        self.update_hostname_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn update_hostname_async_opt(&self, req: &super::agent::UpdateHostnameRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_UPDATE_HOSTNAME, req, opt)
+    }
+
+    pub fn update_hostname_async(&self, req: &super::agent::UpdateHostnameRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
+        self.update_hostname_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn online_cpu_mem_opt(&self, req: &super::agent::OnlineCPUMemRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::empty::Empty> {
         self.client.unary_call(&METHOD_AGENT_SERVICE_ONLINE_CPU_MEM, req, opt)
     }
@@ -719,6 +870,30 @@ impl AgentServiceClient {
     pub fn copy_file_async(&self, req: &super::agent::CopyFileRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::empty::Empty>> {
         self.copy_file_async_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn get_oom_event_opt(&self, req: &super::agent::GetOOMEventRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::agent::OOMEvent> {
+        self.client.unary_call(&METHOD_AGENT_SERVICE_GET_OOM_EVENT, req, opt)
+    }
+
+    pub fn get_oom_event(&self, req: &super::agent::GetOOMEventRequest) -> ::grpcio::Result<super::agent::OOMEvent> {
+        self.get_oom_event_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_oom_event_async_opt(&self, req: &super::agent::GetOOMEventRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::OOMEvent>> {
+        self.client.unary_call_async(&METHOD_AGENT_SERVICE_GET_OOM_EVENT, req, opt)
+    }
+
+    pub fn get_oom_event_async(&self, req: &super::agent::GetOOMEventRequest) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::agent::OOMEvent>> {
+        self.get_oom_event_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn subscribe_memory_pressure_opt(&self, req: &super::agent::MemoryPressureRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::MemoryPressureEvent>> {
+        self.client.server_streaming(&METHOD_AGENT_SERVICE_SUBSCRIBE_MEMORY_PRESSURE, req, opt)
+    }
+
+    pub fn subscribe_memory_pressure(&self, req: &super::agent::MemoryPressureRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::agent::MemoryPressureEvent>> {
+        self.subscribe_memory_pressure_opt(req, ::grpcio::CallOption::default())
+    }
     pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
         self.client.spawn(f)
     }
@@ -727,7 +902,7 @@ impl AgentServiceClient {
 pub trait AgentService {
     fn create_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CreateContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn start_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StartContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
-    fn remove_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::RemoveContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn remove_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::RemoveContainerRequest, sink: ::grpcio::UnarySink<super::agent::RemoveContainerResponse>);
     fn exec_process(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ExecProcessRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn signal_process(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::SignalProcessRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn wait_process(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::WaitProcessRequest, sink: ::grpcio::UnarySink<super::agent::WaitProcessResponse>);
@@ -736,25 +911,34 @@ pub trait AgentService {
     fn stats_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StatsContainerRequest, sink: ::grpcio::UnarySink<super::agent::StatsContainerResponse>);
     fn pause_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::PauseContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn resume_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ResumeContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn checkpoint_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CheckpointContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn restore_container(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::RestoreContainerRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn write_stdin(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::WriteStreamRequest, sink: ::grpcio::UnarySink<super::agent::WriteStreamResponse>);
     fn read_stdout(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadStreamRequest, sink: ::grpcio::UnarySink<super::agent::ReadStreamResponse>);
     fn read_stderr(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadStreamRequest, sink: ::grpcio::UnarySink<super::agent::ReadStreamResponse>);
     fn close_stdin(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CloseStdinRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn tty_win_resize(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::TtyWinResizeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn stream_stdout(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadStreamRequest, sink: ::grpcio::ServerStreamingSink<super::agent::ReadStreamResponse>);
+    fn stream_stderr(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReadStreamRequest, sink: ::grpcio::ServerStreamingSink<super::agent::ReadStreamResponse>);
+    fn stream_stdin(&mut self, ctx: ::grpcio::RpcContext, stream: ::grpcio::RequestStream<super::agent::WriteStreamRequest>, sink: ::grpcio::ClientStreamingSink<super::agent::WriteStreamResponse>);
     fn update_interface(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateInterfaceRequest, sink: ::grpcio::UnarySink<super::types::Interface>);
     fn update_routes(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateRoutesRequest, sink: ::grpcio::UnarySink<super::agent::Routes>);
     fn list_interfaces(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ListInterfacesRequest, sink: ::grpcio::UnarySink<super::agent::Interfaces>);
     fn list_routes(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ListRoutesRequest, sink: ::grpcio::UnarySink<super::agent::Routes>);
+    fn update_dns(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateDNSRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn start_tracing(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StartTracingRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn stop_tracing(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::StopTracingRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn create_sandbox(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CreateSandboxRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn destroy_sandbox(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::DestroySandboxRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn update_hostname(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::UpdateHostnameRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn online_cpu_mem(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::OnlineCPUMemRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn reseed_random_dev(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::ReseedRandomDevRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn get_guest_details(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GuestDetailsRequest, sink: ::grpcio::UnarySink<super::agent::GuestDetailsResponse>);
     fn mem_hotplug_by_probe(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::MemHotplugByProbeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn set_guest_date_time(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::SetGuestDateTimeRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
     fn copy_file(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::CopyFileRequest, sink: ::grpcio::UnarySink<super::empty::Empty>);
+    fn get_oom_event(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::GetOOMEventRequest, sink: ::grpcio::UnarySink<super::agent::OOMEvent>);
+    fn subscribe_memory_pressure(&mut self, ctx: ::grpcio::RpcContext, req: super::agent::MemoryPressureRequest, sink: ::grpcio::ServerStreamingSink<super::agent::MemoryPressureEvent>);
 }
 
 pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -804,6 +988,14 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.resume_container(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_CHECKPOINT_CONTAINER, move |ctx, req, resp| {
+        instance.checkpoint_container(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_RESTORE_CONTAINER, move |ctx, req, resp| {
+        instance.restore_container(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_WRITE_STDIN, move |ctx, req, resp| {
         instance.write_stdin(ctx, req, resp)
     });
@@ -824,6 +1016,18 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.tty_win_resize(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_AGENT_SERVICE_STREAM_STDOUT, move |ctx, req, resp| {
+        instance.stream_stdout(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_AGENT_SERVICE_STREAM_STDERR, move |ctx, req, resp| {
+        instance.stream_stderr(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_client_streaming_handler(&METHOD_AGENT_SERVICE_STREAM_STDIN, move |ctx, req, resp| {
+        instance.stream_stdin(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_UPDATE_INTERFACE, move |ctx, req, resp| {
         instance.update_interface(ctx, req, resp)
     });
@@ -840,6 +1044,10 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.list_routes(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_UPDATE_DNS, move |ctx, req, resp| {
+        instance.update_dns(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_START_TRACING, move |ctx, req, resp| {
         instance.start_tracing(ctx, req, resp)
     });
@@ -856,6 +1064,10 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
         instance.destroy_sandbox(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_UPDATE_HOSTNAME, move |ctx, req, resp| {
+        instance.update_hostname(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_ONLINE_CPU_MEM, move |ctx, req, resp| {
         instance.online_cpu_mem(ctx, req, resp)
     });
@@ -879,5 +1091,13 @@ pub fn create_agent_service<S: AgentService + Send + Clone + 'static>(s: S) -> :
     builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_COPY_FILE, move |ctx, req, resp| {
         instance.copy_file(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_AGENT_SERVICE_GET_OOM_EVENT, move |ctx, req, resp| {
+        instance.get_oom_event(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_AGENT_SERVICE_SUBSCRIBE_MEMORY_PRESSURE, move |ctx, req, resp| {
+        instance.subscribe_memory_pressure(ctx, req, resp)
+    });
     builder.build()
 }
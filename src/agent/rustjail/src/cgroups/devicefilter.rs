@@ -0,0 +1,566 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// cgroup v2 has no devices.allow file: the device accesses of a cgroup
+// are checked by the BPF_PROG_TYPE_CGROUP_DEVICE programs attached to it.
+// The device rules of a container are compiled into such a program, the
+// last rule matching an access deciding of it as with cgroup v1, and the
+// accesses no rule matches being denied.
+
+use crate::cgroups::fs::DEFAULT_ALLOWED_DEVICES;
+use crate::container::DEFAULT_DEVICES;
+use crate::errors::*;
+use nix::errno::Errno;
+use nix::fcntl::{self, OFlag};
+use nix::sys::stat::Mode;
+use nix::unistd;
+use protobuf::{CachedSize, UnknownFields};
+use protocols::oci::LinuxDeviceCgroup;
+use std::ffi::CString;
+use std::os::unix::io::RawFd;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "cgroups"))
+    };
+}
+
+// bpf commands
+const BPF_PROG_LOAD: libc::c_long = 5;
+const BPF_PROG_ATTACH: libc::c_long = 8;
+const BPF_PROG_DETACH: libc::c_long = 9;
+const BPF_PROG_GET_FD_BY_ID: libc::c_long = 13;
+const BPF_PROG_QUERY: libc::c_long = 16;
+
+const BPF_PROG_TYPE_CGROUP_DEVICE: u32 = 15;
+const BPF_CGROUP_DEVICE: u32 = 6;
+const BPF_F_ALLOW_MULTI: u32 = 1 << 1;
+const BPF_F_REPLACE: u32 = 1 << 2;
+
+// most programs attached to a cgroup
+const BPF_CGROUP_MAX_PROGS: usize = 64;
+
+// struct bpf_cgroup_dev_ctx, access_type holding the access in its high
+// half and the device type in its low one
+const BPF_DEVCG_DEV_BLOCK: i32 = 1;
+const BPF_DEVCG_DEV_CHAR: i32 = 2;
+const BPF_DEVCG_ACC_MKNOD: i32 = 1;
+const BPF_DEVCG_ACC_READ: i32 = 2;
+const BPF_DEVCG_ACC_WRITE: i32 = 4;
+const ACC_ALL: i32 = BPF_DEVCG_ACC_MKNOD | BPF_DEVCG_ACC_READ | BPF_DEVCG_ACC_WRITE;
+
+const CTX_ACCESS_TYPE: i16 = 0;
+const CTX_MAJOR: i16 = 4;
+const CTX_MINOR: i16 = 8;
+
+// instruction classes and operations
+const BPF_LDX: u8 = 0x01;
+const BPF_JMP: u8 = 0x05;
+const BPF_ALU64: u8 = 0x07;
+const BPF_W: u8 = 0x00;
+const BPF_MEM: u8 = 0x60;
+const BPF_K: u8 = 0x00;
+const BPF_X: u8 = 0x08;
+const BPF_AND: u8 = 0x50;
+const BPF_RSH: u8 = 0x70;
+const BPF_MOV: u8 = 0xb0;
+const BPF_JEQ: u8 = 0x10;
+const BPF_JNE: u8 = 0x50;
+const BPF_EXIT: u8 = 0x90;
+
+// registers of the program: r0 is the verdict, r1 the context and then
+// a scratch register once the context is read
+const R0: u8 = 0;
+const R1: u8 = 1;
+const R_TYPE: u8 = 2;
+const R_ACCESS: u8 = 3;
+const R_MAJOR: u8 = 4;
+const R_MINOR: u8 = 5;
+
+const LICENSE: &str = "Apache";
+
+// struct bpf_insn
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BpfInsn {
+    pub code: u8,
+    // the destination register in the low nibble, the source one in the
+    // high nibble
+    pub regs: u8,
+    pub off: i16,
+    pub imm: i32,
+}
+
+impl BpfInsn {
+    fn new(code: u8, dst: u8, src: u8, off: i16, imm: i32) -> Self {
+        BpfInsn {
+            code,
+            regs: (src << 4) | dst,
+            off,
+            imm,
+        }
+    }
+
+    pub fn dst(&self) -> usize {
+        (self.regs & 0xf) as usize
+    }
+
+    pub fn src(&self) -> usize {
+        (self.regs >> 4) as usize
+    }
+}
+
+fn load_ctx(dst: u8, off: i16) -> BpfInsn {
+    BpfInsn::new(BPF_LDX | BPF_W | BPF_MEM, dst, R1, off, 0)
+}
+
+fn alu(op: u8, dst: u8, imm: i32) -> BpfInsn {
+    BpfInsn::new(BPF_ALU64 | op | BPF_K, dst, 0, 0, imm)
+}
+
+fn mov_reg(dst: u8, src: u8) -> BpfInsn {
+    BpfInsn::new(BPF_ALU64 | BPF_MOV | BPF_X, dst, src, 0, 0)
+}
+
+// the offset of a jump is patched once its rule is compiled
+fn jump(op: u8, dst: u8, imm: i32) -> BpfInsn {
+    BpfInsn::new(BPF_JMP | op | BPF_K, dst, 0, 0, imm)
+}
+
+fn exit() -> BpfInsn {
+    BpfInsn::new(BPF_JMP | BPF_EXIT, 0, 0, 0, 0)
+}
+
+// container_rules returns the device rules of a container, the ones of
+// its spec being followed by the devices every container has.
+pub fn container_rules(devices: &[LinuxDeviceCgroup]) -> Vec<LinuxDeviceCgroup> {
+    let mut rules = devices.to_vec();
+
+    for d in DEFAULT_DEVICES.iter() {
+        rules.push(LinuxDeviceCgroup {
+            Allow: true,
+            Type: d.Type.clone(),
+            Major: d.Major,
+            Minor: d.Minor,
+            Access: "rwm".to_string(),
+            unknown_fields: UnknownFields::default(),
+            cached_size: CachedSize::default(),
+        });
+    }
+
+    rules.extend(DEFAULT_ALLOWED_DEVICES.iter().cloned());
+
+    rules
+}
+
+fn device_type(t: &str) -> Result<Option<i32>> {
+    match t {
+        "" | "a" => Ok(None),
+        "c" | "u" => Ok(Some(BPF_DEVCG_DEV_CHAR)),
+        "b" => Ok(Some(BPF_DEVCG_DEV_BLOCK)),
+        _ => Err(ErrorKind::InvalidArgument(format!("invalid device type {}", t)).into()),
+    }
+}
+
+fn device_access(access: &str) -> Result<i32> {
+    if access.is_empty() {
+        return Ok(ACC_ALL);
+    }
+
+    let mut acc = 0;
+    for c in access.chars() {
+        acc |= match c {
+            'r' => BPF_DEVCG_ACC_READ,
+            'w' => BPF_DEVCG_ACC_WRITE,
+            'm' => BPF_DEVCG_ACC_MKNOD,
+            _ => {
+                return Err(
+                    ErrorKind::InvalidArgument(format!("invalid device access {}", access)).into(),
+                )
+            }
+        };
+    }
+
+    Ok(acc)
+}
+
+// compile_rule returns the instructions deciding of the accesses a rule
+// matches, and jumping past them otherwise. An allow rule matches the
+// accesses it fully allows, a deny rule the ones it partly denies.
+fn compile_rule(rule: &LinuxDeviceCgroup) -> Result<Vec<BpfInsn>> {
+    let mut insns = Vec::new();
+
+    if let Some(t) = device_type(rule.Type.as_str())? {
+        insns.push(jump(BPF_JNE, R_TYPE, t));
+    }
+
+    let access = device_access(rule.Access.as_str())?;
+    if access != ACC_ALL {
+        insns.push(mov_reg(R1, R_ACCESS));
+        if rule.Allow {
+            insns.push(alu(BPF_AND, R1, !access & ACC_ALL));
+            insns.push(jump(BPF_JNE, R1, 0));
+        } else {
+            insns.push(alu(BPF_AND, R1, access));
+            insns.push(jump(BPF_JEQ, R1, 0));
+        }
+    }
+
+    if rule.Major >= 0 {
+        insns.push(jump(BPF_JNE, R_MAJOR, rule.Major as i32));
+    }
+    if rule.Minor >= 0 {
+        insns.push(jump(BPF_JNE, R_MINOR, rule.Minor as i32));
+    }
+
+    insns.push(BpfInsn::new(
+        BPF_ALU64 | BPF_MOV | BPF_K,
+        R0,
+        0,
+        0,
+        rule.Allow as i32,
+    ));
+    insns.push(exit());
+
+    let len = insns.len();
+    for (i, insn) in insns.iter_mut().enumerate() {
+        if insn.code & 0x07 == BPF_JMP && insn.code != BPF_JMP | BPF_EXIT {
+            insn.off = (len - i - 1) as i16;
+        }
+    }
+
+    Ok(insns)
+}
+
+// compile returns the program deciding of the device accesses by rules.
+pub fn compile(rules: &[LinuxDeviceCgroup]) -> Result<Vec<BpfInsn>> {
+    let mut insns = vec![
+        load_ctx(R_TYPE, CTX_ACCESS_TYPE),
+        alu(BPF_AND, R_TYPE, 0xffff),
+        load_ctx(R_ACCESS, CTX_ACCESS_TYPE),
+        alu(BPF_RSH, R_ACCESS, 16),
+        load_ctx(R_MAJOR, CTX_MAJOR),
+        load_ctx(R_MINOR, CTX_MINOR),
+    ];
+
+    // the last matching rule wins
+    for rule in rules.iter().rev() {
+        insns.extend(compile_rule(rule)?);
+    }
+
+    insns.push(BpfInsn::new(BPF_ALU64 | BPF_MOV | BPF_K, R0, 0, 0, 0));
+    insns.push(exit());
+
+    Ok(insns)
+}
+
+// union bpf_attr, for BPF_PROG_LOAD
+#[repr(C)]
+#[derive(Default)]
+struct ProgLoadAttr {
+    prog_type: u32,
+    insn_cnt: u32,
+    insns: u64,
+    license: u64,
+    log_level: u32,
+    log_size: u32,
+    log_buf: u64,
+    kern_version: u32,
+    prog_flags: u32,
+    prog_name: [u8; 16],
+    prog_ifindex: u32,
+    expected_attach_type: u32,
+}
+
+// union bpf_attr, for BPF_PROG_ATTACH and BPF_PROG_DETACH
+#[repr(C)]
+#[derive(Default)]
+struct ProgAttachAttr {
+    target_fd: u32,
+    attach_bpf_fd: u32,
+    attach_type: u32,
+    attach_flags: u32,
+    replace_bpf_fd: u32,
+}
+
+// union bpf_attr, for BPF_PROG_QUERY
+#[repr(C)]
+#[derive(Default)]
+struct ProgQueryAttr {
+    target_fd: u32,
+    attach_type: u32,
+    query_flags: u32,
+    attach_flags: u32,
+    prog_ids: u64,
+    prog_cnt: u32,
+    pad: u32,
+}
+
+// union bpf_attr, for BPF_PROG_GET_FD_BY_ID
+#[repr(C)]
+#[derive(Default)]
+struct GetFdByIdAttr {
+    prog_id: u32,
+    next_id: u32,
+    open_flags: u32,
+}
+
+fn bpf<T>(cmd: libc::c_long, attr: &T) -> nix::Result<libc::c_long> {
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_bpf,
+            cmd,
+            attr as *const T,
+            std::mem::size_of::<T>(),
+        )
+    };
+    Errno::result(ret)
+}
+
+// load loads a device program, returning its fd.
+pub fn load(insns: &[BpfInsn]) -> Result<RawFd> {
+    let license = CString::new(LICENSE).unwrap();
+    let attr = ProgLoadAttr {
+        prog_type: BPF_PROG_TYPE_CGROUP_DEVICE,
+        insn_cnt: insns.len() as u32,
+        insns: insns.as_ptr() as u64,
+        license: license.as_ptr() as u64,
+        ..Default::default()
+    };
+
+    let fd = bpf(BPF_PROG_LOAD, &attr).chain_err(|| "cannot load the device program")?;
+    Ok(fd as RawFd)
+}
+
+// attached returns the fds of the device programs attached to a cgroup.
+fn attached(cgroup: RawFd) -> Result<Vec<RawFd>> {
+    let mut ids = [0u32; BPF_CGROUP_MAX_PROGS];
+    let mut attr = ProgQueryAttr {
+        target_fd: cgroup as u32,
+        attach_type: BPF_CGROUP_DEVICE,
+        prog_ids: ids.as_mut_ptr() as u64,
+        prog_cnt: ids.len() as u32,
+        ..Default::default()
+    };
+
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_bpf,
+            BPF_PROG_QUERY,
+            &mut attr as *mut ProgQueryAttr,
+            std::mem::size_of::<ProgQueryAttr>(),
+        )
+    };
+    Errno::result(ret).chain_err(|| "cannot query the device programs")?;
+
+    let mut fds = Vec::new();
+    for id in ids[..attr.prog_cnt as usize].iter() {
+        let attr = GetFdByIdAttr {
+            prog_id: *id,
+            ..Default::default()
+        };
+        match bpf(BPF_PROG_GET_FD_BY_ID, &attr) {
+            Ok(fd) => fds.push(fd as RawFd),
+            // detached in the meantime
+            Err(nix::Error::Sys(Errno::ENOENT)) => (),
+            Err(e) => {
+                for fd in fds {
+                    let _ = unistd::close(fd);
+                }
+                return Err(Error::from(e)).chain_err(|| "cannot get a device program");
+            }
+        }
+    }
+
+    Ok(fds)
+}
+
+fn attach_replacing(cgroup: RawFd, prog: RawFd, old: &[RawFd]) -> Result<()> {
+    let mut attr = ProgAttachAttr {
+        target_fd: cgroup as u32,
+        attach_bpf_fd: prog as u32,
+        attach_type: BPF_CGROUP_DEVICE,
+        attach_flags: BPF_F_ALLOW_MULTI,
+        replace_bpf_fd: 0,
+    };
+
+    // a single program is replaced atomically, from Linux 5.6
+    if old.len() == 1 {
+        attr.attach_flags |= BPF_F_REPLACE;
+        attr.replace_bpf_fd = old[0] as u32;
+        match bpf(BPF_PROG_ATTACH, &attr) {
+            Ok(_) => return Ok(()),
+            Err(nix::Error::Sys(Errno::EINVAL)) => {
+                attr.attach_flags = BPF_F_ALLOW_MULTI;
+                attr.replace_bpf_fd = 0;
+            }
+            Err(e) => return Err(Error::from(e)).chain_err(|| "cannot replace the device program"),
+        }
+    }
+
+    // otherwise the new program is attached before the old ones are
+    // detached, both of them having to allow an access in between
+    bpf(BPF_PROG_ATTACH, &attr).chain_err(|| "cannot attach the device program")?;
+
+    for fd in old {
+        let attr = ProgAttachAttr {
+            target_fd: cgroup as u32,
+            attach_bpf_fd: *fd as u32,
+            attach_type: BPF_CGROUP_DEVICE,
+            ..Default::default()
+        };
+        bpf(BPF_PROG_DETACH, &attr).chain_err(|| "cannot detach a device program")?;
+    }
+
+    Ok(())
+}
+
+// apply compiles the device rules into a program, which replaces the
+// device programs of the cgroup directory.
+pub fn apply(dir: &str, rules: &[LinuxDeviceCgroup]) -> Result<()> {
+    let insns = compile(rules)?;
+    info!(
+        sl!(),
+        "device program of {} instructions for {}",
+        insns.len(),
+        dir
+    );
+
+    let prog = load(&insns)?;
+    defer!({
+        let _ = unistd::close(prog);
+    });
+
+    let cgroup = fcntl::open(
+        dir,
+        OFlag::O_DIRECTORY | OFlag::O_RDONLY | OFlag::O_CLOEXEC,
+        Mode::empty(),
+    )
+    .chain_err(|| format!("cannot open {}", dir))?;
+    defer!({
+        let _ = unistd::close(cgroup);
+    });
+
+    let old = attached(cgroup)?;
+    let ret = attach_replacing(cgroup, prog, &old);
+    for fd in old {
+        let _ = unistd::close(fd);
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cgroups::fs::WILDCARD;
+
+    const READ: u32 = BPF_DEVCG_ACC_READ as u32;
+    const WRITE: u32 = BPF_DEVCG_ACC_WRITE as u32;
+    const MKNOD: u32 = BPF_DEVCG_ACC_MKNOD as u32;
+    const CHAR: u32 = BPF_DEVCG_DEV_CHAR as u32;
+    const BLOCK: u32 = BPF_DEVCG_DEV_BLOCK as u32;
+
+    // run interprets a compiled program as the kernel would, on the
+    // access of a device.
+    fn run(insns: &[BpfInsn], dev_type: u32, access: u32, major: u32, minor: u32) -> bool {
+        let ctx = [(access << 16) | dev_type, major, minor];
+        let mut regs = [0u64; 11];
+        let mut pc = 0;
+
+        loop {
+            let insn = insns[pc];
+            let (dst, src) = (insn.dst(), insn.src());
+            let imm = insn.imm as i64 as u64;
+            pc += 1;
+
+            match insn.code {
+                c if c == BPF_LDX | BPF_W | BPF_MEM => {
+                    assert_eq!(src, R1 as usize);
+                    regs[dst] = ctx[insn.off as usize / 4] as u64;
+                }
+                c if c == BPF_ALU64 | BPF_AND | BPF_K => regs[dst] &= imm,
+                c if c == BPF_ALU64 | BPF_RSH | BPF_K => regs[dst] >>= imm,
+                c if c == BPF_ALU64 | BPF_MOV | BPF_K => regs[dst] = imm,
+                c if c == BPF_ALU64 | BPF_MOV | BPF_X => regs[dst] = regs[src],
+                c if c == BPF_JMP | BPF_JEQ | BPF_K => {
+                    if regs[dst] == imm {
+                        pc += insn.off as usize;
+                    }
+                }
+                c if c == BPF_JMP | BPF_JNE | BPF_K => {
+                    if regs[dst] != imm {
+                        pc += insn.off as usize;
+                    }
+                }
+                c if c == BPF_JMP | BPF_EXIT => return regs[R0 as usize] == 1,
+                c => panic!("unexpected instruction {:#x}", c),
+            }
+        }
+    }
+
+    fn rule(allow: bool, t: &str, major: i64, minor: i64, access: &str) -> LinuxDeviceCgroup {
+        LinuxDeviceCgroup {
+            Allow: allow,
+            Type: t.to_string(),
+            Major: major,
+            Minor: minor,
+            Access: access.to_string(),
+            unknown_fields: UnknownFields::default(),
+            cached_size: CachedSize::default(),
+        }
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let deny_all = rule(false, "a", WILDCARD, WILDCARD, "rwm");
+        let insns = compile(&container_rules(&[deny_all])).unwrap();
+
+        // /dev/null and the ptys
+        assert!(run(&insns, CHAR, READ | WRITE, 1, 3));
+        assert!(run(&insns, CHAR, WRITE, 136, 2));
+        // any device node can be created, but not opened
+        assert!(run(&insns, BLOCK, MKNOD, 8, 0));
+        assert!(!run(&insns, BLOCK, READ, 8, 0));
+        assert!(!run(&insns, CHAR, READ, 10, 229));
+
+        // no rule
+        let insns = compile(&[]).unwrap();
+        assert!(!run(&insns, CHAR, READ, 1, 3));
+    }
+
+    #[test]
+    fn test_rule_order() {
+        let rules = vec![
+            rule(false, "a", WILDCARD, WILDCARD, "rwm"),
+            rule(true, "b", 8, WILDCARD, "rw"),
+            rule(false, "b", 8, 0, "w"),
+        ];
+        let insns = compile(&rules).unwrap();
+
+        assert!(run(&insns, BLOCK, READ, 8, 16));
+        assert!(run(&insns, BLOCK, READ | WRITE, 8, 16));
+        assert!(run(&insns, BLOCK, READ, 8, 0));
+        // the later rule denies a part of the access
+        assert!(!run(&insns, BLOCK, WRITE, 8, 0));
+        assert!(!run(&insns, BLOCK, READ | WRITE, 8, 0));
+        // the allow rule doesn't cover the whole access
+        assert!(!run(&insns, BLOCK, MKNOD, 8, 16));
+        assert!(!run(&insns, CHAR, READ, 8, 16));
+
+        // the last rule allows everything again
+        let mut rules = rules;
+        rules.push(rule(true, "", WILDCARD, WILDCARD, ""));
+        let insns = compile(&rules).unwrap();
+        assert!(run(&insns, BLOCK, WRITE, 8, 0));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(compile(&[rule(true, "p", 1, 3, "rwm")]).is_err());
+        assert!(compile(&[rule(true, "c", 1, 3, "rx")]).is_err());
+    }
+}
@@ -3,8 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
-use crate::cgroups::FreezerState;
 use crate::cgroups::Manager as CgroupManager;
+use crate::cgroups::{FreezerState, FROZEN, THAWED};
 use crate::container::DEFAULT_DEVICES;
 use crate::errors::*;
 use lazy_static;
@@ -19,6 +19,8 @@ use protocols::oci::{LinuxDeviceCgroup, LinuxResources, LinuxThrottleDevice, Lin
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
+use std::thread;
+use std::time::Duration;
 
 // Convenience macro to obtain the scope logger
 macro_rules! sl {
@@ -380,9 +382,14 @@ fn try_write_nonzero(dir: &str, file: &str, v: i128) -> Result<()> {
     }
 }
 
+// remove removes a cgroup directory, which can't be emptied as a regular
+// one. Controllers mounted together share their directories, so it may
+// already be gone.
 fn remove(dir: &str) -> Result<()> {
-    fs::remove_dir_all(dir)?;
-    Ok(())
+    match fs::remove_dir(dir) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
 }
 
 fn apply(dir: &str, pid: pid_t) -> Result<()> {
@@ -1230,8 +1237,10 @@ pub struct Manager {
     pub cpath: String,
 }
 
-pub const THAWED: &'static str = "THAWED";
-pub const FROZEN: &'static str = "FROZEN";
+// a large or busy cgroup can take seconds to freeze, which is waited for
+// with the container lock held
+const FREEZER_RETRIES: u32 = 1000;
+const FREEZER_INTERVAL_MS: u64 = 10;
 
 impl CgroupManager for Manager {
     fn apply(&self, pid: pid_t) -> Result<()> {
@@ -1329,11 +1338,42 @@ impl CgroupManager for Manager {
         Ok(self.paths.clone())
     }
 
+    fn get_mounts(&self) -> Result<HashMap<String, String>> {
+        Ok(self.mounts.clone())
+    }
+
+    fn get_cpath(&self) -> Result<String> {
+        Ok(self.cpath.clone())
+    }
+
+    fn update_cpuset_path(&self, cpuset: &str) -> Result<()> {
+        Manager::update_cpuset_path(self, cpuset)
+    }
+
     fn freeze(&self, state: FreezerState) -> Result<()> {
         if state == THAWED || state == FROZEN {
             if self.paths.get("freezer").is_some() {
                 let dir = self.paths.get("freezer").unwrap();
-                write_file(dir, "freezer.state", state)?;
+                // The freezer may report FREEZING for a while, or even
+                // bounce back to THAWED when a task is forking, so keep
+                // writing the target state until the kernel agrees.
+                let mut current = String::new();
+                for _ in 0..FREEZER_RETRIES {
+                    write_file(dir, "freezer.state", state)?;
+                    current = read_file(dir, "freezer.state")?;
+                    if current.trim() == state {
+                        return Ok(());
+                    }
+                    thread::sleep(Duration::from_millis(FREEZER_INTERVAL_MS));
+                }
+
+                return Err(ErrorKind::DeadlineExceeded(format!(
+                    "timeout waiting for freezer of {} to become {}, still {}",
+                    dir,
+                    state,
+                    current.trim()
+                ))
+                .into());
             }
         } else {
             if !state.is_empty() {
@@ -0,0 +1,805 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The cgroup v2 manager puts a container in a single directory of the
+// unified hierarchy, which controllers are enabled from the root down.
+// The resources of the spec, written for v1, are converted to the v2
+// interface files, and the stats back to the v1 shape. The device rules
+// are a BPF program attached to the cgroup.
+
+use crate::cgroups::devicefilter;
+use crate::cgroups::fs::HUGEPAGESIZES;
+use crate::cgroups::{FreezerState, Manager as CgroupManager, CGROUP_ROOT, FROZEN, THAWED};
+use crate::errors::*;
+use libc::pid_t;
+use nix::errno::Errno;
+use protobuf::{CachedSize, RepeatedField, SingularPtrField, UnknownFields};
+use protocols::agent::{
+    BlkioStats, BlkioStatsEntry, CgroupStats, CpuStats, CpuUsage, HugetlbStats, MemoryData,
+    MemoryStats, PidsStats, ThrottlingData,
+};
+use protocols::oci::{LinuxResources, LinuxThrottleDevice};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "cgroups"))
+    };
+}
+
+pub const CGROUP_PROCS: &str = "cgroup.procs";
+pub const CGROUP_CONTROLLERS: &str = "cgroup.controllers";
+pub const CGROUP_SUBTREE_CONTROL: &str = "cgroup.subtree_control";
+pub const CGROUP_FREEZE: &str = "cgroup.freeze";
+pub const CGROUP_EVENTS: &str = "cgroup.events";
+pub const CPU_WEIGHT: &str = "cpu.weight";
+pub const CPU_MAX: &str = "cpu.max";
+pub const CPU_STAT: &str = "cpu.stat";
+pub const CPUSET_CPUS: &str = "cpuset.cpus";
+pub const CPUSET_MEMS: &str = "cpuset.mems";
+pub const CPUSET_CPUS_EFFECTIVE: &str = "cpuset.cpus.effective";
+pub const MEMORY_MAX: &str = "memory.max";
+pub const MEMORY_HIGH: &str = "memory.high";
+pub const MEMORY_CURRENT: &str = "memory.current";
+pub const MEMORY_EVENTS: &str = "memory.events";
+pub const MEMORY_STAT: &str = "memory.stat";
+pub const MEMORY_SWAP_MAX: &str = "memory.swap.max";
+pub const MEMORY_SWAP_CURRENT: &str = "memory.swap.current";
+pub const IO_WEIGHT: &str = "io.weight";
+pub const IO_MAX: &str = "io.max";
+pub const IO_STAT: &str = "io.stat";
+pub const PIDS_MAX: &str = "pids.max";
+pub const PIDS_CURRENT: &str = "pids.current";
+
+const MAX: &str = "max";
+const DEFAULT_CPU_PERIOD: u64 = 100000;
+
+// a large or busy cgroup can take seconds to freeze, which is waited for
+// with the container lock held
+const FREEZER_RETRIES: u32 = 1000;
+const FREEZER_INTERVAL_MS: u64 = 10;
+
+fn write_file<T>(dir: &str, file: &str, v: T) -> Result<()>
+where
+    T: ToString,
+{
+    let p = format!("{}/{}", dir, file);
+    info!(sl!(), "{}", p.as_str());
+    fs::write(p.as_str(), v.to_string().as_bytes()).chain_err(|| format!("cannot write {}", p))?;
+    Ok(())
+}
+
+fn read_file(dir: &str, file: &str) -> Result<String> {
+    let p = format!("{}/{}", dir, file);
+    let ret = fs::read_to_string(p.as_str())?;
+    Ok(ret)
+}
+
+fn exists(dir: &str, file: &str) -> bool {
+    Path::new(dir).join(file).exists()
+}
+
+// a limit is "max" when unlimited
+fn get_param_limit(dir: &str, file: &str) -> Result<u64> {
+    let v = read_file(dir, file)?;
+    if v.trim() == MAX {
+        return Ok(u64::max_value());
+    }
+    Ok(v.trim().parse::<u64>()?)
+}
+
+fn get_param_key_u64(dir: &str, file: &str) -> Result<HashMap<String, u64>> {
+    let mut m = HashMap::new();
+
+    for l in read_file(dir, file)?.lines() {
+        let t: Vec<&str> = l.split(' ').collect();
+        if t.len() != 2 {
+            continue;
+        }
+
+        m.insert(t[0].to_string(), t[1].trim().parse::<u64>()?);
+    }
+
+    Ok(m)
+}
+
+fn get_procs(dir: &str) -> Result<Vec<pid_t>> {
+    let mut m = Vec::new();
+
+    for l in read_file(dir, CGROUP_PROCS)?.lines() {
+        m.push(l.trim().parse::<pid_t>()?);
+    }
+
+    Ok(m)
+}
+
+fn get_all_procs(dir: &str) -> Result<Vec<pid_t>> {
+    let mut m = get_procs(dir)?;
+
+    for e in fs::read_dir(dir)? {
+        let path = e?.path();
+        if path.is_dir() {
+            m.append(get_all_procs(path.to_str().unwrap())?.as_mut());
+        }
+    }
+
+    Ok(m)
+}
+
+// get_path returns the cgroup of the calling process in the unified
+// hierarchy.
+fn get_path() -> Result<String> {
+    for l in fs::read_to_string("/proc/self/cgroup")?.lines() {
+        if l.starts_with("0::") {
+            return Ok(l[3..].to_string());
+        }
+    }
+
+    Err(ErrorKind::ErrorCode("no unified cgroup".to_string()).into())
+}
+
+// cpu_weight converts the cpu shares of v1, from 2 to 262144, to a cpu
+// weight, from 1 to 10000.
+pub fn cpu_weight(shares: u64) -> u64 {
+    if shares == 0 {
+        return 0;
+    }
+    let shares = std::cmp::min(std::cmp::max(shares, 2), 262144);
+    1 + ((shares - 2) * 9999) / 262142
+}
+
+pub fn cpu_max(quota: i64, period: u64) -> String {
+    let period = if period == 0 {
+        DEFAULT_CPU_PERIOD
+    } else {
+        period
+    };
+
+    if quota > 0 {
+        format!("{} {}", quota, period)
+    } else {
+        format!("{} {}", MAX, period)
+    }
+}
+
+// io_weight converts a blkio weight of v1, from 10 to 1000, to an io
+// weight, from 1 to 10000.
+pub fn io_weight(weight: u32) -> u64 {
+    if weight == 0 {
+        return 0;
+    }
+    let weight = std::cmp::min(std::cmp::max(weight, 10), 1000) as u64;
+    1 + ((weight - 10) * 9999) / 990
+}
+
+fn memory_limit(v: i64) -> String {
+    if v < 0 {
+        MAX.to_string()
+    } else {
+        v.to_string()
+    }
+}
+
+// swap_max converts the memory+swap limit of v1 to the swap limit of v2.
+pub fn swap_max(limit: i64, swap: i64) -> Result<Option<String>> {
+    if swap == 0 {
+        return Ok(None);
+    }
+    if swap < 0 {
+        return Ok(Some(MAX.to_string()));
+    }
+    if limit <= 0 {
+        return Err(
+            ErrorKind::InvalidArgument("a swap limit needs a memory limit".to_string()).into(),
+        );
+    }
+    if swap < limit {
+        return Err(ErrorKind::InvalidArgument(format!(
+            "the memory+swap limit {} is below the memory limit {}",
+            swap, limit
+        ))
+        .into());
+    }
+
+    Ok(Some((swap - limit).to_string()))
+}
+
+fn io_max(devices: &[LinuxThrottleDevice], key: &str, m: &mut HashMap<String, Vec<String>>) {
+    for d in devices {
+        m.entry(format!("{}:{}", d.Major, d.Minor))
+            .or_insert_with(Vec::new)
+            .push(format!("{}={}", key, d.Rate));
+    }
+}
+
+// set_resources writes the resources of a container to its cgroup.
+// Kernel memory, swappiness and the OOM killer have no v2 files.
+pub fn set_resources(dir: &str, r: &LinuxResources) -> Result<()> {
+    if let Some(cpu) = r.CPU.as_ref() {
+        if cpu.Shares != 0 {
+            write_file(dir, CPU_WEIGHT, cpu_weight(cpu.Shares))?;
+        }
+        if cpu.Quota != 0 || cpu.Period != 0 {
+            write_file(dir, CPU_MAX, cpu_max(cpu.Quota, cpu.Period))?;
+        }
+        if !cpu.Cpus.is_empty() {
+            write_file(dir, CPUSET_CPUS, cpu.Cpus.as_str())?;
+        }
+        if !cpu.Mems.is_empty() {
+            write_file(dir, CPUSET_MEMS, cpu.Mems.as_str())?;
+        }
+    }
+
+    if let Some(memory) = r.Memory.as_ref() {
+        // the swap limit is checked first, memory.max not being written
+        // when it is wrong
+        let swap = swap_max(memory.Limit, memory.Swap)?;
+        if memory.Limit != 0 {
+            write_file(dir, MEMORY_MAX, memory_limit(memory.Limit))?;
+        }
+        if memory.Reservation != 0 {
+            write_file(dir, MEMORY_HIGH, memory_limit(memory.Reservation))?;
+        }
+        if let Some(swap) = swap {
+            write_file(dir, MEMORY_SWAP_MAX, swap)?;
+        }
+    }
+
+    if let Some(pids) = r.Pids.as_ref() {
+        let v = if pids.Limit > 0 {
+            pids.Limit.to_string()
+        } else {
+            MAX.to_string()
+        };
+        write_file(dir, PIDS_MAX, v)?;
+    }
+
+    if let Some(blkio) = r.BlockIO.as_ref() {
+        if blkio.Weight != 0 {
+            write_file(
+                dir,
+                IO_WEIGHT,
+                format!("default {}", io_weight(blkio.Weight)),
+            )?;
+        }
+        for d in blkio.WeightDevice.iter().filter(|d| d.Weight != 0) {
+            write_file(
+                dir,
+                IO_WEIGHT,
+                format!("{}:{} {}", d.Major, d.Minor, io_weight(d.Weight)),
+            )?;
+        }
+
+        let mut m: HashMap<String, Vec<String>> = HashMap::new();
+        io_max(&blkio.ThrottleReadBpsDevice, "rbps", &mut m);
+        io_max(&blkio.ThrottleWriteBpsDevice, "wbps", &mut m);
+        io_max(&blkio.ThrottleReadIOPSDevice, "riops", &mut m);
+        io_max(&blkio.ThrottleWriteIOPSDevice, "wiops", &mut m);
+        for (dev, limits) in m.iter() {
+            write_file(dir, IO_MAX, format!("{} {}", dev, limits.join(" ")))?;
+        }
+    }
+
+    for l in r.HugepageLimits.iter() {
+        let file = format!("hugetlb.{}.max", l.Pagesize);
+        write_file(dir, file.as_str(), l.Limit)?;
+    }
+
+    Ok(())
+}
+
+fn get_cpu_stats(dir: &str) -> Result<CpuStats> {
+    let h = get_param_key_u64(dir, CPU_STAT)?;
+    let usec = |k: &str| h.get(k).map_or(0, |v| *v * 1000);
+
+    let cpu_usage = CpuUsage {
+        total_usage: usec("usage_usec"),
+        percpu_usage: vec![],
+        usage_in_kernelmode: usec("system_usec"),
+        usage_in_usermode: usec("user_usec"),
+        unknown_fields: UnknownFields::default(),
+        cached_size: CachedSize::default(),
+    };
+
+    // the throttling is only accounted by the cpu controller
+    let throttling_data = if h.contains_key("nr_periods") {
+        SingularPtrField::some(ThrottlingData {
+            periods: h["nr_periods"],
+            throttled_periods: h.get("nr_throttled").map_or(0, |v| *v),
+            throttled_time: usec("throttled_usec"),
+            unknown_fields: UnknownFields::default(),
+            cached_size: CachedSize::default(),
+        })
+    } else {
+        SingularPtrField::none()
+    };
+
+    Ok(CpuStats {
+        cpu_usage: SingularPtrField::some(cpu_usage),
+        throttling_data,
+        unknown_fields: UnknownFields::default(),
+        cached_size: CachedSize::default(),
+    })
+}
+
+// the failcnt of v1 is the number of times the limit was hit
+fn get_failcnt(dir: &str, file: &str) -> Result<u64> {
+    if !exists(dir, file) {
+        return Ok(0);
+    }
+    let h = get_param_key_u64(dir, file)?;
+    Ok(h.get(MAX).map_or(0, |v| *v))
+}
+
+fn get_memory_stats(dir: &str) -> Result<MemoryStats> {
+    let h = get_param_key_u64(dir, MEMORY_STAT)?;
+    let cache = h.get("file").map_or(0, |v| *v);
+
+    let usage = MemoryData {
+        usage: get_param_limit(dir, MEMORY_CURRENT)?,
+        max_usage: 0,
+        failcnt: get_failcnt(dir, MEMORY_EVENTS)?,
+        limit: get_param_limit(dir, MEMORY_MAX)?,
+        unknown_fields: UnknownFields::default(),
+        cached_size: CachedSize::default(),
+    };
+
+    // the swap is accounted apart from the memory
+    let swap_usage = if exists(dir, MEMORY_SWAP_CURRENT) {
+        SingularPtrField::some(MemoryData {
+            usage: get_param_limit(dir, MEMORY_SWAP_CURRENT)?,
+            max_usage: 0,
+            failcnt: get_failcnt(dir, "memory.swap.events")?,
+            limit: get_param_limit(dir, MEMORY_SWAP_MAX)?,
+            unknown_fields: UnknownFields::default(),
+            cached_size: CachedSize::default(),
+        })
+    } else {
+        SingularPtrField::none()
+    };
+
+    Ok(MemoryStats {
+        cache,
+        usage: SingularPtrField::some(usage),
+        swap_usage,
+        kernel_usage: SingularPtrField::none(),
+        use_hierarchy: true,
+        stats: h,
+        unknown_fields: UnknownFields::default(),
+        cached_size: CachedSize::default(),
+    })
+}
+
+fn get_pids_stats(dir: &str) -> Result<PidsStats> {
+    let current = get_param_limit(dir, PIDS_CURRENT)?;
+    let limit = match get_param_limit(dir, PIDS_MAX)? {
+        l if l == u64::max_value() => 0,
+        l => l,
+    };
+
+    Ok(PidsStats {
+        current,
+        limit,
+        unknown_fields: UnknownFields::default(),
+        cached_size: CachedSize::default(),
+    })
+}
+
+fn blkio_entry(major: u64, minor: u64, op: &str, value: u64) -> BlkioStatsEntry {
+    BlkioStatsEntry {
+        major,
+        minor,
+        op: op.to_string(),
+        value,
+        unknown_fields: UnknownFields::default(),
+        cached_size: CachedSize::default(),
+    }
+}
+
+// get_blkio_stats converts the lines of io.stat, like
+// "8:0 rbytes=4096 wbytes=0 rios=1 wios=0 dbytes=0 dios=0", to the
+// recursive blkio stats of v1.
+fn get_blkio_stats(dir: &str) -> Result<BlkioStats> {
+    let mut bytes = RepeatedField::new();
+    let mut serviced = RepeatedField::new();
+
+    for l in read_file(dir, IO_STAT)?.lines() {
+        let parts: Vec<&str> = l.split_whitespace().collect();
+        if parts.is_empty() {
+            continue;
+        }
+
+        let devno: Vec<&str> = parts[0].split(':').collect();
+        if devno.len() != 2 {
+            return Err(nix::Error::Sys(Errno::EINVAL).into());
+        }
+        let major = devno[0].parse::<u64>()?;
+        let minor = devno[1].parse::<u64>()?;
+
+        for kv in parts[1..].iter() {
+            let kv: Vec<&str> = kv.split('=').collect();
+            if kv.len() != 2 {
+                continue;
+            }
+            let value = kv[1].parse::<u64>()?;
+
+            match kv[0] {
+                "rbytes" => bytes.push(blkio_entry(major, minor, "Read", value)),
+                "wbytes" => bytes.push(blkio_entry(major, minor, "Write", value)),
+                "rios" => serviced.push(blkio_entry(major, minor, "Read", value)),
+                "wios" => serviced.push(blkio_entry(major, minor, "Write", value)),
+                _ => (),
+            }
+        }
+    }
+
+    let mut m = BlkioStats::new();
+    m.io_service_bytes_recursive = bytes;
+    m.io_serviced_recursive = serviced;
+
+    Ok(m)
+}
+
+fn get_hugetlb_stats(dir: &str) -> Result<HashMap<String, HugetlbStats>> {
+    let mut h = HashMap::new();
+
+    for pagesize in HUGEPAGESIZES.iter() {
+        let fusage = format!("hugetlb.{}.current", pagesize);
+        if !exists(dir, fusage.as_str()) {
+            continue;
+        }
+
+        let usage = get_param_limit(dir, fusage.as_str())?;
+        let failcnt = get_failcnt(dir, format!("hugetlb.{}.events", pagesize).as_str())?;
+
+        h.insert(
+            pagesize.to_string(),
+            HugetlbStats {
+                usage,
+                max_usage: 0,
+                failcnt,
+                unknown_fields: UnknownFields::default(),
+                cached_size: CachedSize::default(),
+            },
+        );
+    }
+
+    Ok(h)
+}
+
+// get_stats reads the stats of a cgroup, the ones of the controllers
+// which aren't enabled being left out.
+pub fn get_stats(dir: &str) -> Result<CgroupStats> {
+    let cpu_stats = SingularPtrField::some(get_cpu_stats(dir)?);
+
+    let memory_stats = if exists(dir, MEMORY_CURRENT) {
+        SingularPtrField::some(get_memory_stats(dir)?)
+    } else {
+        SingularPtrField::none()
+    };
+
+    let pids_stats = if exists(dir, PIDS_CURRENT) {
+        SingularPtrField::some(get_pids_stats(dir)?)
+    } else {
+        SingularPtrField::none()
+    };
+
+    let blkio_stats = if exists(dir, IO_STAT) {
+        SingularPtrField::some(get_blkio_stats(dir)?)
+    } else {
+        SingularPtrField::none()
+    };
+
+    Ok(CgroupStats {
+        cpu_stats,
+        memory_stats,
+        pids_stats,
+        blkio_stats,
+        hugetlb_stats: get_hugetlb_stats(dir)?,
+        unknown_fields: UnknownFields::default(),
+        cached_size: CachedSize::default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct Manager {
+    // mountpoint of the unified hierarchy
+    pub mount: String,
+    pub path: String,
+    pub cpath: String,
+}
+
+impl Manager {
+    pub fn new(cpath: &str) -> Result<Self> {
+        if !cpath.starts_with('/') {
+            return Err(nix::Error::Sys(Errno::EINVAL).into());
+        }
+
+        let rel = get_path()?;
+        let path = if rel == "/" {
+            format!("{}{}", CGROUP_ROOT, cpath)
+        } else {
+            format!("{}{}{}", CGROUP_ROOT, rel, cpath)
+        };
+
+        Ok(Self {
+            mount: CGROUP_ROOT.to_string(),
+            path,
+            cpath: cpath.to_string(),
+        })
+    }
+
+    // the directories from the root of the hierarchy down to the cgroup
+    fn ancestors(&self) -> Vec<String> {
+        let mut dirs = vec![self.mount.clone()];
+        let rel = self.path[self.mount.len()..].to_string();
+
+        for d in rel.split('/').filter(|d| !d.is_empty()) {
+            let dir = format!("{}/{}", dirs.last().unwrap(), d);
+            dirs.push(dir);
+        }
+
+        dirs
+    }
+
+    // create creates the cgroup, the controllers of a cgroup being the
+    // ones its parent enables in its subtree. Some controllers can't be
+    // enabled in a cgroup with processes, like the one of the agent, nor
+    // in the ones it doesn't own, which is only warned about.
+    fn create(&self) -> Result<()> {
+        fs::create_dir_all(self.path.as_str())
+            .chain_err(|| format!("cannot create {}", self.path))?;
+
+        let dirs = self.ancestors();
+        for dir in dirs[..dirs.len() - 1].iter() {
+            let controllers = match read_file(dir, CGROUP_CONTROLLERS) {
+                Ok(c) => c,
+                Err(e) => {
+                    warn!(sl!(), "cannot read the controllers of {}: {}", dir, e);
+                    continue;
+                }
+            };
+            for c in controllers.split_whitespace() {
+                if let Err(e) = write_file(dir, CGROUP_SUBTREE_CONTROL, format!("+{}", c)) {
+                    warn!(
+                        sl!(),
+                        "cannot enable the {} controller in {}: {}", c, dir, e
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn update_cpuset_path(&self, cpuset: &str) -> Result<()> {
+        // the root cgroup has no cpuset.cpus
+        for dir in self.ancestors().iter().skip(1) {
+            if exists(dir, CPUSET_CPUS) {
+                write_file(dir, CPUSET_CPUS, cpuset)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CgroupManager for Manager {
+    fn apply(&self, pid: pid_t) -> Result<()> {
+        self.create()?;
+        write_file(self.path.as_str(), CGROUP_PROCS, pid)
+    }
+
+    fn set(&self, spec: &LinuxResources, update: bool) -> Result<()> {
+        self.create()?;
+        set_resources(self.path.as_str(), spec)?;
+
+        // an update without device rules keeps the device program
+        if !update || !spec.Devices.is_empty() {
+            let rules = devicefilter::container_rules(&spec.Devices);
+            devicefilter::apply(self.path.as_str(), &rules)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_stats(&self) -> Result<CgroupStats> {
+        get_stats(self.path.as_str())
+    }
+
+    fn get_paths(&self) -> Result<HashMap<String, String>> {
+        let mut m = HashMap::new();
+        m.insert("".to_string(), self.path.clone());
+        Ok(m)
+    }
+
+    fn get_mounts(&self) -> Result<HashMap<String, String>> {
+        let mut m = HashMap::new();
+        m.insert("".to_string(), self.mount.clone());
+        Ok(m)
+    }
+
+    fn get_cpath(&self) -> Result<String> {
+        Ok(self.cpath.clone())
+    }
+
+    fn update_cpuset_path(&self, cpuset: &str) -> Result<()> {
+        Manager::update_cpuset_path(self, cpuset)
+    }
+
+    fn freeze(&self, state: FreezerState) -> Result<()> {
+        let v = if state == FROZEN {
+            "1"
+        } else if state == THAWED {
+            "0"
+        } else if state.is_empty() {
+            return Ok(());
+        } else {
+            return Err(nix::Error::Sys(Errno::EINVAL).into());
+        };
+
+        let dir = self.path.as_str();
+        write_file(dir, CGROUP_FREEZE, v)?;
+
+        // the cgroup is frozen once all its tasks are
+        let mut frozen = None;
+        for _ in 0..FREEZER_RETRIES {
+            let events = get_param_key_u64(dir, CGROUP_EVENTS)?;
+            frozen = events.get("frozen").cloned();
+            if frozen.map_or(false, |f| f.to_string() == v) {
+                return Ok(());
+            }
+            thread::sleep(Duration::from_millis(FREEZER_INTERVAL_MS));
+        }
+
+        Err(ErrorKind::DeadlineExceeded(format!(
+            "timeout waiting for {} to become {}, frozen still {}",
+            dir,
+            state,
+            frozen.map_or("unknown".to_string(), |f| f.to_string())
+        ))
+        .into())
+    }
+
+    fn destroy(&mut self) -> Result<()> {
+        match fs::remove_dir(self.path.as_str()) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn get_pids(&self) -> Result<Vec<pid_t>> {
+        get_procs(self.path.as_str())
+    }
+
+    fn get_all_pids(&self) -> Result<Vec<pid_t>> {
+        get_all_procs(self.path.as_str())
+    }
+}
+
+pub fn get_guest_cpuset() -> Result<String> {
+    read_file(CGROUP_ROOT, CPUSET_CPUS_EFFECTIVE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protocols::oci::{LinuxBlockIO, LinuxCPU, LinuxMemory, LinuxPids};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(cpu_weight(0), 0);
+        assert_eq!(cpu_weight(2), 1);
+        assert_eq!(cpu_weight(1024), 39);
+        assert_eq!(cpu_weight(262144), 10000);
+
+        assert_eq!(cpu_max(50000, 0), "50000 100000");
+        assert_eq!(cpu_max(-1, 200000), "max 200000");
+
+        assert_eq!(io_weight(10), 1);
+        assert_eq!(io_weight(500), 4950);
+        assert_eq!(io_weight(1000), 10000);
+
+        assert_eq!(swap_max(1024, 0).unwrap(), None);
+        assert_eq!(swap_max(1024, -1).unwrap(), Some("max".to_string()));
+        assert_eq!(swap_max(1024, 3072).unwrap(), Some("2048".to_string()));
+        assert!(swap_max(0, 3072).is_err());
+        assert!(swap_max(4096, 3072).is_err());
+    }
+
+    #[test]
+    fn test_set_resources() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().to_str().unwrap();
+
+        let mut r = LinuxResources::new();
+        let mut cpu = LinuxCPU::new();
+        cpu.Shares = 1024;
+        cpu.Quota = 20000;
+        cpu.Cpus = "0-1".to_string();
+        r.set_CPU(cpu);
+        let mut memory = LinuxMemory::new();
+        memory.Limit = 1 << 20;
+        memory.Swap = -1;
+        r.set_Memory(memory);
+        r.set_Pids(LinuxPids::new());
+        let mut blkio = LinuxBlockIO::new();
+        let mut rbps = LinuxThrottleDevice::new();
+        rbps.Major = 8;
+        rbps.Rate = 1000;
+        let mut wiops = rbps.clone();
+        wiops.Rate = 10;
+        blkio.ThrottleReadBpsDevice = RepeatedField::from_vec(vec![rbps]);
+        blkio.ThrottleWriteIOPSDevice = RepeatedField::from_vec(vec![wiops]);
+        r.set_BlockIO(blkio);
+
+        set_resources(path, &r).unwrap();
+
+        let read = |f: &str| read_file(path, f).unwrap();
+        assert_eq!(read(CPU_WEIGHT), "39");
+        assert_eq!(read(CPU_MAX), "20000 100000");
+        assert_eq!(read(CPUSET_CPUS), "0-1");
+        assert!(!exists(path, CPUSET_MEMS));
+        assert_eq!(read(MEMORY_MAX), "1048576");
+        assert_eq!(read(MEMORY_SWAP_MAX), "max");
+        assert!(!exists(path, MEMORY_HIGH));
+        assert_eq!(read(PIDS_MAX), "max");
+        assert_eq!(read(IO_MAX), "8:0 rbps=1000 wiops=10");
+    }
+
+    #[test]
+    fn test_get_stats() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().to_str().unwrap();
+        let write = |f: &str, v: &str| fs::write(dir.path().join(f), v).unwrap();
+
+        write(
+            CPU_STAT,
+            "usage_usec 3000\nuser_usec 2000\nsystem_usec 1000\n\
+             nr_periods 10\nnr_throttled 2\nthrottled_usec 500\n",
+        );
+        write(MEMORY_STAT, "anon 4096\nfile 8192\n");
+        write(MEMORY_CURRENT, "12288\n");
+        write(MEMORY_MAX, "max\n");
+        write(MEMORY_EVENTS, "low 0\nhigh 0\nmax 3\noom 0\n");
+        write(PIDS_CURRENT, "4\n");
+        write(PIDS_MAX, "max\n");
+        write(
+            IO_STAT,
+            "8:0 rbytes=4096 wbytes=512 rios=1 wios=2 dbytes=0 dios=0\n",
+        );
+
+        let stats = get_stats(path).unwrap();
+
+        let cpu = stats.cpu_stats.as_ref().unwrap();
+        assert_eq!(cpu.cpu_usage.as_ref().unwrap().total_usage, 3000000);
+        assert_eq!(cpu.cpu_usage.as_ref().unwrap().usage_in_kernelmode, 1000000);
+        let throttling = cpu.throttling_data.as_ref().unwrap();
+        assert_eq!(throttling.throttled_periods, 2);
+        assert_eq!(throttling.throttled_time, 500000);
+
+        let memory = stats.memory_stats.as_ref().unwrap();
+        assert_eq!(memory.cache, 8192);
+        assert_eq!(memory.usage.as_ref().unwrap().usage, 12288);
+        assert_eq!(memory.usage.as_ref().unwrap().failcnt, 3);
+        assert_eq!(memory.usage.as_ref().unwrap().limit, u64::max_value());
+        assert!(memory.swap_usage.is_none());
+
+        let pids = stats.pids_stats.as_ref().unwrap();
+        assert_eq!((pids.current, pids.limit), (4, 0));
+
+        let blkio = stats.blkio_stats.as_ref().unwrap();
+        assert_eq!(blkio.io_service_bytes_recursive.len(), 2);
+        assert_eq!(blkio.io_service_bytes_recursive[1].op, "Write");
+        assert_eq!(blkio.io_service_bytes_recursive[1].value, 512);
+        assert_eq!(blkio.io_serviced_recursive[0].value, 1);
+    }
+}
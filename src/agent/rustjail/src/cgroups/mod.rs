@@ -8,13 +8,56 @@ use crate::errors::*;
 use protocols::agent::CgroupStats;
 use protocols::oci::LinuxResources;
 use std::collections::HashMap;
+use std::ffi::CString;
+use std::fmt::Debug;
 
+pub mod devicefilter;
 pub mod fs;
+pub mod fs2;
+pub mod notifier;
 pub mod systemd;
 
 pub type FreezerState = &'static str;
 
-pub trait Manager {
+pub const THAWED: FreezerState = "THAWED";
+pub const FROZEN: FreezerState = "FROZEN";
+
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+const CGROUP2_SUPER_MAGIC: i64 = 0x6367_7270;
+
+// is_unified tells whether the cgroup root is the unified hierarchy of
+// cgroup v2, rather than a tmpfs with a mount per v1 hierarchy.
+pub fn is_unified() -> bool {
+    let root = CString::new(CGROUP_ROOT).unwrap();
+    let mut st: libc::statfs = unsafe { std::mem::zeroed() };
+
+    if unsafe { libc::statfs(root.as_ptr(), &mut st) } != 0 {
+        return false;
+    }
+
+    st.f_type as i64 == CGROUP2_SUPER_MAGIC
+}
+
+// new_manager returns the manager of the cgroup hierarchy mounted in the
+// guest.
+pub fn new_manager(cpath: &str) -> Result<Box<dyn Manager + Send + Sync>> {
+    if is_unified() {
+        Ok(Box::new(fs2::Manager::new(cpath)?))
+    } else {
+        Ok(Box::new(fs::Manager::new(cpath)?))
+    }
+}
+
+pub fn get_guest_cpuset() -> Result<String> {
+    if is_unified() {
+        fs2::get_guest_cpuset()
+    } else {
+        fs::get_guest_cpuset()
+    }
+}
+
+pub trait Manager: Debug {
     fn apply(&self, _pid: i32) -> Result<()> {
         Err(ErrorKind::ErrorCode("not supported!".to_string()).into())
     }
@@ -46,4 +89,16 @@ pub trait Manager {
     fn set(&self, _container: &LinuxResources, _update: bool) -> Result<()> {
         Err(ErrorKind::ErrorCode("not supported!".to_string()).into())
     }
+
+    fn get_mounts(&self) -> Result<HashMap<String, String>> {
+        Err(ErrorKind::ErrorCode("not supported!".to_string()).into())
+    }
+
+    fn get_cpath(&self) -> Result<String> {
+        Err(ErrorKind::ErrorCode("not supported!".to_string()).into())
+    }
+
+    fn update_cpuset_path(&self, _cpuset: &str) -> Result<()> {
+        Err(ErrorKind::ErrorCode("not supported!".to_string()).into())
+    }
 }
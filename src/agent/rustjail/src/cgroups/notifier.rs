@@ -0,0 +1,376 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use crate::errors::*;
+use nix::errno::Errno;
+use nix::fcntl::OFlag;
+use nix::poll::{poll, PollFd, PollFlags};
+use nix::sys::eventfd::{eventfd, EfdFlags};
+use nix::sys::inotify::{AddWatchFlags, InitFlags, Inotify};
+use nix::unistd;
+use std::fs;
+use std::io::Write;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "cgroups_notifier"))
+    };
+}
+
+const MEMORY_OOM_CONTROL: &'static str = "memory.oom_control";
+const MEMORY_EVENTS: &'static str = "memory.events";
+const CGROUP_EVENT_CONTROL: &'static str = "cgroup.event_control";
+const MEMORY_PRESSURE_LEVEL: &'static str = "memory.pressure_level";
+const MEMORY_PRESSURE: &'static str = "memory.pressure";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PressureLevel {
+    Low,
+    Medium,
+    Critical,
+}
+
+impl PressureLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PressureLevel::Low => "low",
+            PressureLevel::Medium => "medium",
+            PressureLevel::Critical => "critical",
+        }
+    }
+
+    // PSI trigger used on cgroup v2: stall type, then the stall time
+    // and the tracking window, both in microseconds.
+    fn psi_trigger(&self) -> &'static str {
+        match self {
+            PressureLevel::Low => "some 100000 1000000",
+            PressureLevel::Medium => "some 300000 1000000",
+            PressureLevel::Critical => "full 300000 1000000",
+        }
+    }
+}
+
+// Subscription keeps the notifications of a memory pressure level
+// coming, the eventfd or PSI trigger and the thread behind them are
+// released once it's dropped.
+#[derive(Debug)]
+pub struct Subscription {
+    // the notifier thread stops when the pipe is hung up
+    stop: RawFd,
+}
+
+impl Subscription {
+    // new returns a subscription along with the read end of its pipe,
+    // for the notifier thread to poll.
+    fn new() -> Result<(Subscription, RawFd)> {
+        let (r, w) = unistd::pipe2(OFlag::O_CLOEXEC)?;
+        Ok((Subscription { stop: w }, r))
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let _ = unistd::close(self.stop);
+    }
+}
+
+// notify_oom watches the memory cgroup in dir and sends cid on the
+// returned channel every time a task of the cgroup is OOM killed.
+// The channel is closed once the cgroup has been removed.
+pub fn notify_oom(cid: &str, dir: &str) -> Result<Receiver<String>> {
+    if Path::new(dir).join(MEMORY_OOM_CONTROL).exists() {
+        return notify_on_oom(cid, dir);
+    }
+
+    notify_on_oom_v2(cid, dir)
+}
+
+// cgroup v1: an eventfd registered on memory.oom_control fires on
+// every OOM, and one last time when the cgroup is removed.
+fn notify_on_oom(cid: &str, dir: &str) -> Result<Receiver<String>> {
+    register_memory_event(dir, MEMORY_OOM_CONTROL, "", cid.to_string(), None)
+}
+
+// notify_memory_pressure watches the memory cgroup in dir and sends the
+// name of lvl on the returned channel every time the cgroup reaches
+// that pressure level. The channel is closed once the cgroup has been
+// removed, or the returned subscription dropped.
+pub fn notify_memory_pressure(
+    dir: &str,
+    lvl: PressureLevel,
+) -> Result<(Receiver<String>, Subscription)> {
+    let (subscription, stop) = Subscription::new()?;
+
+    let res = if Path::new(dir).join(MEMORY_PRESSURE_LEVEL).exists() {
+        register_memory_event(
+            dir,
+            MEMORY_PRESSURE_LEVEL,
+            lvl.as_str(),
+            lvl.as_str().to_string(),
+            Some(stop),
+        )
+    } else {
+        notify_memory_pressure_v2(dir, lvl, stop)
+    };
+
+    match res {
+        Ok(rx) => Ok((rx, subscription)),
+        Err(e) => {
+            let _ = unistd::close(stop);
+            Err(e)
+        }
+    }
+}
+
+// wait_event waits for events on fd, and returns None once stop, if
+// any, has been hung up.
+fn wait_event(fd: RawFd, events: PollFlags, stop: Option<RawFd>) -> Result<Option<PollFlags>> {
+    let mut fds = vec![PollFd::new(fd, events)];
+    if let Some(stop) = stop {
+        fds.push(PollFd::new(stop, PollFlags::POLLIN));
+    }
+
+    loop {
+        match poll(&mut fds, -1) {
+            Ok(_) => break,
+            Err(nix::Error::Sys(Errno::EINTR)) => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if fds.len() > 1 && fds[1].revents().map_or(false, |r| !r.is_empty()) {
+        return Ok(None);
+    }
+
+    Ok(Some(fds[0].revents().unwrap_or(PollFlags::empty())))
+}
+
+// register_memory_event registers an eventfd for event_file through
+// cgroup.event_control, and sends msg on the returned channel each time
+// the eventfd is signaled, until stop is hung up.
+pub fn register_memory_event(
+    dir: &str,
+    event_file: &str,
+    arg: &str,
+    msg: String,
+    stop: Option<RawFd>,
+) -> Result<Receiver<String>> {
+    let path = Path::new(dir).join(event_file);
+    let file = fs::File::open(&path).chain_err(|| format!("fail to open {:?}", &path))?;
+
+    let efd = eventfd(0, EfdFlags::EFD_CLOEXEC)?;
+
+    let control = if arg.is_empty() {
+        format!("{} {}", efd, file.as_raw_fd())
+    } else {
+        format!("{} {} {}", efd, file.as_raw_fd(), arg)
+    };
+
+    if let Err(e) = fs::write(Path::new(dir).join(CGROUP_EVENT_CONTROL), control) {
+        let _ = unistd::close(efd);
+        return Err(e).chain_err(|| format!("fail to register event for {:?}", &path));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let event_control = Path::new(dir).join(CGROUP_EVENT_CONTROL);
+
+    thread::spawn(move || {
+        // the event file must stay open for as long as the eventfd is used
+        let _file = file;
+        let mut buf = [0u8; 8];
+
+        loop {
+            match wait_event(efd, PollFlags::POLLIN, stop) {
+                Ok(Some(_)) => {}
+                Ok(None) => break,
+                Err(e) => {
+                    warn!(sl!(), "fail to poll eventfd: {:?}", e);
+                    break;
+                }
+            }
+
+            match unistd::read(efd, &mut buf) {
+                Ok(_) => {}
+                Err(nix::Error::Sys(Errno::EINTR)) => continue,
+                Err(e) => {
+                    warn!(sl!(), "fail to read eventfd: {:?}", e);
+                    break;
+                }
+            }
+
+            // the eventfd is also signaled when the cgroup is removed
+            if !event_control.exists() {
+                break;
+            }
+
+            if tx.send(msg.clone()).is_err() {
+                break;
+            }
+        }
+
+        let _ = unistd::close(efd);
+        if let Some(stop) = stop {
+            let _ = unistd::close(stop);
+        }
+    });
+
+    Ok(rx)
+}
+
+// cgroup v2: there's no eventfd interface, watch memory.events for
+// modifications and check whether the oom_kill counter has increased.
+fn notify_on_oom_v2(cid: &str, dir: &str) -> Result<Receiver<String>> {
+    let path = Path::new(dir).join(MEMORY_EVENTS);
+    let mut last = get_memory_event(&path, "oom_kill")?;
+
+    let inotify = Inotify::init(InitFlags::IN_CLOEXEC)?;
+    if let Err(e) = inotify.add_watch(&path, AddWatchFlags::IN_MODIFY) {
+        let _ = unistd::close(inotify.as_raw_fd());
+        return Err(e.into());
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let cid = cid.to_string();
+
+    thread::spawn(move || {
+        loop {
+            match inotify.read_events() {
+                Ok(events) => {
+                    if events
+                        .iter()
+                        .any(|e| e.mask.contains(AddWatchFlags::IN_IGNORED))
+                    {
+                        // the watch is gone with the cgroup
+                        break;
+                    }
+                }
+                Err(nix::Error::Sys(Errno::EINTR)) => continue,
+                Err(e) => {
+                    warn!(sl!(), "fail to read inotify events: {:?}", e);
+                    break;
+                }
+            }
+
+            let count = match get_memory_event(&path, "oom_kill") {
+                Ok(c) => c,
+                Err(_) => break,
+            };
+
+            if count > last {
+                last = count;
+                if tx.send(cid.clone()).is_err() {
+                    break;
+                }
+            }
+        }
+
+        let _ = unistd::close(inotify.as_raw_fd());
+    });
+
+    Ok(rx)
+}
+
+// cgroup v2: write a PSI trigger to memory.pressure, the kernel then
+// raises POLLPRI on that file descriptor every time the trigger fires,
+// and POLLERR once the cgroup is gone.
+fn notify_memory_pressure_v2(
+    dir: &str,
+    lvl: PressureLevel,
+    stop: RawFd,
+) -> Result<Receiver<String>> {
+    let path = Path::new(dir).join(MEMORY_PRESSURE);
+    let mut file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&path)
+        .chain_err(|| format!("fail to open {:?}", &path))?;
+
+    // the trigger lives as long as the file stays open
+    file.write_all(lvl.psi_trigger().as_bytes())
+        .chain_err(|| format!("fail to set pressure trigger on {:?}", &path))?;
+
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        loop {
+            let revents = match wait_event(file.as_raw_fd(), PollFlags::POLLPRI, Some(stop)) {
+                Ok(Some(r)) => r,
+                Ok(None) => break,
+                Err(e) => {
+                    warn!(sl!(), "fail to poll {:?}: {:?}", &path, e);
+                    break;
+                }
+            };
+
+            if revents.contains(PollFlags::POLLERR) {
+                break;
+            }
+
+            if revents.contains(PollFlags::POLLPRI) {
+                if tx.send(lvl.as_str().to_string()).is_err() {
+                    break;
+                }
+            }
+        }
+
+        // closing the file removes the trigger
+        drop(file);
+        let _ = unistd::close(stop);
+    });
+
+    Ok(rx)
+}
+
+// get_memory_event returns the counter of key in a memory.events file.
+fn get_memory_event(path: &PathBuf, key: &str) -> Result<u64> {
+    let content = fs::read_to_string(path)?;
+
+    for line in content.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() == 2 && fields[0] == key {
+            return Ok(fields[1].parse::<u64>()?);
+        }
+    }
+
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::RecvTimeoutError;
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_notify_memory_pressure_dropped() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join(MEMORY_PRESSURE_LEVEL), "").unwrap();
+        fs::write(dir.path().join(CGROUP_EVENT_CONTROL), "").unwrap();
+
+        let (rx, subscription) =
+            notify_memory_pressure(dir.path().to_str().unwrap(), PressureLevel::Low).unwrap();
+
+        let control = fs::read_to_string(dir.path().join(CGROUP_EVENT_CONTROL)).unwrap();
+        assert!(control.ends_with(" low"), "{}", control);
+
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(100)),
+            Err(RecvTimeoutError::Timeout)
+        );
+
+        // the notifier goes away with the subscription
+        drop(subscription);
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+}
@@ -0,0 +1,615 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// A D-Bus client speaking just enough of the wire protocol to call
+// systemd: little endian messages, the basic types, arrays, structs and
+// variants, and the EXTERNAL authentication of a unix socket.
+
+use crate::errors::*;
+use nix::unistd;
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+pub const METHOD_CALL: u8 = 1;
+pub const METHOD_RETURN: u8 = 2;
+pub const ERROR: u8 = 3;
+pub const SIGNAL: u8 = 4;
+
+// header fields
+const FIELD_PATH: u8 = 1;
+const FIELD_INTERFACE: u8 = 2;
+const FIELD_MEMBER: u8 = 3;
+const FIELD_ERROR_NAME: u8 = 4;
+const FIELD_REPLY_SERIAL: u8 = 5;
+const FIELD_DESTINATION: u8 = 6;
+const FIELD_SIGNATURE: u8 = 8;
+
+const LITTLE_ENDIAN: u8 = b'l';
+const PROTOCOL_VERSION: u8 = 1;
+
+// systemd may take a while to run a job
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Byte(u8),
+    Bool(bool),
+    Int32(i32),
+    Uint32(u32),
+    Int64(i64),
+    Uint64(u64),
+    Str(String),
+    ObjectPath(String),
+    Signature(String),
+    // the signature of the elements, and the elements
+    Array(String, Vec<Value>),
+    Struct(Vec<Value>),
+    Variant(Box<Value>),
+}
+
+impl Value {
+    pub fn signature(&self) -> String {
+        match self {
+            Value::Byte(_) => "y".to_string(),
+            Value::Bool(_) => "b".to_string(),
+            Value::Int32(_) => "i".to_string(),
+            Value::Uint32(_) => "u".to_string(),
+            Value::Int64(_) => "x".to_string(),
+            Value::Uint64(_) => "t".to_string(),
+            Value::Str(_) => "s".to_string(),
+            Value::ObjectPath(_) => "o".to_string(),
+            Value::Signature(_) => "g".to_string(),
+            Value::Array(s, _) => format!("a{}", s),
+            Value::Struct(v) => format!("({})", signature(v)),
+            Value::Variant(_) => "v".to_string(),
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) | Value::ObjectPath(s) | Value::Signature(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+}
+
+pub fn signature(values: &[Value]) -> String {
+    values.iter().map(|v| v.signature()).collect()
+}
+
+fn malformed(what: &str) -> Error {
+    ErrorKind::ErrorCode(format!("malformed D-Bus message: {}", what)).into()
+}
+
+fn alignment(t: u8) -> usize {
+    match t {
+        b'x' | b't' | b'd' | b'(' | b'{' => 8,
+        b'b' | b'i' | b'u' | b's' | b'o' | b'a' => 4,
+        b'n' | b'q' => 2,
+        _ => 1,
+    }
+}
+
+// type_end returns the end of the complete type starting at i.
+fn type_end(sig: &[u8], i: usize) -> Result<usize> {
+    match sig.get(i) {
+        Some(b'a') => type_end(sig, i + 1),
+        Some(b'(') => {
+            let mut j = i + 1;
+            while sig.get(j) != Some(&b')') {
+                j = type_end(sig, j)?;
+            }
+            Ok(j + 1)
+        }
+        Some(_) => Ok(i + 1),
+        None => Err(malformed("truncated signature")),
+    }
+}
+
+// split_types splits a signature into its complete types.
+fn split_types(sig: &[u8]) -> Result<Vec<&[u8]>> {
+    let mut types = Vec::new();
+    let mut i = 0;
+
+    while i < sig.len() {
+        let end = type_end(sig, i)?;
+        types.push(&sig[i..end]);
+        i = end;
+    }
+
+    Ok(types)
+}
+
+#[derive(Default)]
+struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    fn align(&mut self, n: usize) {
+        while self.buf.len() % n != 0 {
+            self.buf.push(0);
+        }
+    }
+
+    fn u32(&mut self, v: u32) {
+        self.align(4);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn u64(&mut self, v: u64) {
+        self.align(8);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    fn string(&mut self, s: &str) {
+        self.u32(s.len() as u32);
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0);
+    }
+
+    fn signature(&mut self, s: &str) {
+        self.buf.push(s.len() as u8);
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(0);
+    }
+
+    fn value(&mut self, v: &Value) {
+        match v {
+            Value::Byte(b) => self.buf.push(*b),
+            Value::Bool(b) => self.u32(*b as u32),
+            Value::Int32(i) => self.u32(*i as u32),
+            Value::Uint32(u) => self.u32(*u),
+            Value::Int64(i) => self.u64(*i as u64),
+            Value::Uint64(u) => self.u64(*u),
+            Value::Str(s) | Value::ObjectPath(s) => self.string(s),
+            Value::Signature(s) => self.signature(s),
+            Value::Array(sig, values) => {
+                self.u32(0);
+                let at = self.buf.len() - 4;
+                // the length leaves out the padding of the first element
+                self.align(alignment(sig.as_bytes().first().cloned().unwrap_or(b'y')));
+                let start = self.buf.len();
+                for v in values {
+                    self.value(v);
+                }
+                let len = (self.buf.len() - start) as u32;
+                self.buf[at..at + 4].copy_from_slice(&len.to_le_bytes());
+            }
+            Value::Struct(values) => {
+                self.align(8);
+                for v in values {
+                    self.value(v);
+                }
+            }
+            Value::Variant(v) => {
+                self.signature(v.signature().as_str());
+                self.value(v);
+            }
+        }
+    }
+}
+
+struct Decoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.pos + n > self.buf.len() {
+            return Err(malformed("truncated value"));
+        }
+        let b = &self.buf[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(b)
+    }
+
+    fn align(&mut self, n: usize) -> Result<()> {
+        let pad = (n - self.pos % n) % n;
+        self.take(pad)?;
+        Ok(())
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        self.align(4)?;
+        let mut b = [0u8; 4];
+        b.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(b))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        self.align(8)?;
+        let mut b = [0u8; 8];
+        b.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(b))
+    }
+
+    fn text(&mut self, len: usize) -> Result<String> {
+        let b = self.take(len + 1)?;
+        String::from_utf8(b[..len].to_vec()).map_err(|_| malformed("invalid string"))
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.u32()? as usize;
+        self.text(len)
+    }
+
+    fn signature(&mut self) -> Result<String> {
+        let len = self.take(1)?[0] as usize;
+        self.text(len)
+    }
+
+    // value decodes a value of a complete type.
+    fn value(&mut self, sig: &[u8]) -> Result<Value> {
+        let t = match sig.first() {
+            Some(t) => *t,
+            None => return Err(malformed("empty signature")),
+        };
+
+        let v = match t {
+            b'y' => Value::Byte(self.take(1)?[0]),
+            b'b' => Value::Bool(self.u32()? != 0),
+            b'i' => Value::Int32(self.u32()? as i32),
+            b'u' => Value::Uint32(self.u32()?),
+            b'x' => Value::Int64(self.u64()? as i64),
+            b't' => Value::Uint64(self.u64()?),
+            b's' => Value::Str(self.string()?),
+            b'o' => Value::ObjectPath(self.string()?),
+            b'g' => Value::Signature(self.signature()?),
+            b'v' => {
+                let s = self.signature()?;
+                if split_types(s.as_bytes())?.len() != 1 {
+                    return Err(malformed("invalid variant"));
+                }
+                Value::Variant(Box::new(self.value(s.as_bytes())?))
+            }
+            b'a' => {
+                let elem = &sig[1..];
+                let len = self.u32()? as usize;
+                self.align(alignment(elem.first().cloned().unwrap_or(b'y')))?;
+                let end = self.pos + len;
+                let mut values = Vec::new();
+                while self.pos < end {
+                    values.push(self.value(elem)?);
+                }
+                let elem = String::from_utf8(elem.to_vec()).map_err(|_| malformed("signature"))?;
+                Value::Array(elem, values)
+            }
+            b'(' => {
+                self.align(8)?;
+                let mut values = Vec::new();
+                for t in split_types(&sig[1..sig.len() - 1])? {
+                    values.push(self.value(t)?);
+                }
+                Value::Struct(values)
+            }
+            _ => return Err(malformed(&format!("unsupported type {}", t as char))),
+        };
+
+        Ok(v)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Message {
+    pub msg_type: u8,
+    pub flags: u8,
+    pub serial: u32,
+    pub path: String,
+    pub interface: String,
+    pub member: String,
+    pub error_name: String,
+    pub reply_serial: u32,
+    pub destination: String,
+    pub body: Vec<Value>,
+}
+
+fn field(code: u8, v: Value) -> Value {
+    Value::Struct(vec![Value::Byte(code), Value::Variant(Box::new(v))])
+}
+
+impl Message {
+    pub fn method_call(
+        dest: &str,
+        path: &str,
+        iface: &str,
+        member: &str,
+        body: Vec<Value>,
+    ) -> Self {
+        Message {
+            msg_type: METHOD_CALL,
+            path: path.to_string(),
+            interface: iface.to_string(),
+            member: member.to_string(),
+            destination: dest.to_string(),
+            body,
+            ..Default::default()
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut fields = Vec::new();
+        if !self.path.is_empty() {
+            fields.push(field(FIELD_PATH, Value::ObjectPath(self.path.clone())));
+        }
+        if !self.interface.is_empty() {
+            fields.push(field(FIELD_INTERFACE, Value::Str(self.interface.clone())));
+        }
+        if !self.member.is_empty() {
+            fields.push(field(FIELD_MEMBER, Value::Str(self.member.clone())));
+        }
+        if !self.error_name.is_empty() {
+            fields.push(field(FIELD_ERROR_NAME, Value::Str(self.error_name.clone())));
+        }
+        if self.reply_serial != 0 {
+            fields.push(field(FIELD_REPLY_SERIAL, Value::Uint32(self.reply_serial)));
+        }
+        if !self.destination.is_empty() {
+            fields.push(field(
+                FIELD_DESTINATION,
+                Value::Str(self.destination.clone()),
+            ));
+        }
+        if !self.body.is_empty() {
+            fields.push(field(
+                FIELD_SIGNATURE,
+                Value::Signature(signature(&self.body)),
+            ));
+        }
+
+        // the body is 8 aligned in the message, so it can be encoded apart
+        let mut body = Encoder::default();
+        for v in self.body.iter() {
+            body.value(v);
+        }
+
+        let mut e = Encoder::default();
+        e.buf
+            .extend_from_slice(&[LITTLE_ENDIAN, self.msg_type, self.flags, PROTOCOL_VERSION]);
+        e.u32(body.buf.len() as u32);
+        e.u32(self.serial);
+        e.value(&Value::Array("(yv)".to_string(), fields));
+        e.align(8);
+        e.buf.extend_from_slice(&body.buf);
+
+        e.buf
+    }
+
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        if buf.len() < 16 || buf[0] != LITTLE_ENDIAN {
+            return Err(malformed("not a little endian message"));
+        }
+
+        let mut m = Message {
+            msg_type: buf[1],
+            flags: buf[2],
+            ..Default::default()
+        };
+        let mut d = Decoder { buf, pos: 4 };
+        let body_len = d.u32()? as usize;
+        m.serial = d.u32()?;
+
+        let mut sig = String::new();
+        if let Value::Array(_, fields) = d.value(b"a(yv)")? {
+            for f in fields {
+                let (code, v) = match f {
+                    Value::Struct(ref f) if f.len() == 2 => match (&f[0], &f[1]) {
+                        (Value::Byte(c), Value::Variant(v)) => (*c, (**v).clone()),
+                        _ => continue,
+                    },
+                    _ => continue,
+                };
+                let s = v.as_str().unwrap_or("").to_string();
+                match code {
+                    FIELD_PATH => m.path = s,
+                    FIELD_INTERFACE => m.interface = s,
+                    FIELD_MEMBER => m.member = s,
+                    FIELD_ERROR_NAME => m.error_name = s,
+                    FIELD_DESTINATION => m.destination = s,
+                    FIELD_SIGNATURE => sig = s,
+                    FIELD_REPLY_SERIAL => {
+                        if let Value::Uint32(serial) = v {
+                            m.reply_serial = serial;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        d.align(8)?;
+        let start = d.pos;
+        if start + body_len > buf.len() {
+            return Err(malformed("truncated body"));
+        }
+
+        let mut body = Decoder {
+            buf: &buf[start..start + body_len],
+            pos: 0,
+        };
+        for t in split_types(sig.as_bytes())? {
+            m.body.push(body.value(t)?);
+        }
+
+        Ok(m)
+    }
+
+    // into_body returns the body of a reply, an error reply being turned
+    // into an error.
+    pub fn into_body(self) -> Result<Vec<Value>> {
+        if self.msg_type == ERROR {
+            let msg = self.body.get(0).and_then(|v| v.as_str()).unwrap_or("");
+            return Err(ErrorKind::ErrorCode(format!("{}: {}", self.error_name, msg)).into());
+        }
+        Ok(self.body)
+    }
+}
+
+pub fn read_message<R: Read>(r: &mut R) -> Result<Message> {
+    let mut buf = vec![0u8; 16];
+    r.read_exact(&mut buf)?;
+
+    let mut len = [0u8; 4];
+    len.copy_from_slice(&buf[4..8]);
+    let body_len = u32::from_le_bytes(len) as usize;
+    len.copy_from_slice(&buf[12..16]);
+    let fields_len = u32::from_le_bytes(len) as usize;
+
+    let header_len = (16 + fields_len + 7) / 8 * 8;
+    buf.resize(header_len + body_len, 0);
+    r.read_exact(&mut buf[16..])?;
+
+    Message::decode(&buf)
+}
+
+pub fn read_line<R: Read>(r: &mut R) -> Result<String> {
+    let mut line = Vec::new();
+    let mut b = [0u8; 1];
+
+    while !line.ends_with(b"\r\n") {
+        r.read_exact(&mut b)?;
+        line.push(b[0]);
+    }
+    line.truncate(line.len() - 2);
+
+    String::from_utf8(line).map_err(|_| malformed("invalid authentication line"))
+}
+
+pub struct Connection {
+    stream: UnixStream,
+    serial: u32,
+    // the signals received while waiting for a reply
+    signals: VecDeque<Message>,
+}
+
+impl Connection {
+    // connect connects to the bus of a unix socket, authenticated by the
+    // uid of the process.
+    pub fn connect(path: &str) -> Result<Self> {
+        let mut stream =
+            UnixStream::connect(path).chain_err(|| format!("cannot connect to {}", path))?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+
+        let uid: String = unistd::getuid()
+            .to_string()
+            .bytes()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        stream.write_all(format!("\0AUTH EXTERNAL {}\r\n", uid).as_bytes())?;
+
+        let reply = read_line(&mut stream)?;
+        if !reply.starts_with("OK ") {
+            return Err(
+                ErrorKind::ErrorCode(format!("D-Bus authentication failed: {}", reply)).into(),
+            );
+        }
+        stream.write_all(b"BEGIN\r\n")?;
+
+        Ok(Connection {
+            stream,
+            serial: 0,
+            signals: VecDeque::new(),
+        })
+    }
+
+    // call calls a method, returning its reply.
+    pub fn call(&mut self, mut m: Message) -> Result<Message> {
+        self.serial += 1;
+        m.serial = self.serial;
+        self.stream.write_all(&m.encode())?;
+
+        loop {
+            let reply = read_message(&mut self.stream)?;
+            match reply.msg_type {
+                METHOD_RETURN | ERROR if reply.reply_serial == m.serial => return Ok(reply),
+                SIGNAL => self.signals.push_back(reply),
+                _ => (),
+            }
+        }
+    }
+
+    // wait_signal returns the first signal matching f, the other ones
+    // being dropped.
+    pub fn wait_signal<F>(&mut self, f: F) -> Result<Message>
+    where
+        F: Fn(&Message) -> bool,
+    {
+        while let Some(s) = self.signals.pop_front() {
+            if f(&s) {
+                return Ok(s);
+            }
+        }
+
+        loop {
+            let m = read_message(&mut self.stream)?;
+            if m.msg_type == SIGNAL && f(&m) {
+                return Ok(m);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let properties = Value::Array(
+            "(sv)".to_string(),
+            vec![
+                Value::Struct(vec![
+                    Value::Str("PIDs".to_string()),
+                    Value::Variant(Box::new(Value::Array(
+                        "u".to_string(),
+                        vec![Value::Uint32(42)],
+                    ))),
+                ]),
+                Value::Struct(vec![
+                    Value::Str("MemoryMax".to_string()),
+                    Value::Variant(Box::new(Value::Uint64(1 << 30))),
+                ]),
+            ],
+        );
+        let mut m = Message::method_call(
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+            "StartTransientUnit",
+            vec![
+                Value::Str("kata-c.scope".to_string()),
+                Value::Str("replace".to_string()),
+                properties,
+                Value::Array("(sa(sv))".to_string(), vec![]),
+            ],
+        );
+        m.serial = 7;
+
+        let buf = m.encode();
+        assert_eq!(buf.len() % 8, 0);
+        assert_eq!(signature(&m.body), "ssa(sv)a(sa(sv))");
+
+        let decoded = read_message(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, m);
+
+        // truncated
+        assert!(Message::decode(&buf[..buf.len() - 4]).is_err());
+    }
+
+    #[test]
+    fn test_into_body() {
+        let reply = Message {
+            msg_type: ERROR,
+            error_name: "org.freedesktop.systemd1.NoSuchUnit".to_string(),
+            body: vec![Value::Str("Unit kata-c.scope not loaded.".to_string())],
+            ..Default::default()
+        };
+        let err = reply.into_body().unwrap_err();
+        assert!(err.to_string().contains("NoSuchUnit"));
+    }
+}
@@ -0,0 +1,700 @@
+// Copyright (c) 2019 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The systemd driver puts a container in a transient scope unit, which
+// systemd creates in its cgroups with the resources given as properties.
+// The cgroups are delegated to the agent, which manages what systemd has
+// no property for through the cgroup filesystem, as well as the stats and
+// the freezer.
+
+use crate::cgroups::{self, fs, fs2, FreezerState, Manager as CgroupManager, CGROUP_ROOT};
+use crate::errors::*;
+use libc::pid_t;
+use protocols::agent::CgroupStats;
+use protocols::oci::LinuxResources;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+pub mod dbus;
+
+use self::dbus::{Connection, Message, Value};
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "cgroups"))
+    };
+}
+
+// the private socket of systemd, which needs no D-Bus daemon
+pub const SYSTEMD_SOCKET: &str = "/run/systemd/private";
+
+const SYSTEMD_DESTINATION: &str = "org.freedesktop.systemd1";
+const SYSTEMD_PATH: &str = "/org/freedesktop/systemd1";
+const MANAGER_INTERFACE: &str = "org.freedesktop.systemd1.Manager";
+const NO_SUCH_UNIT: &str = "org.freedesktop.systemd1.NoSuchUnit";
+
+const DEFAULT_SLICE: &str = "system.slice";
+const DEFAULT_CPU_PERIOD: u64 = 100000;
+// systemd rounds the cpu quota to a percent of a cpu
+const CPU_QUOTA_GRANULARITY: u64 = 10000;
+const INFINITY: u64 = u64::max_value();
+
+// is_systemd_cgroup tells whether the cgroups path of a container is a
+// "slice:prefix:name" systemd one.
+pub fn is_systemd_cgroup(cgroups_path: &str) -> bool {
+    !cgroups_path.starts_with('/') && cgroups_path.split(':').count() == 3
+}
+
+// expand_slice returns the cgroup of a slice, a-b.slice being under
+// a.slice.
+// is_reachable tells whether systemd answers on its socket.
+pub fn is_reachable(socket: &str) -> bool {
+    Connection::connect(socket).is_ok()
+}
+
+fn expand_slice(slice: &str) -> Result<String> {
+    if !slice.ends_with(".slice") || slice.contains('/') {
+        return Err(ErrorKind::InvalidArgument(format!("invalid slice {}", slice)).into());
+    }
+
+    let name = &slice[..slice.len() - ".slice".len()];
+    if name == "-" {
+        return Ok("/".to_string());
+    }
+
+    let mut path = String::new();
+    let mut prefix = String::new();
+    for part in name.split('-') {
+        if part.is_empty() {
+            return Err(ErrorKind::InvalidArgument(format!("invalid slice {}", slice)).into());
+        }
+        prefix.push_str(part);
+        path.push_str(&format!("/{}.slice", prefix));
+        prefix.push('-');
+    }
+
+    Ok(path)
+}
+
+fn limit(v: i64) -> u64 {
+    if v < 0 {
+        INFINITY
+    } else {
+        v as u64
+    }
+}
+
+// cpu_quota_per_sec converts a cfs quota to the cpu time a unit gets per
+// second.
+pub fn cpu_quota_per_sec(quota: i64, period: u64) -> u64 {
+    if quota <= 0 {
+        return INFINITY;
+    }
+
+    let period = if period == 0 {
+        DEFAULT_CPU_PERIOD
+    } else {
+        period
+    };
+    let usec = quota as u64 * 1000000 / period;
+
+    (usec + CPU_QUOTA_GRANULARITY - 1) / CPU_QUOTA_GRANULARITY * CPU_QUOTA_GRANULARITY
+}
+
+// resource_properties converts resources to unit properties, the ones of
+// the unified hierarchy or of the v1 controllers.
+pub fn resource_properties(r: &LinuxResources, unified: bool) -> Result<Vec<(String, Value)>> {
+    let mut props = Vec::new();
+    let mut push = |name: &str, v: Value| props.push((name.to_string(), v));
+
+    if let Some(cpu) = r.CPU.as_ref() {
+        if cpu.Shares != 0 {
+            if unified {
+                push("CPUWeight", Value::Uint64(fs2::cpu_weight(cpu.Shares)));
+            } else {
+                push("CPUShares", Value::Uint64(cpu.Shares));
+            }
+        }
+        if cpu.Quota != 0 || cpu.Period != 0 {
+            push(
+                "CPUQuotaPerSecUSec",
+                Value::Uint64(cpu_quota_per_sec(cpu.Quota, cpu.Period)),
+            );
+        }
+    }
+
+    if let Some(memory) = r.Memory.as_ref() {
+        if memory.Limit != 0 {
+            let name = if unified { "MemoryMax" } else { "MemoryLimit" };
+            push(name, Value::Uint64(limit(memory.Limit)));
+        }
+        if unified {
+            if memory.Reservation != 0 {
+                push("MemoryHigh", Value::Uint64(limit(memory.Reservation)));
+            }
+            if let Some(swap) = fs2::swap_max(memory.Limit, memory.Swap)? {
+                let swap = swap.parse::<u64>().unwrap_or(INFINITY);
+                push("MemorySwapMax", Value::Uint64(swap));
+            }
+        }
+    }
+
+    if let Some(pids) = r.Pids.as_ref() {
+        let max = if pids.Limit > 0 {
+            pids.Limit as u64
+        } else {
+            INFINITY
+        };
+        push("TasksMax", Value::Uint64(max));
+    }
+
+    if let Some(blkio) = r.BlockIO.as_ref() {
+        if blkio.Weight != 0 {
+            if unified {
+                push("IOWeight", Value::Uint64(fs2::io_weight(blkio.Weight)));
+            } else {
+                push("BlockIOWeight", Value::Uint64(blkio.Weight as u64));
+            }
+        }
+    }
+
+    Ok(props)
+}
+
+fn properties(props: Vec<(String, Value)>) -> Value {
+    Value::Array(
+        "(sv)".to_string(),
+        props
+            .into_iter()
+            .map(|(name, v)| Value::Struct(vec![Value::Str(name), Value::Variant(Box::new(v))]))
+            .collect(),
+    )
+}
+
+// unit_cgroup returns the manager of the cgroup of a unit, which path is
+// from the root of the hierarchy rather than from the cgroup of the agent.
+fn unit_cgroup(cpath: &str) -> Result<Box<dyn CgroupManager + Send + Sync>> {
+    if cgroups::is_unified() {
+        return Ok(Box::new(fs2::Manager {
+            mount: CGROUP_ROOT.to_string(),
+            path: format!("{}{}", CGROUP_ROOT, cpath),
+            cpath: cpath.to_string(),
+        }));
+    }
+
+    let mut m = fs::Manager::new(cpath)?;
+    m.paths = m
+        .paths
+        .keys()
+        .filter_map(|k| {
+            m.mounts
+                .get(k)
+                .map(|mnt| (k.clone(), format!("{}{}", mnt, cpath)))
+        })
+        .collect();
+    m.rels = m
+        .rels
+        .keys()
+        .map(|k| (k.clone(), "/".to_string()))
+        .collect();
+
+    Ok(Box::new(m))
+}
+
+#[derive(Debug)]
+pub struct Manager {
+    pub unit: String,
+    pub slice: String,
+    socket: String,
+    unified: bool,
+    // the resources the unit is started with
+    resources: Mutex<LinuxResources>,
+    cgroup: Box<dyn CgroupManager + Send + Sync>,
+}
+
+impl Manager {
+    // new returns the manager of the scope of a "slice:prefix:name"
+    // cgroups path, the slice being system.slice if empty.
+    pub fn new(cgroups_path: &str) -> Result<Self> {
+        let parts: Vec<&str> = cgroups_path.split(':').collect();
+        if !is_systemd_cgroup(cgroups_path) || parts[2].is_empty() {
+            return Err(ErrorKind::InvalidArgument(format!(
+                "invalid systemd cgroups path {}",
+                cgroups_path
+            ))
+            .into());
+        }
+
+        let slice = if parts[0].is_empty() {
+            DEFAULT_SLICE
+        } else {
+            parts[0]
+        };
+        let unit = if parts[1].is_empty() {
+            format!("{}.scope", parts[2])
+        } else {
+            format!("{}-{}.scope", parts[1], parts[2])
+        };
+
+        let cpath = format!("{}/{}", expand_slice(slice)?.trim_end_matches('/'), unit);
+
+        Ok(Manager {
+            unit,
+            slice: slice.to_string(),
+            socket: SYSTEMD_SOCKET.to_string(),
+            unified: cgroups::is_unified(),
+            resources: Mutex::new(LinuxResources::new()),
+            cgroup: unit_cgroup(cpath.as_str())?,
+        })
+    }
+
+    fn call(&self, conn: &mut Connection, member: &str, body: Vec<Value>) -> Result<Message> {
+        conn.call(Message::method_call(
+            SYSTEMD_DESTINATION,
+            SYSTEMD_PATH,
+            MANAGER_INTERFACE,
+            member,
+            body,
+        ))
+    }
+
+    // wait_job waits for a job of the unit to be done.
+    fn wait_job(&self, conn: &mut Connection, reply: Message) -> Result<()> {
+        let job = match reply.into_body()?.get(0) {
+            Some(Value::ObjectPath(job)) => job.clone(),
+            _ => return Err(ErrorKind::ErrorCode("no systemd job".to_string()).into()),
+        };
+
+        let removed = conn.wait_signal(|m| {
+            m.member == "JobRemoved" && m.body.get(1).and_then(|j| j.as_str()) == Some(job.as_str())
+        })?;
+
+        match removed.body.get(3).and_then(|r| r.as_str()) {
+            Some("done") => Ok(()),
+            result => Err(ErrorKind::ErrorCode(format!(
+                "job {} of unit {} failed: {}",
+                job,
+                self.unit,
+                result.unwrap_or("unknown")
+            ))
+            .into()),
+        }
+    }
+
+    fn start_unit(&self, pid: pid_t, r: &LinuxResources) -> Result<()> {
+        let mut props = vec![
+            (
+                "Description".to_string(),
+                Value::Str(format!("kata container {}", self.unit)),
+            ),
+            ("Slice".to_string(), Value::Str(self.slice.clone())),
+            ("Delegate".to_string(), Value::Bool(true)),
+            ("DefaultDependencies".to_string(), Value::Bool(false)),
+            (
+                "PIDs".to_string(),
+                Value::Array("u".to_string(), vec![Value::Uint32(pid as u32)]),
+            ),
+            ("CPUAccounting".to_string(), Value::Bool(true)),
+            ("MemoryAccounting".to_string(), Value::Bool(true)),
+            ("TasksAccounting".to_string(), Value::Bool(true)),
+        ];
+        let io_accounting = if self.unified {
+            "IOAccounting"
+        } else {
+            "BlockIOAccounting"
+        };
+        props.push((io_accounting.to_string(), Value::Bool(true)));
+        props.extend(resource_properties(r, self.unified)?);
+
+        info!(sl!(), "starting unit {}", self.unit; "pid" => pid);
+
+        let mut conn = Connection::connect(self.socket.as_str())?;
+        // the jobs are only signaled to subscribers
+        self.call(&mut conn, "Subscribe", vec![])?.into_body()?;
+        let reply = self.call(
+            &mut conn,
+            "StartTransientUnit",
+            vec![
+                Value::Str(self.unit.clone()),
+                Value::Str("replace".to_string()),
+                properties(props),
+                Value::Array("(sa(sv))".to_string(), vec![]),
+            ],
+        )?;
+
+        self.wait_job(&mut conn, reply)
+    }
+
+    fn set_unit_properties(&self, r: &LinuxResources) -> Result<()> {
+        let props = resource_properties(r, self.unified)?;
+        if props.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = Connection::connect(self.socket.as_str())?;
+        self.call(
+            &mut conn,
+            "SetUnitProperties",
+            vec![
+                Value::Str(self.unit.clone()),
+                Value::Bool(true),
+                properties(props),
+            ],
+        )?
+        .into_body()?;
+
+        Ok(())
+    }
+
+    // stop_unit stops the unit, which may already be gone.
+    fn stop_unit(&self) -> Result<()> {
+        info!(sl!(), "stopping unit {}", self.unit);
+
+        let mut conn = Connection::connect(self.socket.as_str())?;
+        self.call(&mut conn, "Subscribe", vec![])?.into_body()?;
+        let reply = self.call(
+            &mut conn,
+            "StopUnit",
+            vec![
+                Value::Str(self.unit.clone()),
+                Value::Str("replace".to_string()),
+            ],
+        )?;
+
+        if reply.error_name == NO_SUCH_UNIT {
+            return Ok(());
+        }
+
+        self.wait_job(&mut conn, reply)
+    }
+
+    // the unit is started once its cgroup exists, maybe by a previous
+    // agent
+    fn started(&self) -> bool {
+        self.cgroup
+            .get_paths()
+            .map_or(false, |paths| paths.values().any(|p| Path::new(p).exists()))
+    }
+}
+
+impl CgroupManager for Manager {
+    fn apply(&self, pid: pid_t) -> Result<()> {
+        if !self.started() {
+            let r = self.resources.lock().unwrap().clone();
+            self.start_unit(pid, &r)?;
+            // systemd leaves out the v1 controllers it doesn't manage
+            self.cgroup.set(&r, false)?;
+        }
+
+        self.cgroup.apply(pid)
+    }
+
+    fn set(&self, r: &LinuxResources, update: bool) -> Result<()> {
+        // the unit is created by apply, with the first resources
+        if !update {
+            *self.resources.lock().unwrap() = r.clone();
+            return Ok(());
+        }
+
+        self.set_unit_properties(r)?;
+        self.cgroup.set(r, update)
+    }
+
+    fn get_pids(&self) -> Result<Vec<pid_t>> {
+        self.cgroup.get_pids()
+    }
+
+    fn get_all_pids(&self) -> Result<Vec<pid_t>> {
+        self.cgroup.get_all_pids()
+    }
+
+    fn get_stats(&self) -> Result<CgroupStats> {
+        self.cgroup.get_stats()
+    }
+
+    fn freeze(&self, state: FreezerState) -> Result<()> {
+        self.cgroup.freeze(state)
+    }
+
+    // destroy removes the cgroups even if the unit can't be stopped.
+    fn destroy(&mut self) -> Result<()> {
+        let mut errors: Vec<String> = Vec::new();
+
+        if let Err(e) = self.stop_unit() {
+            errors.push(format!("cannot stop unit {}: {}", self.unit, e));
+        }
+        if let Err(e) = self.cgroup.destroy() {
+            errors.push(format!(
+                "cannot remove the cgroups of unit {}: {}",
+                self.unit, e
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(ErrorKind::ErrorCode(errors.join("; ")).into());
+        }
+
+        Ok(())
+    }
+
+    fn get_paths(&self) -> Result<HashMap<String, String>> {
+        self.cgroup.get_paths()
+    }
+
+    fn get_mounts(&self) -> Result<HashMap<String, String>> {
+        self.cgroup.get_mounts()
+    }
+
+    fn get_cpath(&self) -> Result<String> {
+        self.cgroup.get_cpath()
+    }
+
+    fn update_cpuset_path(&self, cpuset: &str) -> Result<()> {
+        self.cgroup.update_cpuset_path(cpuset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dbus::{read_line, read_message, ERROR, METHOD_RETURN, SIGNAL};
+    use super::*;
+    use protocols::oci::{LinuxCPU, LinuxMemory, LinuxPids};
+    use std::io::Write;
+    use std::os::unix::net::UnixListener;
+    use std::sync::mpsc::{channel, Receiver};
+    use std::sync::Arc;
+    use std::thread;
+    use tempfile::tempdir;
+
+    // mock_systemd serves the manager methods on a socket, sending the
+    // calls it gets. The units it starts are known until stopped.
+    fn mock_systemd(path: &Path) -> Receiver<Message> {
+        let listener = UnixListener::bind(path).unwrap();
+        let (tx, rx) = channel();
+
+        thread::spawn(move || {
+            let mut units: Vec<String> = Vec::new();
+            let mut job = 0;
+
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let auth = read_line(&mut stream).unwrap();
+                assert!(auth.starts_with("\0AUTH EXTERNAL "));
+                stream.write_all(b"OK 0123456789abcdef\r\n").unwrap();
+                assert_eq!(read_line(&mut stream).unwrap(), "BEGIN");
+
+                while let Ok(call) = read_message(&mut stream) {
+                    let unit = call.body.get(0).and_then(|u| u.as_str()).unwrap_or("");
+                    let mut reply = Message {
+                        msg_type: METHOD_RETURN,
+                        reply_serial: call.serial,
+                        ..Default::default()
+                    };
+                    let mut removed = None;
+
+                    match call.member.as_str() {
+                        "StartTransientUnit" | "StopUnit" => {
+                            if call.member == "StartTransientUnit" {
+                                units.push(unit.to_string());
+                            } else if units.iter().any(|u| u == unit) {
+                                units.retain(|u| u != unit);
+                            } else {
+                                reply.msg_type = ERROR;
+                                reply.error_name = NO_SUCH_UNIT.to_string();
+                            }
+
+                            if reply.msg_type == METHOD_RETURN {
+                                job += 1;
+                                let path = format!("{}/job/{}", SYSTEMD_PATH, job);
+                                reply.body = vec![Value::ObjectPath(path.clone())];
+                                removed = Some(Message {
+                                    msg_type: SIGNAL,
+                                    path: SYSTEMD_PATH.to_string(),
+                                    interface: MANAGER_INTERFACE.to_string(),
+                                    member: "JobRemoved".to_string(),
+                                    body: vec![
+                                        Value::Uint32(job),
+                                        Value::ObjectPath(path),
+                                        Value::Str(unit.to_string()),
+                                        Value::Str("done".to_string()),
+                                    ],
+                                    ..Default::default()
+                                });
+                            }
+                        }
+                        "Subscribe" | "SetUnitProperties" => (),
+                        _ => {
+                            reply.msg_type = ERROR;
+                            reply.error_name =
+                                "org.freedesktop.DBus.Error.UnknownMethod".to_string();
+                        }
+                    }
+
+                    // the job may be done before its reply is sent
+                    if let Some(s) = removed {
+                        stream.write_all(&s.encode()).unwrap();
+                    }
+                    stream.write_all(&reply.encode()).unwrap();
+                    tx.send(call).unwrap();
+                }
+            }
+        });
+
+        rx
+    }
+
+    fn property<'a>(call: &'a Message, name: &str) -> Option<&'a Value> {
+        match call.body.get(2) {
+            Some(Value::Array(_, props)) => props.iter().find_map(|p| match p {
+                Value::Struct(p) if p[0] == Value::Str(name.to_string()) => match &p[1] {
+                    Value::Variant(v) => Some(&**v),
+                    _ => None,
+                },
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_cgroups_path() {
+        assert!(is_systemd_cgroup("system.slice:kata:abc"));
+        assert!(is_systemd_cgroup("::abc"));
+        assert!(!is_systemd_cgroup("/kata/abc"));
+        assert!(!is_systemd_cgroup("kata:abc"));
+
+        assert_eq!(expand_slice("-.slice").unwrap(), "/");
+        assert_eq!(expand_slice("system.slice").unwrap(), "/system.slice");
+        assert_eq!(
+            expand_slice("kata-pod-a.slice").unwrap(),
+            "/kata.slice/kata-pod.slice/kata-pod-a.slice"
+        );
+        assert!(expand_slice("kata").is_err());
+        assert!(expand_slice("kata--a.slice").is_err());
+
+        let m = Manager::new("kata.slice:cri:abc").unwrap();
+        assert_eq!(m.unit, "cri-abc.scope");
+        assert_eq!(m.get_cpath().unwrap(), "/kata.slice/cri-abc.scope");
+        let m = Manager::new("::abc").unwrap();
+        assert_eq!(m.slice, "system.slice");
+        assert_eq!(m.get_cpath().unwrap(), "/system.slice/abc.scope");
+        assert!(Manager::new("kata.slice:cri:").is_err());
+    }
+
+    #[test]
+    fn test_resource_properties() {
+        let mut r = LinuxResources::new();
+        let mut cpu = LinuxCPU::new();
+        cpu.Shares = 1024;
+        cpu.Quota = 50000;
+        cpu.Period = 100000;
+        r.set_CPU(cpu);
+        let mut memory = LinuxMemory::new();
+        memory.Limit = 1 << 30;
+        memory.Swap = -1;
+        r.set_Memory(memory);
+        r.set_Pids(LinuxPids::new());
+
+        let props: HashMap<String, Value> = resource_properties(&r, false)
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(props["CPUShares"], Value::Uint64(1024));
+        assert_eq!(props["CPUQuotaPerSecUSec"], Value::Uint64(500000));
+        assert_eq!(props["MemoryLimit"], Value::Uint64(1 << 30));
+        assert_eq!(props["TasksMax"], Value::Uint64(INFINITY));
+        assert!(!props.contains_key("MemorySwapMax"));
+
+        let props: HashMap<String, Value> =
+            resource_properties(&r, true).unwrap().into_iter().collect();
+        assert_eq!(props["CPUWeight"], Value::Uint64(39));
+        assert_eq!(props["MemoryMax"], Value::Uint64(1 << 30));
+        assert_eq!(props["MemorySwapMax"], Value::Uint64(INFINITY));
+
+        // rounded up to a percent of a cpu
+        assert_eq!(cpu_quota_per_sec(1234, 100000), 20000);
+        assert_eq!(cpu_quota_per_sec(-1, 0), INFINITY);
+    }
+
+    #[test]
+    fn test_unit() {
+        let dir = tempdir().unwrap();
+        let socket = dir.path().join("private");
+        let calls = mock_systemd(&socket);
+
+        let mut m = Manager::new("kata.slice:cri:abc").unwrap();
+        m.socket = socket.to_str().unwrap().to_string();
+
+        let mut r = LinuxResources::new();
+        let mut pids = LinuxPids::new();
+        pids.Limit = 100;
+        r.set_Pids(pids);
+        m.start_unit(42, &r).unwrap();
+
+        assert_eq!(calls.recv().unwrap().member, "Subscribe");
+        let start = calls.recv().unwrap();
+        assert_eq!(start.member, "StartTransientUnit");
+        assert_eq!(start.body[0], Value::Str("cri-abc.scope".to_string()));
+        assert_eq!(
+            property(&start, "PIDs"),
+            Some(&Value::Array("u".to_string(), vec![Value::Uint32(42)]))
+        );
+        assert_eq!(
+            property(&start, "Slice"),
+            Some(&Value::Str("kata.slice".to_string()))
+        );
+        assert_eq!(property(&start, "TasksMax"), Some(&Value::Uint64(100)));
+
+        m.set_unit_properties(&r).unwrap();
+        let set = calls.recv().unwrap();
+        assert_eq!(set.member, "SetUnitProperties");
+        assert_eq!(property(&set, "TasksMax"), Some(&Value::Uint64(100)));
+
+        m.stop_unit().unwrap();
+        assert_eq!(calls.recv().unwrap().member, "Subscribe");
+        assert_eq!(calls.recv().unwrap().member, "StopUnit");
+
+        // already stopped
+        m.stop_unit().unwrap();
+    }
+
+    // Destroyed records whether its cgroup is destroyed
+    #[derive(Debug, Default)]
+    struct Destroyed(Arc<Mutex<bool>>);
+
+    impl CgroupManager for Destroyed {
+        fn destroy(&mut self) -> Result<()> {
+            *self.0.lock().unwrap() = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_destroy() {
+        let dir = tempdir().unwrap();
+        let socket = dir.path().join("private");
+        let socket = socket.to_str().unwrap();
+        assert!(!is_reachable(socket));
+
+        let _calls = mock_systemd(Path::new(socket));
+        assert!(is_reachable(socket));
+
+        let mut m = Manager::new("kata.slice:cri:abc").unwrap();
+        let destroyed = Destroyed::default();
+        let cgroup = destroyed.0.clone();
+        m.cgroup = Box::new(destroyed);
+        m.socket = socket.to_string();
+        m.destroy().unwrap();
+        assert!(*cgroup.lock().unwrap());
+
+        // the cgroups are removed without systemd
+        *cgroup.lock().unwrap() = false;
+        m.socket = dir.path().join("none").to_str().unwrap().to_string();
+        assert!(m.destroy().is_err());
+        assert!(*cgroup.lock().unwrap());
+    }
+}
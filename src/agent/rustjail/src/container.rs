@@ -18,16 +18,23 @@ use protocols::oci::{LinuxDevice, LinuxIDMapping};
 use std::clone::Clone;
 use std::fmt::Display;
 use std::process::Command;
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
 
 // use crate::configs::namespaces::{NamespaceType};
 use crate::cgroups::Manager as CgroupManager;
-use crate::process::Process;
+use crate::process::{Process, UNKNOWN_EXIT_CODE};
 // use crate::intelrdt::Manager as RdtManager;
 use crate::errors::*;
 use crate::specconv::CreateOpts;
 // use crate::stats::Stats;
 use crate::capabilities::{self, CAPSMAP};
-use crate::cgroups::fs::{self as fscgroup, Manager as FsManager};
+use crate::cgroups::fs as fscgroup;
+use crate::cgroups::notifier::{self, PressureLevel};
+use crate::cgroups::{self, systemd, FROZEN, THAWED};
+use crate::criu::{self, CriuOpts};
+use crate::label;
+use crate::seccomp;
 use crate::{mount, validator};
 
 use protocols::agent::StatsContainerResponse;
@@ -37,24 +44,33 @@ use nix::fcntl::{self, OFlag};
 use nix::pty;
 use nix::sched::{self, CloneFlags};
 use nix::sys::signal::{self, Signal};
-use nix::sys::socket::{self, ControlMessage, ControlMessageOwned, MsgFlags};
+use nix::sys::socket::{
+    self, AddressFamily, ControlMessage, ControlMessageOwned, MsgFlags, SockFlag, SockType,
+};
 use nix::sys::stat::{self, Mode};
 use nix::sys::uio::IoVec;
 use nix::unistd::{self, ForkResult, Gid, Pid, Uid};
 use nix::Error;
 
 use libc;
-use protobuf::{CachedSize, SingularPtrField, UnknownFields};
+use protobuf::{CachedSize, Message, SingularPtrField, UnknownFields};
 
 use oci::State as OCIState;
 use std::collections::HashMap;
 
-use slog::{debug, info, o, Logger};
+use slog::{debug, info, o, warn, Logger};
 
 const STATE_FILENAME: &'static str = "state.json";
 const EXEC_FIFO_FILENAME: &'static str = "exec.fifo";
 const VER_MARKER: &'static str = "1.2.5";
 
+// version of the container state files, to be bumped on incompatible
+// changes
+pub const STATE_VERSION: u32 = 1;
+
+// time given to the killed processes to exit, in milliseconds
+const EXIT_TIMEOUT: u64 = 10000;
+
 type Status = Option<String>;
 pub type Config = CreateOpts;
 type NamespaceType = String;
@@ -176,11 +192,21 @@ pub struct BaseState {
     init_process_pid: i32,
     #[serde(default)]
     init_process_start: u64,
-    /*
+    // seconds since the epoch
     #[serde(default)]
-        created: SystemTime,
-        config: Config,
-    */
+    created: u64,
+}
+
+// ProcessState identifies a process across agent restarts, the start time
+// telling it apart from a process reusing its pid.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProcessState {
+    exec_id: String,
+    pid: pid_t,
+    #[serde(default)]
+    init: bool,
+    #[serde(default)]
+    start_time: u64,
 }
 
 pub trait BaseContainer {
@@ -190,7 +216,7 @@ pub trait BaseContainer {
     fn oci_state(&self) -> Result<OCIState>;
     fn config(&self) -> Result<&Config>;
     fn processes(&self) -> Result<Vec<i32>>;
-    fn get_process(&mut self, eid: &str) -> Result<&mut Process>;
+    fn get_process(&self, eid: &str) -> Result<Arc<Mutex<Process>>>;
     fn stats(&self) -> Result<StatsContainerResponse>;
     fn set(&mut self, config: LinuxResources) -> Result<()>;
     fn start(&mut self, p: Process) -> Result<()>;
@@ -200,9 +226,10 @@ pub trait BaseContainer {
     fn exec(&mut self) -> Result<()>;
 }
 
-// LinuxContainer protected by Mutex
-// Arc<Mutex<Innercontainer>> or just Mutex<InnerContainer>?
-// Or use Mutex<xx> as a member of struct, like C?
+// LinuxContainer is shared as Arc<Mutex<LinuxContainer>> by the agent,
+// and each of its processes has a lock of its own, so that a process can
+// be waited on or have its stdio used without holding the container.
+// Locks are always taken in the sandbox -> container -> process order.
 // a lot of String in the struct might be &str
 #[derive(Debug)]
 pub struct LinuxContainer
@@ -211,21 +238,46 @@ pub struct LinuxContainer
     pub id: String,
     pub root: String,
     pub config: Config,
-    pub cgroup_manager: Option<FsManager>,
+    pub cgroup_manager: Option<Box<dyn CgroupManager + Send + Sync>>,
     pub init_process_pid: pid_t,
     pub init_process_start_time: u64,
     pub uid_map_path: String,
     pub gid_map_path: String,
-    pub processes: HashMap<pid_t, Process>,
+    pub processes: HashMap<pid_t, Arc<Mutex<Process>>>,
     pub status: Status,
     pub created: SystemTime,
     pub logger: Logger,
+    // the OOM notifications, registered once the cgroup of the container
+    // is created, for its owner to take
+    pub oom_events: Option<Receiver<String>>,
+    // set once the poststop hooks ran, a checkpointed container being
+    // stopped as well but not destroyed yet
+    destroyed: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct State {
+    #[serde(default)]
+    version: u32,
     base: BaseState,
     #[serde(default)]
+    status: String,
+    // the protobuf encoded spec, in hex
+    #[serde(default)]
+    spec: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    cgroup_name: String,
+    #[serde(default)]
+    use_systemd_cgroup: bool,
+    #[serde(default)]
+    no_pivot_root: bool,
+    #[serde(default)]
+    no_new_keyring: bool,
+    #[serde(default)]
+    rootless_cgroup: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    processes: Vec<ProcessState>,
+    #[serde(default)]
     rootless: bool,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     cgroup_paths: HashMap<String, String>,
@@ -244,12 +296,15 @@ pub struct SyncPC {
 }
 
 pub trait Container: BaseContainer {
-    //	fn checkpoint(&self, opts: &CriuOpts) -> Result<()>;
-    //	fn restore(&self, p: &Process, opts: &CriuOpts) -> Result<()>;
-    fn pause(&self) -> Result<()>;
-    fn resume(&self) -> Result<()>;
-    //	fn notify_oom(&self) -> Result<(Sender, Receiver)>;
-    //	fn notify_memory_pressure(&self, lvl: PressureLevel) -> Result<(Sender, Receiver)>;
+    fn checkpoint(&mut self, opts: &CriuOpts) -> Result<()>;
+    fn restore(&mut self, p: Process, opts: &CriuOpts) -> Result<()>;
+    fn pause(&mut self) -> Result<()>;
+    fn resume(&mut self) -> Result<()>;
+    fn notify_oom(&self) -> Result<Receiver<String>>;
+    fn notify_memory_pressure(
+        &self,
+        lvl: PressureLevel,
+    ) -> Result<(Receiver<String>, notifier::Subscription)>;
 }
 
 impl BaseContainer for LinuxContainer {
@@ -262,7 +317,53 @@ impl BaseContainer for LinuxContainer {
     }
 
     fn state(&self) -> Result<State> {
-        Err(ErrorKind::ErrorCode(String::from("not suppoerted")).into())
+        let spec = match self.config.spec.as_ref() {
+            Some(s) => s.write_to_bytes()?,
+            None => return Err(ErrorKind::InvalidArgument("no spec".to_string()).into()),
+        };
+
+        let processes = self
+            .processes
+            .values()
+            .map(|p| {
+                let p = p.lock().unwrap();
+                ProcessState {
+                    exec_id: p.exec_id.clone(),
+                    pid: p.pid,
+                    init: p.init,
+                    start_time: read_stat(p.pid).map_or(0, |(_, t)| t),
+                }
+            })
+            .collect();
+
+        Ok(State {
+            version: STATE_VERSION,
+            base: BaseState {
+                id: self.id.clone(),
+                init_process_pid: self.init_process_pid,
+                init_process_start: self.init_process_start_time,
+                created: self
+                    .created
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs()),
+            },
+            status: self.status.clone().unwrap_or_default(),
+            spec: to_hex(&spec),
+            cgroup_name: self.config.cgroup_name.clone(),
+            use_systemd_cgroup: self.config.use_systemd_cgroup,
+            no_pivot_root: self.config.no_pivot_root,
+            no_new_keyring: self.config.no_new_keyring,
+            rootless_cgroup: self.config.rootless_cgroup,
+            processes,
+            rootless: self.config.rootless_euid,
+            cgroup_paths: self
+                .cgroup_manager
+                .as_ref()
+                .map_or(HashMap::new(), |cgm| cgm.get_paths().unwrap_or_default()),
+            namespace_paths: HashMap::new(),
+            external_descriptors: Vec::new(),
+            intel_rdt_path: String::new(),
+        })
     }
 
     fn oci_state(&self) -> Result<OCIState> {
@@ -295,14 +396,14 @@ impl BaseContainer for LinuxContainer {
         Ok(self.processes.keys().cloned().collect())
     }
 
-    fn get_process(&mut self, eid: &str) -> Result<&mut Process> {
-        for (_, v) in self.processes.iter_mut() {
-            if eid == v.exec_id.as_str() {
-                return Ok(v);
+    fn get_process(&self, eid: &str) -> Result<Arc<Mutex<Process>>> {
+        for (_, v) in self.processes.iter() {
+            if eid == v.lock().unwrap().exec_id.as_str() {
+                return Ok(v.clone());
             }
         }
 
-        Err(ErrorKind::ErrorCode(format!("invalid eid {}", eid)).into())
+        Err(ErrorKind::NotFound(format!("invalid eid {}", eid)).into())
     }
 
     fn stats(&self) -> Result<StatsContainerResponse> {
@@ -336,10 +437,17 @@ impl BaseContainer for LinuxContainer {
     fn start(&mut self, mut p: Process) -> Result<()> {
         let fifo_file = format!("{}/{}", &self.root, EXEC_FIFO_FILENAME);
         info!(self.logger, "enter container.start!");
+        if !p.init && self.is_paused() {
+            return Err(ErrorKind::FailedPrecondition(format!(
+                "cannot exec in paused container {}",
+                &self.id
+            ))
+            .into());
+        }
         let mut fifofd: RawFd = -1;
         if p.init {
             if let Ok(_) = stat::stat(fifo_file.as_str()) {
-                return Err(ErrorKind::ErrorCode("exec fifo exists".to_string()).into());
+                return Err(ErrorKind::AlreadyExists("exec fifo exists".to_string()).into());
             }
             unistd::mkfifo(fifo_file.as_str(), Mode::from_bits(0o622).unwrap())?;
             // defer!(fs::remove_file(&fifo_file)?);
@@ -359,15 +467,26 @@ impl BaseContainer for LinuxContainer {
         fscgroup::init_static();
 
         if self.config.spec.is_none() {
-            return Err(ErrorKind::ErrorCode("no spec".to_string()).into());
+            return Err(ErrorKind::InvalidArgument("no spec".to_string()).into());
         }
 
         let spec = self.config.spec.as_ref().unwrap();
         if spec.Linux.is_none() {
-            return Err(ErrorKind::ErrorCode("no linux config".to_string()).into());
+            return Err(ErrorKind::InvalidArgument("no linux config".to_string()).into());
         }
 
         let linux = spec.Linux.as_ref().unwrap();
+
+        // the profile is compiled before forking, so that a bad one
+        // fails the request
+        let seccomp_filter = match linux.Seccomp.as_ref() {
+            Some(s) => Some(seccomp::compile(s)?),
+            None => None,
+        };
+        let seccomp_notify = seccomp_filter
+            .as_ref()
+            .map_or(false, |f| f.needs_listener());
+
         // get namespace vector to join/new
         let nses = get_namespaces(&linux, p.init, self.init_process_pid)?;
         info!(self.logger, "got namespaces {:?}!\n", nses);
@@ -378,7 +497,7 @@ impl BaseContainer for LinuxContainer {
         for ns in &nses {
             let s = NAMESPACES.get(&ns.Type.as_str());
             if s.is_none() {
-                return Err(ErrorKind::ErrorCode("invalid ns type".to_string()).into());
+                return Err(ErrorKind::InvalidArgument("invalid ns type".to_string()).into());
             }
             let s = s.unwrap();
 
@@ -411,6 +530,19 @@ impl BaseContainer for LinuxContainer {
             userns = true;
         }
 
+        // the listener of the notified system calls is handed over to
+        // the agent, like the console master fd
+        let seccomp_socket = if seccomp_notify {
+            Some(socket::socketpair(
+                AddressFamily::Unix,
+                SockType::Stream,
+                None,
+                SockFlag::SOCK_CLOEXEC,
+            )?)
+        } else {
+            None
+        };
+
         let mut parent: u32 = 0;
         let st = self.oci_state()?;
 
@@ -423,7 +555,7 @@ impl BaseContainer for LinuxContainer {
             userns,
             p.init,
             self.config.no_pivot_root,
-            self.cgroup_manager.as_ref().unwrap(),
+            self.cgroup_manager.as_ref().unwrap().as_ref(),
             &st,
             &mut parent,
         ) {
@@ -449,6 +581,7 @@ impl BaseContainer for LinuxContainer {
             if p.init {
                 self.init_process_pid = p.pid;
                 unistd::close(fifofd)?;
+                self.register_oom();
             }
             self.created = SystemTime::now();
             // defer!({ self.processes.insert(p.pid, p); () });
@@ -463,6 +596,13 @@ impl BaseContainer for LinuxContainer {
                 let _ = unistd::close(fd);
             }
 
+            if let Some((psock, csock)) = seccomp_socket {
+                unistd::close(csock)?;
+                let listener = recv_seccomp_listener(psock);
+                let _ = unistd::close(psock);
+                p.seccomp_listener = Some(listener?);
+            }
+
             // create the pipes for notify process exited
             let (exit_pipe_r, exit_pipe_w) =
                 unistd::pipe2(OFlag::O_CLOEXEC).chain_err(|| "failed to create pipe")?;
@@ -472,7 +612,7 @@ impl BaseContainer for LinuxContainer {
             let console_fd = if p.parent_console_socket.is_some() {
                 p.parent_console_socket.unwrap()
             } else {
-                self.processes.insert(p.pid, p);
+                self.processes.insert(p.pid, Arc::new(Mutex::new(p)));
                 return Ok(());
             };
 
@@ -520,7 +660,7 @@ impl BaseContainer for LinuxContainer {
             // term.local_flags &= !(LocalFlags::ECHO | LocalFlags::ICANON);
             // termios::tcsetattr(p.term_master.unwrap(), SetArg::TCSANOW, &term)?;
 
-            self.processes.insert(p.pid, p);
+            self.processes.insert(p.pid, Arc::new(Mutex::new(p)));
 
             return Ok(());
         } // end parent
@@ -540,6 +680,18 @@ impl BaseContainer for LinuxContainer {
             unistd::chdir(p.oci.Cwd.as_str())?;
         }
 
+        // the labels apply once the process execs
+        label::set_exec_labels(&p.oci.ApparmorProfile, &p.oci.SelinuxLabel)?;
+
+        // without no_new_privs, the filter needs the capabilities which
+        // are about to be dropped, so it's loaded now
+        let mut seccomp_listener = None;
+        if !p.oci.NoNewPrivileges {
+            if let Some(filter) = seccomp_filter.as_ref() {
+                seccomp_listener = filter.load()?;
+            }
+        }
+
         // setup uid/gid
         info!(self.logger, "{:?}", p.oci.clone());
 
@@ -571,6 +723,21 @@ impl BaseContainer for LinuxContainer {
             capabilities::drop_priviledges(&self.logger, c)?;
         }
 
+        // the agent waits for the listener before the init process
+        // waits to be started
+        if p.oci.NoNewPrivileges && seccomp_notify {
+            seccomp_listener = seccomp_filter.as_ref().unwrap().load()?;
+        }
+
+        if let Some((psock, csock)) = seccomp_socket {
+            unistd::close(psock)?;
+            if let Some(listener) = seccomp_listener {
+                send_seccomp_listener(csock, listener)?;
+                unistd::close(listener)?;
+            }
+            unistd::close(csock)?;
+        }
+
         if p.init {
             // notify parent to run poststart hooks
             // cfd is closed when return from join_namespaces
@@ -599,6 +766,13 @@ impl BaseContainer for LinuxContainer {
         // exec process
         let args = p.oci.Args.to_vec();
         let env = p.oci.Env.to_vec();
+
+        if p.oci.NoNewPrivileges && !seccomp_notify {
+            if let Some(filter) = seccomp_filter.as_ref() {
+                filter.load()?;
+            }
+        }
+
         do_exec(&self.logger, &args[0], &args, &env)?;
 
         Err(ErrorKind::ErrorCode("fail to create container".to_string()).into())
@@ -617,29 +791,86 @@ impl BaseContainer for LinuxContainer {
     }
 
     fn destroy(&mut self) -> Result<()> {
+        // the poststop hooks only run once
+        if self.destroyed {
+            return Ok(());
+        }
+
         let spec = self.config.spec.as_ref().unwrap();
         let st = self.oci_state()?;
 
-        for pid in self.processes.keys() {
-            signal::kill(Pid::from_raw(*pid), Some(Signal::SIGKILL))?;
+        // the tasks forked by the container processes are only known
+        // by the cgroup
+        let mut pids: Vec<pid_t> = self.processes.keys().cloned().collect();
+        if let Some(cgm) = self.cgroup_manager.as_ref() {
+            for pid in cgm.get_all_pids().unwrap_or_default() {
+                if !pids.contains(&pid) {
+                    pids.push(pid);
+                }
+            }
+        }
+
+        for pid in pids.iter() {
+            match signal::kill(Pid::from_raw(*pid), Some(Signal::SIGKILL)) {
+                Ok(()) | Err(Error::Sys(Errno::ESRCH)) => (),
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        // frozen tasks won't act on SIGKILL until they are thawed
+        if self.is_paused() {
+            if let Some(cgm) = self.cgroup_manager.as_ref() {
+                cgm.freeze(THAWED)?;
+            }
+        }
+
+        // the poststop hooks run once the processes are all gone
+        if !wait_exited(&pids, EXIT_TIMEOUT) {
+            return Err(ErrorKind::DeadlineExceeded(format!(
+                "processes of container {} still running after {}ms",
+                self.id, EXIT_TIMEOUT
+            ))
+            .into());
         }
 
+        // all the hooks run even if one of them fails
+        let mut hook_err = None;
         if spec.Hooks.is_some() {
             info!(self.logger, "poststop");
             let hooks = spec.Hooks.as_ref().unwrap();
             for h in hooks.Poststop.iter() {
-                execute_hook(&self.logger, h, &st)?;
+                if let Err(e) = execute_hook(&self.logger, h, &st) {
+                    warn!(self.logger, "poststop hook {} failed: {:?}", h.Path, e);
+                    hook_err.get_or_insert(e);
+                }
             }
         }
 
         self.status = Some("stopped".to_string());
-        Ok(())
+        self.destroyed = true;
+
+        match hook_err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
     }
 
     fn signal(&self, sig: Signal, all: bool) -> Result<()> {
+        if self.is_paused() {
+            return Err(ErrorKind::FailedPrecondition(format!(
+                "cannot signal paused container {}",
+                &self.id
+            ))
+            .into());
+        }
+
+        // the exec processes may be gone already
         if all {
             for pid in self.processes.keys() {
-                signal::kill(Pid::from_raw(*pid), Some(sig))?;
+                match signal::kill(Pid::from_raw(*pid), Some(sig)) {
+                    Ok(()) | Err(Error::Sys(Errno::ESRCH)) => (),
+                    Err(e) => return Err(e.into()),
+                }
             }
         }
 
@@ -666,6 +897,228 @@ impl BaseContainer for LinuxContainer {
     }
 }
 
+impl Container for LinuxContainer {
+    fn pause(&mut self) -> Result<()> {
+        let status = self.status.clone().unwrap_or_default();
+        if status != "running" && status != "created" {
+            return Err(ErrorKind::FailedPrecondition(format!(
+                "container {} is {}, cannot be paused",
+                &self.id, status
+            ))
+            .into());
+        }
+
+        if self.cgroup_manager.is_none() {
+            return Err(ErrorKind::FailedPrecondition(
+                "failed to get container cgroup manager".to_string(),
+            )
+            .into());
+        }
+
+        self.cgroup_manager.as_ref().unwrap().freeze(FROZEN)?;
+
+        info!(self.logger, "container {} paused", &self.id);
+        self.status = Some("paused".to_string());
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        if !self.is_paused() {
+            return Err(ErrorKind::FailedPrecondition(format!(
+                "container {} is not paused",
+                &self.id
+            ))
+            .into());
+        }
+
+        if self.cgroup_manager.is_none() {
+            return Err(ErrorKind::FailedPrecondition(
+                "failed to get container cgroup manager".to_string(),
+            )
+            .into());
+        }
+
+        self.cgroup_manager.as_ref().unwrap().freeze(THAWED)?;
+
+        info!(self.logger, "container {} resumed", &self.id);
+        self.status = Some("running".to_string());
+        Ok(())
+    }
+
+    fn checkpoint(&mut self, opts: &CriuOpts) -> Result<()> {
+        let status = self.status.clone().unwrap_or_default();
+        if status != "running" && status != "paused" {
+            return Err(ErrorKind::FailedPrecondition(format!(
+                "container {} is {}, cannot be checkpointed",
+                &self.id, status
+            ))
+            .into());
+        }
+
+        let spec = criu_spec(&self.config)?;
+        let root = criu_root(spec)?;
+
+        if opts.images_directory.is_empty() {
+            return Err(ErrorKind::InvalidArgument("no images directory".to_string()).into());
+        }
+        fs::create_dir_all(&opts.images_directory)?;
+        fs::create_dir_all(opts.work_directory())?;
+
+        // the pipes of the init process stdio are replaced at restore
+        let descriptors: Vec<String> = (0..3)
+            .map(|fd| {
+                fs::read_link(format!("/proc/{}/fd/{}", self.init_process_pid, fd))
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            })
+            .collect();
+        let path = format!("{}/{}", opts.images_directory, criu::DESCRIPTORS_FILENAME);
+        fs::write(&path, serde_json::to_vec(&descriptors)?)
+            .chain_err(|| format!("cannot write {}", path))?;
+
+        let freezer = self.get_cgroup_path("freezer");
+        let args = criu::dump_args(
+            opts,
+            self.init_process_pid,
+            root.as_str(),
+            spec,
+            freezer.as_ref().map(|f| f.as_str()),
+        );
+        info!(self.logger, "checkpointing container {}", &self.id; "args" => format!("{:?}", args));
+        criu::run(&args, &[], &[])?;
+
+        if opts.leave_running {
+            // criu thaws the tasks it froze
+            if status == "paused" {
+                self.cgroup_manager.as_ref().unwrap().freeze(FROZEN)?;
+            }
+        } else {
+            // the checkpointed processes are killed by criu
+            self.status = Some("stopped".to_string());
+        }
+
+        info!(self.logger, "container {} checkpointed", &self.id);
+        Ok(())
+    }
+
+    fn restore(&mut self, mut p: Process, opts: &CriuOpts) -> Result<()> {
+        if !p.init || !self.processes.is_empty() {
+            return Err(ErrorKind::FailedPrecondition(format!(
+                "container {} has already been started",
+                &self.id
+            ))
+            .into());
+        }
+
+        let spec = criu_spec(&self.config)?;
+        let root = criu_root(spec)?;
+
+        let path = format!("{}/{}", opts.images_directory, criu::DESCRIPTORS_FILENAME);
+        let content = fs::read(&path).chain_err(|| format!("cannot read {}", path))?;
+        let descriptors: Vec<String> = serde_json::from_slice(&content)?;
+
+        fs::create_dir_all(opts.work_directory())?;
+        let _ = fs::remove_file(opts.pid_file());
+
+        // criu joins the container cgroups before restoring the tasks,
+        // which then are created in them
+        let cgm = match self.cgroup_manager.as_ref() {
+            Some(cgm) => cgm,
+            None => {
+                return Err(ErrorKind::FailedPrecondition(
+                    "failed to get container cgroup manager".to_string(),
+                )
+                .into())
+            }
+        };
+        let resources = spec
+            .Linux
+            .as_ref()
+            .and_then(|l| l.Resources.as_ref())
+            .cloned()
+            .unwrap_or_default();
+        cgm.set(&resources, false)?;
+
+        let mut procs = Vec::new();
+        for dir in cgm.get_paths()?.values() {
+            let path = format!("{}/{}", dir, fscgroup::CGROUP_PROCS);
+            procs.push(
+                OpenOptions::new()
+                    .write(true)
+                    .open(&path)
+                    .chain_err(|| format!("cannot open {}", path))?,
+            );
+        }
+
+        // the stdio pipes of the process stand for the checkpointed ones
+        let mut inherited = Vec::new();
+        for (fd, descriptor) in [p.stdin, p.stdout, p.stderr].iter().zip(descriptors.iter()) {
+            if let Some(fd) = fd {
+                if descriptor.starts_with("pipe:") {
+                    inherited.push((*fd, descriptor.clone()));
+                }
+            }
+        }
+        let fds: Vec<RawFd> = inherited.iter().map(|(fd, _)| *fd).collect();
+
+        let args = criu::restore_args(
+            opts,
+            root.as_str(),
+            spec,
+            &inherited,
+            cgm.get_cpath()?.as_str(),
+        );
+        info!(self.logger, "restoring container {}", &self.id; "args" => format!("{:?}", args));
+        criu::run(&args, &procs, &fds)?;
+
+        let pid_file = opts.pid_file();
+        let pid = fs::read_to_string(&pid_file)
+            .chain_err(|| format!("cannot read {}", pid_file))?
+            .trim()
+            .parse::<pid_t>()
+            .chain_err(|| format!("invalid pid in {}", pid_file))?;
+
+        // the child ends are the restored process own
+        for fd in [p.stdin.take(), p.stdout.take(), p.stderr.take()].iter() {
+            if let Some(fd) = fd {
+                let _ = unistd::close(*fd);
+            }
+        }
+
+        let (exit_pipe_r, exit_pipe_w) =
+            unistd::pipe2(OFlag::O_CLOEXEC).chain_err(|| "failed to create pipe")?;
+        p.exit_pipe_w = Some(exit_pipe_w);
+        p.exit_pipe_r = Some(exit_pipe_r);
+        p.pid = pid;
+
+        self.init_process_pid = pid;
+        self.init_process_start_time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        self.created = SystemTime::now();
+        self.status = Some("running".to_string());
+        self.processes.insert(pid, Arc::new(Mutex::new(p)));
+        self.register_oom();
+
+        info!(self.logger, "container {} restored", &self.id; "pid" => pid);
+        Ok(())
+    }
+
+    fn notify_oom(&self) -> Result<Receiver<String>> {
+        let dir = self.get_memory_cgroup_path()?;
+        notifier::notify_oom(self.id.as_str(), dir.as_str())
+    }
+
+    fn notify_memory_pressure(
+        &self,
+        lvl: PressureLevel,
+    ) -> Result<(Receiver<String>, notifier::Subscription)> {
+        let dir = self.get_memory_cgroup_path()?;
+        notifier::notify_memory_pressure(dir.as_str(), lvl)
+    }
+}
+
 use std::env;
 
 fn do_exec(logger: &Logger, path: &str, args: &[String], env: &[String]) -> Result<()> {
@@ -785,6 +1238,35 @@ fn read_sync(fd: RawFd) -> Result<pid_t> {
     Ok(pid_t::from_be_bytes(v))
 }
 
+fn send_seccomp_listener(sock: RawFd, listener: RawFd) -> Result<()> {
+    let data: &[u8] = b"seccomp";
+    let iov = [IoVec::from_slice(&data)];
+    let fds = [listener];
+    let cmsg = ControlMessage::ScmRights(&fds);
+
+    socket::sendmsg(sock, &iov, &[cmsg], MsgFlags::empty(), None)?;
+
+    Ok(())
+}
+
+// recv_seccomp_listener fails if the process died before sending it.
+fn recv_seccomp_listener(sock: RawFd) -> Result<RawFd> {
+    let mut v: Vec<u8> = vec![0; 40];
+    let iov = IoVec::from_mut_slice(v.as_mut_slice());
+    let mut c: Vec<u8> = vec![0; 40];
+
+    let msg = socket::recvmsg(sock, &[iov], Some(&mut c), MsgFlags::empty())?;
+    for cmsg in msg.cmsgs() {
+        if let ControlMessageOwned::ScmRights(fds) = cmsg {
+            if fds.len() == 1 {
+                return Ok(fds[0]);
+            }
+        }
+    }
+
+    Err(ErrorKind::ErrorCode("no seccomp listener received".to_string()).into())
+}
+
 fn write_sync(fd: RawFd, pid: pid_t) -> Result<()> {
     let buf = pid.to_be_bytes();
     let mut len = 0;
@@ -818,7 +1300,7 @@ fn join_namespaces(
     userns: bool,
     init: bool,
     no_pivot: bool,
-    cm: &FsManager,
+    cm: &(dyn CgroupManager + Send + Sync),
     st: &OCIState,
     parent: &mut u32,
 ) -> Result<(Pid, RawFd)> {
@@ -1071,7 +1553,13 @@ fn join_namespaces(
     if to_new.contains(CloneFlags::CLONE_NEWNS) {
         // setup rootfs
         info!(logger, "setup rootfs!");
-        mount::init_rootfs(&logger, &spec, &cm.paths, &cm.mounts, bind_device)?;
+        mount::init_rootfs(
+            &logger,
+            &spec,
+            &cm.get_paths()?,
+            &cm.get_mounts()?,
+            bind_device,
+        )?;
     }
 
     // wait until parent notified
@@ -1242,11 +1730,19 @@ impl LinuxContainer {
     pub fn new<T: Into<String> + Display + Clone>(
         id: T,
         base: T,
-        config: Config,
+        mut config: Config,
         logger: &Logger,
     ) -> Result<Self> {
         let base = base.into();
         let id = id.into();
+
+        if config.use_systemd_cgroup && !systemd::is_reachable(systemd::SYSTEMD_SOCKET) {
+            warn!(
+                logger,
+                "systemd is unreachable, the cgroups of container {} are managed without it", id
+            );
+            config.use_systemd_cgroup = false;
+        }
         let root = format!("{}/{}", base.as_str(), id.as_str());
 
         // validate oci spec
@@ -1271,21 +1767,11 @@ impl LinuxContainer {
             return Err(nix::Error::Sys(Errno::EINVAL).into());
         }
 
-        let spec = config.spec.as_ref().unwrap();
-
-        if spec.Linux.is_none() {
-            return Err(nix::Error::Sys(Errno::EINVAL).into());
-        }
-
-        let linux = spec.Linux.as_ref().unwrap();
-
-        let cpath = if linux.CgroupsPath.is_empty() {
-            format!("/{}", id.as_str())
-        } else {
-            linux.CgroupsPath.clone()
-        };
-
-        let cgroup_manager = FsManager::new(cpath.as_str())?;
+        let cgroup_manager = new_cgroup_manager(
+            id.as_str(),
+            config.spec.as_ref().unwrap(),
+            config.use_systemd_cgroup,
+        )?;
 
         Ok(LinuxContainer {
             id: id,
@@ -1303,11 +1789,136 @@ impl LinuxContainer {
                 .unwrap()
                 .as_secs(),
             logger: logger.new(o!("module" => "rustjail", "subsystem" => "container")),
+            oom_events: None,
+            destroyed: false,
         })
     }
 
-    fn load<T: Into<String>>(_id: T, _base: T) -> Result<Self> {
-        Err(ErrorKind::ErrorCode("not supported".to_string()).into())
+    // save writes the state of the container in its root directory.
+    pub fn save(&self) -> Result<()> {
+        let state = self.state()?;
+        let path = format!("{}/{}", self.root, STATE_FILENAME);
+        let tmp = format!("{}.tmp", path);
+
+        // the state is replaced at once, a crash leaves the previous one
+        fs::write(&tmp, serde_json::to_vec(&state)?)
+            .chain_err(|| format!("cannot write {}", tmp))?;
+        fs::rename(&tmp, &path).chain_err(|| format!("cannot rename {}", tmp))?;
+
+        Ok(())
+    }
+
+    // load rebuilds a container from the state saved by a previous agent.
+    // Its processes can be signaled and waited for, but their stdio is
+    // gone, and so is the exit code of those which exited meanwhile.
+    pub fn load<T: Into<String>>(id: T, base: T, logger: &Logger) -> Result<Self> {
+        let id = id.into();
+        let root = format!("{}/{}", base.into(), id);
+        let path = format!("{}/{}", root, STATE_FILENAME);
+
+        let content = fs::read(&path).chain_err(|| format!("cannot read {}", path))?;
+        let state: State = serde_json::from_slice(&content)?;
+
+        if state.version != STATE_VERSION {
+            return Err(ErrorKind::InvalidArgument(format!(
+                "unsupported version {} of {}",
+                state.version, path
+            ))
+            .into());
+        }
+
+        if state.base.id != id {
+            return Err(ErrorKind::InvalidArgument(format!(
+                "{} is the state of container {}",
+                path, state.base.id
+            ))
+            .into());
+        }
+
+        let spec: Spec = protobuf::parse_from_bytes(&from_hex(state.spec.as_str())?)?;
+        let cgroup_manager = new_cgroup_manager(id.as_str(), &spec, state.use_systemd_cgroup)?;
+        let logger = logger.new(o!("module" => "rustjail", "subsystem" => "container"));
+
+        let mut processes = HashMap::new();
+        for ps in state.processes.iter() {
+            let mut p = Process::adopt(&logger, ps.exec_id.as_str(), ps.pid, ps.init)?;
+
+            let running = match read_stat(ps.pid) {
+                Some((state, start_time)) => {
+                    start_time == ps.start_time && state != 'Z' && state != 'X'
+                }
+                None => false,
+            };
+
+            if !running {
+                info!(logger, "process exited while the agent was away"; "pid" => ps.pid);
+                p.exited(UNKNOWN_EXIT_CODE);
+            }
+
+            processes.insert(ps.pid, Arc::new(Mutex::new(p)));
+        }
+
+        let mut ctr = LinuxContainer {
+            id,
+            root,
+            cgroup_manager: Some(cgroup_manager),
+            status: Some(state.status),
+            uid_map_path: String::from(""),
+            gid_map_path: "".to_string(),
+            config: CreateOpts {
+                cgroup_name: state.cgroup_name,
+                use_systemd_cgroup: state.use_systemd_cgroup,
+                no_pivot_root: state.no_pivot_root,
+                no_new_keyring: state.no_new_keyring,
+                spec: Some(spec),
+                rootless_euid: state.rootless,
+                rootless_cgroup: state.rootless_cgroup,
+            },
+            processes,
+            created: SystemTime::UNIX_EPOCH + Duration::from_secs(state.base.created),
+            init_process_pid: state.base.init_process_pid,
+            init_process_start_time: state.base.init_process_start,
+            logger,
+            oom_events: None,
+            destroyed: false,
+        };
+
+        if ctr.init_process_pid > 0 {
+            ctr.register_oom();
+        }
+
+        Ok(ctr)
+    }
+
+    fn register_oom(&mut self) {
+        match self.notify_oom() {
+            Ok(rx) => self.oom_events = Some(rx),
+            Err(e) => warn!(self.logger, "fail to register OOM notification: {}", e),
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.status.as_ref().map_or(false, |s| s == "paused")
+    }
+
+    // get_cgroup_path returns the cgroup of a subsystem, which is the
+    // unified one on cgroup v2.
+    fn get_cgroup_path(&self, subsystem: &str) -> Option<String> {
+        self.cgroup_manager
+            .as_ref()
+            .and_then(|cgm| cgm.get_paths().ok())
+            .and_then(|paths| paths.get(subsystem).or_else(|| paths.get("")).cloned())
+    }
+
+    fn get_memory_cgroup_path(&self) -> Result<String> {
+        match self.get_cgroup_path("memory") {
+            Some(d) => Ok(d),
+            None => Err(ErrorKind::NotFound(format!(
+                "no memory cgroup for container {}",
+                &self.id
+            ))
+            .into()),
+        }
     }
     /*
         fn new_parent_process(&self, p: &Process) -> Result<Box<ParentProcess>> {
@@ -1440,7 +2051,130 @@ use std::os::unix::process::ExitStatusExt;
 use std::process::Stdio;
 use std::sync::mpsc::{self, RecvTimeoutError};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+// wait_exited waits for the pids to be gone or zombies, for at most
+// timeout milliseconds. The zombies are left to the agent reaper, which
+// may be waiting for the container lock.
+fn wait_exited(pids: &[pid_t], timeout: u64) -> bool {
+    let deadline = Instant::now() + Duration::from_millis(timeout);
+
+    loop {
+        if pids.iter().all(|pid| has_exited(*pid)) {
+            return true;
+        }
+
+        if Instant::now() >= deadline {
+            return false;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn has_exited(pid: pid_t) -> bool {
+    match read_stat(pid) {
+        Some((state, _)) => state == 'Z' || state == 'X',
+        None => true,
+    }
+}
+
+// criu_spec returns the spec of a container criu can handle: a terminal
+// can't be given back to a restored process, and a shared pid namespace
+// can't be checkpointed without the processes of the other containers.
+fn criu_spec(config: &Config) -> Result<&Spec> {
+    let spec = match config.spec.as_ref() {
+        Some(s) => s,
+        None => return Err(ErrorKind::InvalidArgument("no spec".to_string()).into()),
+    };
+
+    if spec.Process.as_ref().map_or(false, |p| p.Terminal) {
+        return Err(ErrorKind::FailedPrecondition(
+            "cannot checkpoint or restore a container with a terminal".to_string(),
+        )
+        .into());
+    }
+
+    if let Some(linux) = spec.Linux.as_ref() {
+        if linux
+            .Namespaces
+            .iter()
+            .any(|ns| ns.Type == "pid" && !ns.Path.is_empty())
+        {
+            return Err(ErrorKind::FailedPrecondition(
+                "cannot checkpoint or restore a container sharing its pid namespace".to_string(),
+            )
+            .into());
+        }
+    }
+
+    Ok(spec)
+}
+
+fn criu_root(spec: &Spec) -> Result<String> {
+    let root = match spec.Root.as_ref() {
+        Some(r) => r.Path.as_str(),
+        None => return Err(ErrorKind::InvalidArgument("no root".to_string()).into()),
+    };
+
+    Ok(fs::canonicalize(root)?.to_string_lossy().to_string())
+}
+
+// read_stat returns the state and start time of a process, from its
+// /proc/<pid>/stat file.
+fn read_stat(pid: pid_t) -> Option<(char, u64)> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+
+    // the fields follow the command name, which is between parentheses
+    let fields: Vec<&str> = stat[stat.rfind(')')? + 1..].split_whitespace().collect();
+    let state = fields.get(0)?.chars().next()?;
+    let start_time = fields.get(19)?.parse::<u64>().ok()?;
+
+    Some((state, start_time))
+}
+
+fn new_cgroup_manager(
+    id: &str,
+    spec: &Spec,
+    use_systemd: bool,
+) -> Result<Box<dyn CgroupManager + Send + Sync>> {
+    let linux = match spec.Linux.as_ref() {
+        Some(l) => l,
+        None => return Err(nix::Error::Sys(Errno::EINVAL).into()),
+    };
+
+    if use_systemd {
+        return Ok(Box::new(systemd::Manager::new(&linux.CgroupsPath)?));
+    }
+
+    // a systemd cgroups path is no directory
+    let cpath = if linux.CgroupsPath.is_empty() || systemd::is_systemd_cgroup(&linux.CgroupsPath) {
+        format!("/{}", id)
+    } else {
+        linux.CgroupsPath.clone()
+    };
+
+    cgroups::new_manager(cpath.as_str())
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return Err(ErrorKind::InvalidArgument("odd length hex string".to_string()).into());
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| {
+                ErrorKind::InvalidArgument(format!("invalid hex string {}", &s[i..i + 2])).into()
+            })
+        })
+        .collect()
+}
 
 fn execute_hook(logger: &Logger, h: &Hook, st: &OCIState) -> Result<()> {
     let logger = logger.new(o!("action" => "execute-hook"));
@@ -1455,6 +2189,8 @@ fn execute_hook(logger: &Logger, h: &Hook, st: &OCIState) -> Result<()> {
     let envs = h.Env.clone();
     let state = serde_json::to_string(st)?;
     //	state.push_str("\n");
+    // hooks might read the spec from their working directory
+    let bundle = st.bundle.clone();
 
     let (rfd, wfd) = unistd::pipe2(OFlag::O_CLOEXEC)?;
     match unistd::fork()? {
@@ -1499,6 +2235,7 @@ fn execute_hook(logger: &Logger, h: &Hook, st: &OCIState) -> Result<()> {
 
                 let mut child = Command::new(path.to_str().unwrap())
                     .args(args.iter())
+                    .current_dir(bundle.as_str())
                     .envs(env.iter())
                     .stdin(Stdio::piped())
                     .stdout(Stdio::piped())
@@ -1595,3 +2332,188 @@ fn execute_hook(logger: &Logger, h: &Hook, st: &OCIState) -> Result<()> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cgroups::FreezerState;
+    use protocols::oci::{Hooks, Root};
+    use std::os::unix::process::ExitStatusExt;
+    use tempfile::tempdir;
+
+    // Freezer records the states its cgroup is set to
+    #[derive(Debug, Default)]
+    struct Freezer {
+        states: Arc<Mutex<Vec<FreezerState>>>,
+    }
+
+    impl CgroupManager for Freezer {
+        fn freeze(&self, state: FreezerState) -> Result<()> {
+            self.states.lock().unwrap().push(state);
+            Ok(())
+        }
+    }
+
+    // Paths only has the cgroup paths
+    #[derive(Debug, Default)]
+    struct Paths(HashMap<String, String>);
+
+    impl CgroupManager for Paths {
+        fn get_paths(&self) -> Result<HashMap<String, String>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn new_container(base: &str) -> LinuxContainer {
+        let rootfs = format!("{}/rootfs", base);
+        fs::create_dir_all(&rootfs).unwrap();
+        let mut root = Root::new();
+        root.Path = rootfs;
+        let mut spec = Spec::new();
+        spec.Root = SingularPtrField::some(root);
+        spec.Linux = SingularPtrField::some(Linux::new());
+
+        let opts = CreateOpts {
+            cgroup_name: "".to_string(),
+            use_systemd_cgroup: false,
+            no_pivot_root: false,
+            no_new_keyring: false,
+            spec: Some(spec),
+            rootless_euid: false,
+            rootless_cgroup: false,
+        };
+        let logger = slog::Logger::root(slog::Discard, o!());
+
+        LinuxContainer::new("foo", base, opts, &logger).unwrap()
+    }
+
+    #[test]
+    fn test_signal_paused() {
+        let dir = tempdir().unwrap();
+        let mut ctr = new_container(dir.path().to_str().unwrap());
+        let freezer = Freezer::default();
+        let states = freezer.states.clone();
+        ctr.cgroup_manager = Some(Box::new(freezer));
+
+        let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+        ctr.init_process_pid = child.id() as pid_t;
+        ctr.status = Some("paused".to_string());
+
+        // a paused container is left as is
+        assert!(ctr.signal(Signal::SIGCONT, false).is_err());
+        assert!(ctr.signal(Signal::SIGKILL, false).is_err());
+        assert!(states.lock().unwrap().is_empty());
+        assert!(ctr.is_paused());
+
+        ctr.resume().unwrap();
+        assert_eq!(*states.lock().unwrap(), vec![THAWED]);
+        ctr.signal(Signal::SIGKILL, false).unwrap();
+        assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+    }
+
+    #[test]
+    fn test_systemd_unreachable() {
+        if systemd::is_reachable(systemd::SYSTEMD_SOCKET) {
+            return;
+        }
+
+        let dir = tempdir().unwrap();
+        let base = dir.path().to_str().unwrap();
+        let rootfs = format!("{}/rootfs", base);
+        fs::create_dir_all(&rootfs).unwrap();
+        let mut root = Root::new();
+        root.Path = rootfs;
+        let mut linux = Linux::new();
+        linux.CgroupsPath = "system.slice:kata:foo".to_string();
+        let mut spec = Spec::new();
+        spec.Root = SingularPtrField::some(root);
+        spec.Linux = SingularPtrField::some(linux);
+
+        let opts = CreateOpts {
+            cgroup_name: "".to_string(),
+            use_systemd_cgroup: true,
+            no_pivot_root: false,
+            no_new_keyring: false,
+            spec: Some(spec),
+            rootless_euid: false,
+            rootless_cgroup: false,
+        };
+        let logger = slog::Logger::root(slog::Discard, o!());
+
+        // the cgroup filesystem is used instead
+        let ctr = LinuxContainer::new("foo", base, opts, &logger).unwrap();
+        assert!(!ctr.config.use_systemd_cgroup);
+        assert_eq!(
+            ctr.cgroup_manager.as_ref().unwrap().get_cpath().unwrap(),
+            "/foo"
+        );
+    }
+
+    #[test]
+    fn test_get_memory_cgroup_path() {
+        let dir = tempdir().unwrap();
+        let mut ctr = new_container(dir.path().to_str().unwrap());
+        ctr.cgroup_manager = None;
+        assert!(ctr.get_memory_cgroup_path().is_err());
+
+        let mut paths = HashMap::new();
+        paths.insert("cpu".to_string(), "/sys/fs/cgroup/cpu/foo".to_string());
+        ctr.cgroup_manager = Some(Box::new(Paths(paths.clone())));
+        assert!(ctr.get_memory_cgroup_path().is_err());
+
+        paths.insert(
+            "memory".to_string(),
+            "/sys/fs/cgroup/memory/foo".to_string(),
+        );
+        ctr.cgroup_manager = Some(Box::new(Paths(paths)));
+        assert_eq!(
+            ctr.get_memory_cgroup_path().unwrap(),
+            "/sys/fs/cgroup/memory/foo"
+        );
+
+        // cgroup v2 only has the unified hierarchy
+        let mut paths = HashMap::new();
+        paths.insert("".to_string(), "/sys/fs/cgroup/foo".to_string());
+        ctr.cgroup_manager = Some(Box::new(Paths(paths)));
+        assert_eq!(ctr.get_memory_cgroup_path().unwrap(), "/sys/fs/cgroup/foo");
+        assert_eq!(
+            ctr.get_cgroup_path("freezer"),
+            Some("/sys/fs/cgroup/foo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        let dir = tempdir().unwrap();
+        let mut ctr = new_container(dir.path().to_str().unwrap());
+        let freezer = Freezer::default();
+        let states = freezer.states.clone();
+        ctr.cgroup_manager = Some(Box::new(freezer));
+        ctr.status = Some("running".to_string());
+
+        ctr.pause().unwrap();
+        assert_eq!(ctr.status, Some("paused".to_string()));
+        assert!(ctr.pause().is_err());
+
+        ctr.resume().unwrap();
+        assert_eq!(ctr.status, Some("running".to_string()));
+        assert!(ctr.resume().is_err());
+        assert_eq!(*states.lock().unwrap(), vec![FROZEN, THAWED]);
+
+        // as once checkpointed without being left running
+        ctr.status = Some("stopped".to_string());
+        assert!(ctr.pause().is_err());
+        assert!(ctr.checkpoint(&CriuOpts::default()).is_err());
+
+        // the poststop hooks still run on destroy, only once
+        let mut hook = Hook::new();
+        hook.Path = dir.path().join("missing").to_str().unwrap().to_string();
+        let mut hooks = Hooks::new();
+        hooks.Poststop.push(hook);
+        ctr.config.spec.as_mut().unwrap().Hooks = SingularPtrField::some(hooks);
+
+        assert!(ctr.destroy().is_err());
+        assert_eq!(ctr.status, Some("stopped".to_string()));
+        assert!(ctr.destroy().is_ok());
+    }
+}
@@ -0,0 +1,319 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// Containers are checkpointed and restored by the criu binary of the
+// guest, which is given the container layout on its command line.
+
+use crate::errors::*;
+use libc;
+use protocols::oci::Spec;
+use std::fs::File;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::os::unix::process::CommandExt;
+use std::process::Command;
+
+const CRIU_BINARY: &str = "criu";
+
+// the stdio of the init process at checkpoint time, which pipes are
+// replaced by the ones of the restored process
+pub const DESCRIPTORS_FILENAME: &str = "descriptors.json";
+
+const PID_FILENAME: &str = "restore.pid";
+
+// the namespaces a restored container can join, by their criu names
+const JOINABLE_NAMESPACES: &[(&str, &str)] = &[("network", "net"), ("ipc", "ipc"), ("uts", "uts")];
+
+#[derive(Debug, Default, Clone)]
+pub struct CriuOpts {
+    // directory of the checkpoint images
+    pub images_directory: String,
+    // directory of the logs and the pid file, the images directory
+    // if empty
+    pub work_directory: String,
+    pub leave_running: bool,
+    pub tcp_established: bool,
+    pub file_locks: bool,
+}
+
+impl CriuOpts {
+    pub fn work_directory(&self) -> &str {
+        if self.work_directory.is_empty() {
+            self.images_directory.as_str()
+        } else {
+            self.work_directory.as_str()
+        }
+    }
+
+    // pid_file is where criu writes the pid of the restored init process.
+    pub fn pid_file(&self) -> String {
+        format!("{}/{}", self.work_directory(), PID_FILENAME)
+    }
+
+    // the log of an action is <action>.log in the work directory
+    fn common_args(&self, action: &str) -> Vec<String> {
+        let mut args = vec![
+            action.to_string(),
+            "--images-dir".to_string(),
+            self.images_directory.clone(),
+            "--work-dir".to_string(),
+            self.work_directory().to_string(),
+            "--log-file".to_string(),
+            format!("{}.log", action),
+            "--manage-cgroups".to_string(),
+        ];
+
+        if self.tcp_established {
+            args.push("--tcp-established".to_string());
+        }
+        if self.file_locks {
+            args.push("--file-locks".to_string());
+        }
+
+        args
+    }
+}
+
+// dump_args are the arguments of criu checkpointing the process tree of
+// pid, which rootfs is root. The tasks are frozen by the freezer cgroup
+// if any.
+pub fn dump_args(
+    opts: &CriuOpts,
+    pid: i32,
+    root: &str,
+    spec: &Spec,
+    freezer: Option<&str>,
+) -> Vec<String> {
+    let mut args = opts.common_args("dump");
+    args.extend(vec![
+        "--tree".to_string(),
+        pid.to_string(),
+        "--root".to_string(),
+        root.to_string(),
+    ]);
+
+    if let Some(dir) = freezer {
+        args.push("--freeze-cgroup".to_string());
+        args.push(dir.to_string());
+    }
+    if opts.leave_running {
+        args.push("--leave-running".to_string());
+    }
+
+    // the bind mounts come from outside of the container, and are
+    // recorded by their destination
+    for (dst, _) in external_mounts(spec) {
+        args.push("--ext-mount-map".to_string());
+        args.push(format!("{}:{}", dst, dst));
+    }
+
+    args
+}
+
+// restore_args are the arguments of criu restoring a process tree in
+// root, the inherited fds replacing the descriptors of the checkpointed
+// process, and the cgroups being moved under cgroup_root.
+pub fn restore_args(
+    opts: &CriuOpts,
+    root: &str,
+    spec: &Spec,
+    inherited_fds: &[(RawFd, String)],
+    cgroup_root: &str,
+) -> Vec<String> {
+    let mut args = opts.common_args("restore");
+    args.extend(vec![
+        "--root".to_string(),
+        root.to_string(),
+        "--restore-detached".to_string(),
+        "--pidfile".to_string(),
+        opts.pid_file(),
+    ]);
+
+    for (dst, src) in external_mounts(spec) {
+        args.push("--ext-mount-map".to_string());
+        args.push(format!("{}:{}", dst, src));
+    }
+
+    for (fd, descriptor) in inherited_fds {
+        args.push("--inherit-fd".to_string());
+        args.push(format!("fd[{}]:{}", fd, descriptor));
+    }
+
+    if !cgroup_root.is_empty() {
+        args.push("--cgroup-root".to_string());
+        args.push(cgroup_root.to_string());
+    }
+
+    // the namespaces shared with the sandbox are joined, not recreated
+    if let Some(linux) = spec.Linux.as_ref() {
+        for ns in linux.Namespaces.iter().filter(|ns| !ns.Path.is_empty()) {
+            if let Some((_, name)) = JOINABLE_NAMESPACES.iter().find(|(t, _)| *t == ns.Type) {
+                args.push("--join-ns".to_string());
+                args.push(format!("{}:{}", name, ns.Path));
+            }
+        }
+    }
+
+    args
+}
+
+// external_mounts returns the destination and source of the mounts which
+// criu cannot rebuild from the container itself.
+fn external_mounts(spec: &Spec) -> Vec<(String, String)> {
+    let mut mounts: Vec<(String, String)> = spec
+        .Mounts
+        .iter()
+        .filter(|m| m.field_type == "bind" || m.options.iter().any(|o| o == "bind" || o == "rbind"))
+        .map(|m| (m.destination.clone(), m.source.clone()))
+        .collect();
+
+    // masked paths are /dev/null bind mounted
+    if let Some(linux) = spec.Linux.as_ref() {
+        mounts.extend(
+            linux
+                .MaskedPaths
+                .iter()
+                .map(|p| (p.clone(), "/dev/null".to_string())),
+        );
+    }
+
+    mounts
+}
+
+// run runs criu, after it moved itself to the cgroups which procs files
+// are given and made the inherited fds its own.
+pub fn run(args: &[String], cgroup_procs: &[File], inherited_fds: &[RawFd]) -> Result<()> {
+    let procs: Vec<RawFd> = cgroup_procs.iter().map(|f| f.as_raw_fd()).collect();
+    let fds = inherited_fds.to_vec();
+
+    let mut cmd = Command::new(CRIU_BINARY);
+    cmd.args(args);
+    unsafe {
+        cmd.pre_exec(move || {
+            // only async-signal-safe calls are allowed here
+            for fd in procs.iter() {
+                if libc::write(*fd, b"0".as_ptr() as *const libc::c_void, 1) < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            for fd in fds.iter() {
+                if libc::fcntl(*fd, libc::F_SETFD, 0) < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        });
+    }
+
+    let status = cmd
+        .status()
+        .chain_err(|| format!("cannot run {}", CRIU_BINARY))?;
+    if !status.success() {
+        let action = args.get(0).map_or("", |a| a.as_str());
+        return Err(ErrorKind::ErrorCode(format!(
+            "{} {} failed with {}, see {}.log in the work directory",
+            CRIU_BINARY, action, status, action
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::RepeatedField;
+    use protocols::oci::{Linux, LinuxNamespace, Mount};
+
+    fn spec() -> Spec {
+        let mut spec = Spec::new();
+        let mut bind = Mount::new();
+        bind.destination = "/data".to_string();
+        bind.source = "/run/kata-containers/shared/data".to_string();
+        bind.field_type = "bind".to_string();
+        let mut proc_mount = Mount::new();
+        proc_mount.destination = "/proc".to_string();
+        proc_mount.source = "proc".to_string();
+        proc_mount.field_type = "proc".to_string();
+        spec.Mounts = RepeatedField::from_vec(vec![bind, proc_mount]);
+
+        let mut linux = Linux::new();
+        linux.MaskedPaths = RepeatedField::from_vec(vec!["/proc/kcore".to_string()]);
+        let mut uts = LinuxNamespace::new();
+        uts.Type = "uts".to_string();
+        uts.Path = "/var/run/sandbox-ns/uts".to_string();
+        let mut pid = LinuxNamespace::new();
+        pid.Type = "pid".to_string();
+        linux.Namespaces = RepeatedField::from_vec(vec![uts, pid]);
+        spec.set_Linux(linux);
+
+        spec
+    }
+
+    fn has_args(args: &[String], expected: &[&str]) -> bool {
+        args.windows(expected.len()).any(|w| w == expected)
+    }
+
+    #[test]
+    fn test_criu_args() {
+        let opts = CriuOpts {
+            images_directory: "/images".to_string(),
+            leave_running: true,
+            tcp_established: true,
+            ..Default::default()
+        };
+        let spec = spec();
+
+        let args = dump_args(
+            &opts,
+            42,
+            "/rootfs",
+            &spec,
+            Some("/sys/fs/cgroup/freezer/c"),
+        );
+        assert_eq!(args[0], "dump");
+        assert!(has_args(&args, &["--work-dir", "/images"]));
+        assert!(has_args(&args, &["--tree", "42"]));
+        assert!(has_args(
+            &args,
+            &["--freeze-cgroup", "/sys/fs/cgroup/freezer/c"]
+        ));
+        assert!(has_args(&args, &["--ext-mount-map", "/data:/data"]));
+        assert!(has_args(
+            &args,
+            &["--ext-mount-map", "/proc/kcore:/proc/kcore"]
+        ));
+        assert!(has_args(&args, &["--leave-running"]));
+        assert!(has_args(&args, &["--tcp-established"]));
+        assert!(!has_args(&args, &["--file-locks"]));
+        assert!(!has_args(&args, &["--ext-mount-map", "/proc:/proc"]));
+
+        let args = restore_args(
+            &opts,
+            "/rootfs",
+            &spec,
+            &[(5, "pipe:[1234]".to_string())],
+            "/c",
+        );
+        assert_eq!(args[0], "restore");
+        assert!(has_args(&args, &["--pidfile", "/images/restore.pid"]));
+        assert!(has_args(
+            &args,
+            &["--ext-mount-map", "/data:/run/kata-containers/shared/data"]
+        ));
+        assert!(has_args(
+            &args,
+            &["--ext-mount-map", "/proc/kcore:/dev/null"]
+        ));
+        assert!(has_args(&args, &["--inherit-fd", "fd[5]:pipe:[1234]"]));
+        assert!(has_args(&args, &["--cgroup-root", "/c"]));
+        assert!(has_args(
+            &args,
+            &["--join-ns", "uts:/var/run/sandbox-ns/uts"]
+        ));
+        assert!(!has_args(&args, &["--leave-running"]));
+        assert_eq!(args.iter().filter(|a| *a == "--join-ns").count(), 1);
+    }
+}
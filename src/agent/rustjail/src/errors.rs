@@ -21,6 +21,7 @@ error_chain! {
         Scanfmt(scan_fmt::parse::ScanError);
         Ip(std::net::AddrParseError);
         Regex(regex::Error);
+        Protobuf(protobuf::ProtobufError);
     }
     // define new errors
     errors {
@@ -28,5 +29,33 @@ error_chain! {
             description("Error Code")
             display("Error Code: '{}'", t)
         }
+        NotFound(t: String) {
+            description("Not Found")
+            display("Not Found: '{}'", t)
+        }
+        InvalidArgument(t: String) {
+            description("Invalid Argument")
+            display("Invalid Argument: '{}'", t)
+        }
+        AlreadyExists(t: String) {
+            description("Already Exists")
+            display("Already Exists: '{}'", t)
+        }
+        FailedPrecondition(t: String) {
+            description("Failed Precondition")
+            display("Failed Precondition: '{}'", t)
+        }
+        DeadlineExceeded(t: String) {
+            description("Deadline Exceeded")
+            display("Deadline Exceeded: '{}'", t)
+        }
+        Unimplemented(t: String) {
+            description("Unimplemented")
+            display("Unimplemented: '{}'", t)
+        }
+        PermissionDenied(t: String) {
+            description("Permission Denied")
+            display("Permission Denied: '{}'", t)
+        }
     }
 }
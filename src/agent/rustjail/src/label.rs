@@ -0,0 +1,90 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The AppArmor profile and SELinux context of a container process are
+// its exec labels, which the kernel applies when the process execs. The
+// mount label is the context of the tmpfs and mqueue mounts, which have
+// no extended attributes to hold one.
+
+use crate::errors::*;
+use std::fs;
+use std::path::Path;
+
+const APPARMOR_ENABLED: &str = "/sys/module/apparmor/parameters/enabled";
+const SELINUX_ENFORCE: &str = "/sys/fs/selinux/enforce";
+
+// the AppArmor attributes of a kernel stacking several LSMs, and the ones
+// of the major LSM otherwise
+const APPARMOR_EXEC_ATTR: &str = "/proc/self/attr/apparmor/exec";
+const EXEC_ATTR: &str = "/proc/self/attr/exec";
+
+// the filesystems which mounts are labelled by a context option
+const LABELLED_FSTYPES: &[&str] = &["tmpfs", "mqueue"];
+
+pub fn apparmor_enabled() -> bool {
+    match fs::read_to_string(APPARMOR_ENABLED) {
+        Ok(enabled) => enabled.trim() == "Y",
+        Err(_) => false,
+    }
+}
+
+pub fn selinux_enabled() -> bool {
+    Path::new(SELINUX_ENFORCE).exists()
+}
+
+// set_exec_labels sets the labels of the program the calling process
+// execs next, the empty ones being left alone.
+pub fn set_exec_labels(apparmor_profile: &str, selinux_label: &str) -> Result<()> {
+    if !selinux_label.is_empty() {
+        fs::write(EXEC_ATTR, selinux_label)
+            .chain_err(|| format!("cannot set the SELinux label {}", selinux_label))?;
+    }
+
+    if !apparmor_profile.is_empty() {
+        let attr = if Path::new(APPARMOR_EXEC_ATTR).exists() {
+            APPARMOR_EXEC_ATTR
+        } else {
+            EXEC_ATTR
+        };
+        fs::write(attr, format!("exec {}", apparmor_profile))
+            .chain_err(|| format!("cannot set the AppArmor profile {}", apparmor_profile))?;
+    }
+
+    Ok(())
+}
+
+// mount_data adds the context of the mount label to the data of a mount
+// of fstype, if the filesystem takes one.
+pub fn mount_data(fstype: &str, data: &str, label: &str) -> String {
+    if label.is_empty() || !LABELLED_FSTYPES.contains(&fstype) {
+        return data.to_string();
+    }
+
+    let context = format!("context=\"{}\"", label);
+    if data.is_empty() {
+        context
+    } else {
+        format!("{},{}", data, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mount_data() {
+        let label = "system_u:object_r:container_file_t:s0:c1,c2";
+        let context = "context=\"system_u:object_r:container_file_t:s0:c1,c2\"";
+
+        assert_eq!(mount_data("tmpfs", "", label), context);
+        assert_eq!(
+            mount_data("mqueue", "mode=755", label),
+            format!("mode=755,{}", context)
+        );
+        assert_eq!(mount_data("proc", "", label), "");
+        assert_eq!(mount_data("tmpfs", "size=64k", ""), "size=64k");
+    }
+}
@@ -44,9 +44,12 @@ macro_rules! sl {
 
 pub mod cgroups;
 pub mod container;
+pub mod criu;
 pub mod errors;
+pub mod label;
 pub mod mount;
 pub mod process;
+pub mod seccomp;
 pub mod specconv;
 // pub mod sync;
 pub mod capabilities;
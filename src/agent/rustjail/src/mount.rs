@@ -21,8 +21,10 @@ use scan_fmt;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
+use crate::cgroups;
 use crate::container::DEFAULT_DEVICES;
 use crate::errors::*;
+use crate::label;
 use lazy_static;
 use std::string::ToString;
 
@@ -140,7 +142,7 @@ pub fn init_rootfs(
                 flags &= !MsFlags::MS_RDONLY;
             }
 
-            mount_from(&m, &rootfs, flags, &data, "")?;
+            mount_from(&m, &rootfs, flags, &data, &linux.MountLabel)?;
         }
     }
 
@@ -165,6 +167,33 @@ fn mount_cgroups(
     cpath: &HashMap<String, String>,
     mounts: &HashMap<String, String>,
 ) -> Result<()> {
+    // a cgroup of the unified hierarchy is a single directory, which is
+    // the hierarchy of the container
+    if cgroups::is_unified() {
+        let source = match cpath.get("") {
+            Some(s) => s,
+            None => return Err(ErrorKind::NotFound("no unified cgroup".to_string()).into()),
+        };
+
+        let bm = Mount {
+            source: source.to_string(),
+            field_type: "bind".to_string(),
+            destination: m.destination.clone(),
+            options: RepeatedField::default(),
+            unknown_fields: UnknownFields::default(),
+            cached_size: CachedSize::default(),
+        };
+
+        info!(logger, "{}", source);
+        return mount_from(
+            &bm,
+            rootfs,
+            flags | MsFlags::MS_REC | MsFlags::MS_BIND,
+            "",
+            "",
+        );
+    }
+
     // mount tmpfs
     let ctm = Mount {
         source: "tmpfs".to_string(),
@@ -426,8 +455,8 @@ fn parse_mount(m: &Mount) -> (MsFlags, String) {
     (flags, data.join(","))
 }
 
-fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, _label: &str) -> Result<()> {
-    let d = String::from(data);
+fn mount_from(m: &Mount, rootfs: &str, flags: MsFlags, data: &str, label: &str) -> Result<()> {
+    let d = label::mount_data(m.field_type.as_str(), data, label);
     let dest = format!("{}{}", rootfs, &m.destination);
 
     let src = if m.field_type.as_str() == "bind" {
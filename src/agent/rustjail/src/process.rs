@@ -7,6 +7,7 @@
 use libc::pid_t;
 use std::fs::File;
 use std::os::unix::io::RawFd;
+use std::sync::mpsc::{self, Receiver, Sender};
 
 // use crate::configs::{Capabilities, Rlimit};
 // use crate::cgroups::Manager as CgroupManager;
@@ -23,6 +24,9 @@ use nix::Error;
 use protocols::oci::Process as OCIProcess;
 use slog::Logger;
 
+// exit code of the processes which exit status is unknown
+pub const UNKNOWN_EXIT_CODE: i32 = 255;
+
 #[derive(Debug)]
 pub struct Process {
     pub exec_id: String,
@@ -36,6 +40,8 @@ pub struct Process {
     //	pub rlimits: Vec<Rlimit>,
     pub console_socket: Option<RawFd>,
     pub term_master: Option<RawFd>,
+    // listener of the system calls notified by the seccomp filter
+    pub seccomp_listener: Option<RawFd>,
     // parent end of fds
     pub parent_console_socket: Option<RawFd>,
     pub parent_stdin: Option<RawFd>,
@@ -47,6 +53,9 @@ pub struct Process {
     pub pid: pid_t,
 
     pub exit_code: i32,
+    // set once the process is gone, its exit code being known
+    reaped: bool,
+    exit_watchers: Vec<Sender<i32>>,
     pub oci: OCIProcess,
     pub logger: Logger,
 }
@@ -91,6 +100,7 @@ impl Process {
             extra_files: Vec::new(),
             console_socket: None,
             term_master: None,
+            seccomp_listener: None,
             parent_console_socket: None,
             parent_stdin: None,
             parent_stdout: None,
@@ -98,6 +108,8 @@ impl Process {
             init,
             pid: -1,
             exit_code: 0,
+            reaped: false,
+            exit_watchers: Vec::new(),
             oci: ocip.clone(),
             logger: logger.clone(),
         };
@@ -144,6 +156,66 @@ impl Process {
 
         Ok(p)
     }
+
+    // adopt rebuilds a process started by a previous agent, which has
+    // no stdio, only the exit pipe telling when it is gone.
+    pub fn adopt(logger: &Logger, id: &str, pid: pid_t, init: bool) -> Result<Self> {
+        let logger = logger.new(o!("subsystem" => "process"));
+        let (exit_pipe_r, exit_pipe_w) = unistd::pipe2(OFlag::O_CLOEXEC)?;
+
+        Ok(Process {
+            exec_id: String::from(id),
+            stdin: None,
+            stdout: None,
+            stderr: None,
+            exit_pipe_w: Some(exit_pipe_w),
+            exit_pipe_r: Some(exit_pipe_r),
+            extra_files: Vec::new(),
+            console_socket: None,
+            term_master: None,
+            seccomp_listener: None,
+            parent_console_socket: None,
+            parent_stdin: None,
+            parent_stdout: None,
+            parent_stderr: None,
+            init,
+            pid,
+            exit_code: 0,
+            reaped: false,
+            exit_watchers: Vec::new(),
+            oci: OCIProcess::new(),
+            logger,
+        })
+    }
+
+    // exited records the exit code of the process once it is gone, closes
+    // its exit pipe and tells the watchers of its exit.
+    pub fn exited(&mut self, code: i32) {
+        self.exit_code = code;
+        self.reaped = true;
+
+        if let Some(fd) = self.exit_pipe_w.take() {
+            let _ = unistd::close(fd);
+        }
+
+        for w in self.exit_watchers.drain(..) {
+            let _ = w.send(code);
+        }
+    }
+
+    // watch_exit returns a receiver of the exit code of the process, which
+    // has it already if the process is gone.
+    pub fn watch_exit(&mut self) -> Receiver<i32> {
+        let (tx, rx) = mpsc::channel();
+
+        if self.reaped {
+            let _ = tx.send(self.exit_code);
+        } else {
+            self.exit_watchers.push(tx);
+        }
+
+        rx
+    }
 }
 
 fn create_extended_pipe(flags: OFlag, pipe_size: i32) -> Result<(RawFd, RawFd)> {
@@ -156,7 +228,7 @@ fn create_extended_pipe(flags: OFlag, pipe_size: i32) -> Result<(RawFd, RawFd)>
 
 #[cfg(test)]
 mod tests {
-    use crate::process::create_extended_pipe;
+    use crate::process::{create_extended_pipe, Process};
     use nix::fcntl::{fcntl, FcntlArg, OFlag};
     use std::fs;
     use std::os::unix::io::RawFd;
@@ -184,4 +256,21 @@ mod tests {
         let actual_size = get_pipe_size(w);
         assert_eq!(max_size, actual_size);
     }
+
+    #[test]
+    fn test_watch_exit() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let mut p = Process::adopt(&logger, "foo", 42, true).unwrap();
+
+        let before = p.watch_exit();
+        assert!(before.try_recv().is_err());
+
+        p.exited(3);
+        assert_eq!(p.exit_code, 3);
+        assert!(p.exit_pipe_w.is_none());
+        assert_eq!(before.try_recv(), Ok(3));
+
+        // the process being gone, the exit code is there right away
+        assert_eq!(p.watch_exit().try_recv(), Ok(3));
+    }
 }
@@ -0,0 +1,1092 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The OCI seccomp profile of a container is compiled to a classic BPF
+// program, checked by the kernel on each system call of its processes.
+// The native architecture and the ones listed by the profile are filtered,
+// the system calls of the other ones killing the process. The system calls
+// notified to user space are received on the listener of the filter, which
+// is handed over to the agent.
+
+use crate::errors::*;
+use libc;
+use nix::errno::Errno;
+use protocols::oci::{LinuxSeccomp, LinuxSeccompArg};
+use std::collections::HashMap;
+use std::os::unix::io::RawFd;
+
+mod syscalls;
+
+// classic BPF
+const BPF_LD: u16 = 0x00;
+const BPF_W: u16 = 0x00;
+const BPF_ABS: u16 = 0x20;
+const BPF_ALU: u16 = 0x04;
+const BPF_AND: u16 = 0x50;
+const BPF_JMP: u16 = 0x05;
+const BPF_JA: u16 = 0x00;
+const BPF_JEQ: u16 = 0x10;
+const BPF_JGT: u16 = 0x20;
+const BPF_JGE: u16 = 0x30;
+const BPF_K: u16 = 0x00;
+const BPF_RET: u16 = 0x06;
+
+const SECCOMP_MODE_FILTER: libc::c_ulong = 2;
+const SECCOMP_SET_MODE_FILTER: libc::c_ulong = 1;
+const SECCOMP_FILTER_FLAG_NEW_LISTENER: libc::c_ulong = 1 << 3;
+
+// ioctls of the listener
+const SECCOMP_IOCTL_NOTIF_RECV: libc::c_ulong = 0xc050_2100;
+const SECCOMP_IOCTL_NOTIF_SEND: libc::c_ulong = 0xc018_2101;
+const SECCOMP_IOCTL_NOTIF_ID_VALID: libc::c_ulong = 0x4008_2102;
+
+const SECCOMP_RET_KILL_PROCESS: u32 = 0x8000_0000;
+const SECCOMP_RET_KILL_THREAD: u32 = 0x0000_0000;
+const SECCOMP_RET_TRAP: u32 = 0x0003_0000;
+const SECCOMP_RET_ERRNO: u32 = 0x0005_0000;
+const SECCOMP_RET_USER_NOTIF: u32 = 0x7fc0_0000;
+const SECCOMP_RET_TRACE: u32 = 0x7ff0_0000;
+const SECCOMP_RET_LOG: u32 = 0x7ffc_0000;
+const SECCOMP_RET_ALLOW: u32 = 0x7fff_0000;
+
+// offsets in struct seccomp_data
+const DATA_NR: u32 = 0;
+const DATA_ARCH: u32 = 4;
+const DATA_ARGS: u32 = 16;
+const MAX_ARGS: u64 = 6;
+
+const AUDIT_ARCH_X86_64: u32 = 0xc000_003e;
+// the x32 system calls share the x86_64 audit arch
+const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+// Arch is an architecture a profile may list, with its system calls.
+#[derive(Debug)]
+struct Arch {
+    name: &'static str,
+    audit: u32,
+    // set in the system call numbers, x32 only
+    nr_bit: u32,
+    syscalls: &'static [(&'static str, u32)],
+}
+
+static ARCHES: &[Arch] = &[
+    Arch {
+        name: "SCMP_ARCH_X86_64",
+        audit: AUDIT_ARCH_X86_64,
+        nr_bit: 0,
+        syscalls: syscalls::X86_64,
+    },
+    Arch {
+        name: "SCMP_ARCH_X32",
+        audit: AUDIT_ARCH_X86_64,
+        nr_bit: X32_SYSCALL_BIT,
+        syscalls: syscalls::X32,
+    },
+    Arch {
+        name: "SCMP_ARCH_X86",
+        audit: 0x4000_0003,
+        nr_bit: 0,
+        syscalls: syscalls::X86,
+    },
+    Arch {
+        name: "SCMP_ARCH_AARCH64",
+        audit: 0xc000_00b7,
+        nr_bit: 0,
+        syscalls: syscalls::AARCH64,
+    },
+    Arch {
+        name: "SCMP_ARCH_ARM",
+        audit: 0x4000_0028,
+        nr_bit: 0,
+        syscalls: syscalls::ARM,
+    },
+];
+
+#[cfg(target_arch = "x86_64")]
+const NATIVE_ARCH: Option<&str> = Some("SCMP_ARCH_X86_64");
+#[cfg(target_arch = "aarch64")]
+const NATIVE_ARCH: Option<&str> = Some("SCMP_ARCH_AARCH64");
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+const NATIVE_ARCH: Option<&str> = None;
+
+impl Arch {
+    fn find(name: &str) -> Option<&'static Arch> {
+        ARCHES.iter().find(|a| a.name == name)
+    }
+
+    fn native() -> Option<&'static Arch> {
+        NATIVE_ARCH.and_then(Arch::find)
+    }
+
+    fn number(&self, name: &str) -> Option<u32> {
+        self.syscalls
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, nr)| nr | self.nr_bit)
+    }
+
+    fn name(&self, nr: u32) -> Option<&'static str> {
+        self.syscalls
+            .iter()
+            .find(|(_, n)| n | self.nr_bit == nr)
+            .map(|(name, _)| *name)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SockFilter {
+    code: u16,
+    jt: u8,
+    jf: u8,
+    k: u32,
+}
+
+#[repr(C)]
+struct SockFprog {
+    len: libc::c_ushort,
+    filter: *const SockFilter,
+}
+
+// Filter is a compiled seccomp profile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    insns: Vec<SockFilter>,
+    notify: bool,
+}
+
+impl Filter {
+    // needs_listener tells whether some system calls are notified to
+    // user space.
+    pub fn needs_listener(&self) -> bool {
+        self.notify
+    }
+
+    // load installs the filter on the calling thread, which needs either
+    // no_new_privs or CAP_SYS_ADMIN. The listener of the notified system
+    // calls is returned, if any.
+    pub fn load(&self) -> Result<Option<RawFd>> {
+        let prog = SockFprog {
+            len: self.insns.len() as libc::c_ushort,
+            filter: self.insns.as_ptr(),
+        };
+
+        if self.notify {
+            let fd = unsafe {
+                libc::syscall(
+                    libc::SYS_seccomp,
+                    SECCOMP_SET_MODE_FILTER,
+                    SECCOMP_FILTER_FLAG_NEW_LISTENER,
+                    &prog as *const SockFprog,
+                )
+            };
+            let fd = Errno::result(fd).chain_err(|| "cannot load the seccomp filter")?;
+            return Ok(Some(fd as RawFd));
+        }
+
+        let ret = unsafe {
+            libc::prctl(
+                libc::PR_SET_SECCOMP,
+                SECCOMP_MODE_FILTER,
+                &prog as *const SockFprog,
+            )
+        };
+        Errno::result(ret).chain_err(|| "cannot load the seccomp filter")?;
+
+        Ok(None)
+    }
+}
+
+// SeccompData is the system call of a notification.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SeccompData {
+    pub nr: i32,
+    pub arch: u32,
+    pub instruction_pointer: u64,
+    pub args: [u64; 6],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Notification {
+    pub id: u64,
+    pub pid: u32,
+    pub flags: u32,
+    pub data: SeccompData,
+}
+
+#[repr(C)]
+#[derive(Debug, Default)]
+struct NotificationResponse {
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+}
+
+// receive waits for the next notification of a listener.
+pub fn receive(listener: RawFd) -> nix::Result<Notification> {
+    // the kernel wants it zeroed
+    let mut req = Notification::default();
+    let ret = unsafe { libc::ioctl(listener, SECCOMP_IOCTL_NOTIF_RECV, &mut req) };
+    Errno::result(ret)?;
+
+    Ok(req)
+}
+
+// respond completes the system call of a notification, which returns
+// val on success or fails with errno.
+pub fn respond(
+    listener: RawFd,
+    id: u64,
+    result: std::result::Result<i64, Errno>,
+) -> nix::Result<()> {
+    let resp = match result {
+        Ok(val) => NotificationResponse {
+            id,
+            val,
+            ..Default::default()
+        },
+        Err(errno) => NotificationResponse {
+            id,
+            error: -(errno as i32),
+            ..Default::default()
+        },
+    };
+
+    let ret = unsafe { libc::ioctl(listener, SECCOMP_IOCTL_NOTIF_SEND, &resp) };
+    Errno::result(ret).map(drop)
+}
+
+// is_valid tells whether the process of a notification still waits for
+// the response, and so that what was read of it did not come from another
+// process reusing its pid.
+pub fn is_valid(listener: RawFd, id: u64) -> bool {
+    unsafe { libc::ioctl(listener, SECCOMP_IOCTL_NOTIF_ID_VALID, &id) == 0 }
+}
+
+// is_supported tells whether seccomp filters can be enforced.
+pub fn is_supported() -> bool {
+    if NATIVE_ARCH.is_none() {
+        return false;
+    }
+
+    // a kernel with filters fails on the missing program, not the mode
+    let ret = unsafe {
+        libc::prctl(
+            libc::PR_SET_SECCOMP,
+            SECCOMP_MODE_FILTER,
+            std::ptr::null::<SockFprog>(),
+        )
+    };
+    ret < 0 && Errno::last() == Errno::EFAULT
+}
+
+fn action(name: &str) -> Result<u32> {
+    let action = match name {
+        "SCMP_ACT_KILL" => SECCOMP_RET_KILL_THREAD,
+        "SCMP_ACT_KILL_PROCESS" => SECCOMP_RET_KILL_PROCESS,
+        "SCMP_ACT_TRAP" => SECCOMP_RET_TRAP,
+        "SCMP_ACT_ERRNO" => SECCOMP_RET_ERRNO | libc::EPERM as u32,
+        "SCMP_ACT_TRACE" => SECCOMP_RET_TRACE | libc::EPERM as u32,
+        "SCMP_ACT_NOTIFY" => SECCOMP_RET_USER_NOTIF,
+        "SCMP_ACT_LOG" => SECCOMP_RET_LOG,
+        "SCMP_ACT_ALLOW" => SECCOMP_RET_ALLOW,
+        _ => {
+            return Err(
+                ErrorKind::InvalidArgument(format!("unknown seccomp action {}", name)).into(),
+            )
+        }
+    };
+
+    Ok(action)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    NotEqual,
+    LessThan,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    GreaterThan,
+    MaskedEqual,
+}
+
+#[derive(Debug, Clone)]
+struct Condition {
+    index: u64,
+    op: Op,
+    value: u64,
+    value_two: u64,
+}
+
+impl Condition {
+    fn from_arg(arg: &LinuxSeccompArg) -> Result<Self> {
+        let op = match arg.Op.as_str() {
+            "SCMP_CMP_NE" => Op::NotEqual,
+            "SCMP_CMP_LT" => Op::LessThan,
+            "SCMP_CMP_LE" => Op::LessOrEqual,
+            "SCMP_CMP_EQ" => Op::Equal,
+            "SCMP_CMP_GE" => Op::GreaterOrEqual,
+            "SCMP_CMP_GT" => Op::GreaterThan,
+            "SCMP_CMP_MASKED_EQ" => Op::MaskedEqual,
+            _ => {
+                return Err(ErrorKind::InvalidArgument(format!(
+                    "unknown seccomp operator {}",
+                    arg.Op
+                ))
+                .into())
+            }
+        };
+
+        if arg.Index >= MAX_ARGS {
+            return Err(ErrorKind::InvalidArgument(format!(
+                "invalid seccomp argument index {}",
+                arg.Index
+            ))
+            .into());
+        }
+
+        Ok(Condition {
+            index: arg.Index,
+            op,
+            value: arg.Value,
+            value_two: arg.ValueTwo,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    conditions: Vec<Condition>,
+    action: u32,
+}
+
+// compile compiles a seccomp profile for the native architecture and the
+// ones it lists. The system calls unknown to an architecture are skipped.
+pub fn compile(seccomp: &LinuxSeccomp) -> Result<Filter> {
+    let native = match Arch::native() {
+        Some(a) => a,
+        None => {
+            return Err(ErrorKind::Unimplemented(
+                "seccomp isn't supported on this architecture".to_string(),
+            )
+            .into())
+        }
+    };
+
+    // as with libseccomp, the native architecture is always filtered
+    let mut arches: Vec<&Arch> = vec![native];
+    for name in seccomp.Architectures.iter() {
+        let arch = match Arch::find(name.as_str()) {
+            Some(a) => a,
+            None => {
+                return Err(ErrorKind::InvalidArgument(format!(
+                    "unsupported seccomp architecture {}",
+                    name
+                ))
+                .into())
+            }
+        };
+        if !arches.iter().any(|a| a.name == arch.name) {
+            arches.push(arch);
+        }
+    }
+
+    let default_action = action(seccomp.DefaultAction.as_str())?;
+    if default_action == SECCOMP_RET_USER_NOTIF {
+        return Err(ErrorKind::InvalidArgument(
+            "SCMP_ACT_NOTIFY can't be the default seccomp action".to_string(),
+        )
+        .into());
+    }
+    let mut notify = false;
+
+    // the rules of each entry of the profile, for its system calls
+    let mut entries: Vec<(&[String], Vec<Rule>)> = Vec::new();
+
+    for syscall in seccomp.Syscalls.iter() {
+        let action = action(syscall.Action.as_str())?;
+        notify |= action == SECCOMP_RET_USER_NOTIF;
+        let conditions = syscall
+            .Args
+            .iter()
+            .map(Condition::from_arg)
+            .collect::<Result<Vec<Condition>>>()?;
+
+        // the conditions are all to be met, but for the ones on a same
+        // argument which are alternatives
+        let mut indexes: Vec<u64> = conditions.iter().map(|c| c.index).collect();
+        indexes.sort();
+        indexes.dedup();
+        let syscall_rules: Vec<Rule> = if indexes.len() < conditions.len() {
+            conditions
+                .into_iter()
+                .map(|c| Rule {
+                    conditions: vec![c],
+                    action,
+                })
+                .collect()
+        } else {
+            vec![Rule { conditions, action }]
+        };
+
+        entries.push((syscall.Names.as_slice(), syscall_rules));
+    }
+
+    let mut asm = Assembler::new();
+    let bad_arch = asm.label();
+
+    asm.stmt(BPF_LD | BPF_W | BPF_ABS, DATA_ARCH);
+
+    let mut audits: Vec<u32> = Vec::new();
+    for arch in arches.iter() {
+        if !audits.contains(&arch.audit) {
+            audits.push(arch.audit);
+        }
+    }
+
+    for audit in audits {
+        let next = asm.label();
+        asm.jump_unless(BPF_JEQ, audit, Target::Label(next));
+        asm.stmt(BPF_LD | BPF_W | BPF_ABS, DATA_NR);
+
+        let plain = arches.iter().find(|a| a.audit == audit && a.nr_bit == 0);
+        let x32 = arches.iter().find(|a| a.audit == audit && a.nr_bit != 0);
+
+        // the x32 system calls are told apart by their number
+        let x32_body = asm.label();
+        if audit == AUDIT_ARCH_X86_64 {
+            let target = if x32.is_some() { x32_body } else { bad_arch };
+            asm.jump_if(BPF_JGE, X32_SYSCALL_BIT, Target::Label(target));
+        }
+
+        match plain {
+            Some(arch) => emit_rules(&mut asm, arch, &entries, default_action),
+            None => asm.ja(Target::Label(bad_arch)),
+        }
+
+        asm.bind(x32_body);
+        if let Some(arch) = x32 {
+            emit_rules(&mut asm, arch, &entries, default_action);
+        }
+
+        asm.bind(next);
+    }
+
+    asm.bind(bad_arch);
+    asm.stmt(BPF_RET | BPF_K, SECCOMP_RET_KILL_PROCESS);
+
+    Ok(Filter {
+        insns: asm.assemble()?,
+        notify,
+    })
+}
+
+// emit_rules emits the rules of the system calls of an architecture, which
+// number is loaded, and returns the default action for the other ones.
+fn emit_rules(
+    asm: &mut Assembler,
+    arch: &Arch,
+    entries: &[(&[String], Vec<Rule>)],
+    default_action: u32,
+) {
+    // the rules of each system call, in the order of the profile
+    let mut order: Vec<u32> = Vec::new();
+    let mut rules: HashMap<u32, Vec<Rule>> = HashMap::new();
+
+    for (names, syscall_rules) in entries.iter() {
+        for name in names.iter() {
+            let nr = match arch.number(name.as_str()) {
+                Some(nr) => nr,
+                None => continue,
+            };
+
+            if !rules.contains_key(&nr) {
+                order.push(nr);
+            }
+            rules
+                .entry(nr)
+                .or_insert_with(Vec::new)
+                .extend(syscall_rules.iter().cloned());
+        }
+    }
+
+    for nr in order {
+        let mut syscall_rules = rules.remove(&nr).unwrap_or_default();
+        // an unconditional rule would shadow the conditional ones
+        syscall_rules.sort_by_key(|r| r.conditions.is_empty());
+        if let Some(pos) = syscall_rules.iter().position(|r| r.conditions.is_empty()) {
+            syscall_rules.truncate(pos + 1);
+        }
+        if syscall_rules.len() == 1
+            && syscall_rules[0].conditions.is_empty()
+            && syscall_rules[0].action == default_action
+        {
+            continue;
+        }
+
+        let next = asm.label();
+        asm.jump_unless(BPF_JEQ, nr, Target::Label(next));
+
+        for rule in syscall_rules.iter() {
+            let fail = asm.label();
+            for c in rule.conditions.iter() {
+                emit_condition(asm, c, fail);
+            }
+            asm.stmt(BPF_RET | BPF_K, rule.action);
+            asm.bind(fail);
+        }
+        asm.stmt(BPF_RET | BPF_K, default_action);
+        asm.bind(next);
+    }
+
+    asm.stmt(BPF_RET | BPF_K, default_action);
+}
+
+// the offsets of the high and low words of an argument
+fn arg_offsets(index: u64) -> (u32, u32) {
+    let offset = DATA_ARGS + 8 * index as u32;
+    if cfg!(target_endian = "little") {
+        (offset + 4, offset)
+    } else {
+        (offset, offset + 4)
+    }
+}
+
+// emit_condition emits the 64 bits comparison of an argument, going on
+// when it's met and jumping to fail otherwise.
+fn emit_condition(asm: &mut Assembler, c: &Condition, fail: usize) {
+    let (hi, lo) = arg_offsets(c.index);
+    let (value_hi, value_lo) = ((c.value >> 32) as u32, c.value as u32);
+    let ok = asm.label();
+    let (next, ok_t, fail_t) = (Target::Next, Target::Label(ok), Target::Label(fail));
+
+    asm.stmt(BPF_LD | BPF_W | BPF_ABS, hi);
+    match c.op {
+        Op::Equal => {
+            asm.jump(BPF_JEQ, value_hi, next, fail_t);
+            asm.stmt(BPF_LD | BPF_W | BPF_ABS, lo);
+            asm.jump(BPF_JEQ, value_lo, ok_t, fail_t);
+        }
+        Op::NotEqual => {
+            asm.jump(BPF_JEQ, value_hi, next, ok_t);
+            asm.stmt(BPF_LD | BPF_W | BPF_ABS, lo);
+            asm.jump(BPF_JEQ, value_lo, fail_t, ok_t);
+        }
+        Op::GreaterThan | Op::GreaterOrEqual => {
+            asm.jump(BPF_JGT, value_hi, ok_t, next);
+            asm.jump(BPF_JEQ, value_hi, next, fail_t);
+            asm.stmt(BPF_LD | BPF_W | BPF_ABS, lo);
+            let op = if c.op == Op::GreaterThan {
+                BPF_JGT
+            } else {
+                BPF_JGE
+            };
+            asm.jump(op, value_lo, ok_t, fail_t);
+        }
+        Op::LessThan | Op::LessOrEqual => {
+            asm.jump(BPF_JGT, value_hi, fail_t, next);
+            asm.jump(BPF_JEQ, value_hi, next, ok_t);
+            asm.stmt(BPF_LD | BPF_W | BPF_ABS, lo);
+            let op = if c.op == Op::LessThan {
+                BPF_JGE
+            } else {
+                BPF_JGT
+            };
+            asm.jump(op, value_lo, fail_t, ok_t);
+        }
+        Op::MaskedEqual => {
+            let (datum_hi, datum_lo) = ((c.value_two >> 32) as u32, c.value_two as u32);
+            asm.stmt(BPF_ALU | BPF_AND | BPF_K, value_hi);
+            asm.jump(BPF_JEQ, datum_hi, next, fail_t);
+            asm.stmt(BPF_LD | BPF_W | BPF_ABS, lo);
+            asm.stmt(BPF_ALU | BPF_AND | BPF_K, value_lo);
+            asm.jump(BPF_JEQ, datum_lo, ok_t, fail_t);
+        }
+    }
+    asm.bind(ok);
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    Next,
+    Label(usize),
+}
+
+// Assembler resolves the jumps to labels of a BPF program.
+struct Assembler {
+    insns: Vec<(u16, u32, Target, Target)>,
+    labels: Vec<Option<usize>>,
+}
+
+impl Assembler {
+    fn new() -> Self {
+        Assembler {
+            insns: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    fn label(&mut self) -> usize {
+        self.labels.push(None);
+        self.labels.len() - 1
+    }
+
+    fn bind(&mut self, label: usize) {
+        self.labels[label] = Some(self.insns.len());
+    }
+
+    fn stmt(&mut self, code: u16, k: u32) {
+        self.insns.push((code, k, Target::Next, Target::Next));
+    }
+
+    fn jump(&mut self, op: u16, k: u32, jt: Target, jf: Target) {
+        self.insns.push((BPF_JMP | op | BPF_K, k, jt, jf));
+    }
+
+    fn ja(&mut self, target: Target) {
+        self.insns.push((BPF_JMP | BPF_JA, 0, target, Target::Next));
+    }
+
+    // jump_if and jump_unless jump to a target however far it is, the
+    // conditional jumps only going 255 instructions ahead.
+    fn jump_if(&mut self, op: u16, k: u32, target: Target) {
+        let skip = self.label();
+        self.jump(op, k, Target::Next, Target::Label(skip));
+        self.ja(target);
+        self.bind(skip);
+    }
+
+    fn jump_unless(&mut self, op: u16, k: u32, target: Target) {
+        let skip = self.label();
+        self.jump(op, k, Target::Label(skip), Target::Next);
+        self.ja(target);
+        self.bind(skip);
+    }
+
+    fn offset(&self, from: usize, target: Target) -> Result<u32> {
+        match target {
+            Target::Next => Ok(0),
+            Target::Label(l) => match self.labels[l] {
+                Some(to) if to > from => Ok((to - from - 1) as u32),
+                _ => Err(ErrorKind::ErrorCode(format!("invalid seccomp jump to {}", l)).into()),
+            },
+        }
+    }
+
+    fn assemble(&self) -> Result<Vec<SockFilter>> {
+        if self.insns.len() > libc::c_ushort::max_value() as usize {
+            return Err(ErrorKind::InvalidArgument("seccomp profile too large".to_string()).into());
+        }
+
+        let mut filter = Vec::with_capacity(self.insns.len());
+        for (i, &(code, k, jt, jf)) in self.insns.iter().enumerate() {
+            let insn = if code == BPF_JMP | BPF_JA {
+                SockFilter {
+                    code,
+                    jt: 0,
+                    jf: 0,
+                    k: self.offset(i, jt)?,
+                }
+            } else if code & 0x07 == BPF_JMP {
+                let (jt, jf) = (self.offset(i, jt)?, self.offset(i, jf)?);
+                if jt > u8::max_value() as u32 || jf > u8::max_value() as u32 {
+                    return Err(
+                        ErrorKind::InvalidArgument("seccomp rule too large".to_string()).into(),
+                    );
+                }
+                SockFilter {
+                    code,
+                    jt: jt as u8,
+                    jf: jf as u8,
+                    k,
+                }
+            } else {
+                SockFilter {
+                    code,
+                    jt: 0,
+                    jf: 0,
+                    k,
+                }
+            };
+            filter.push(insn);
+        }
+
+        Ok(filter)
+    }
+}
+
+// syscall_number and syscall_name look the system calls of the native
+// architecture up.
+pub fn syscall_number(name: &str) -> Option<u32> {
+    Arch::native().and_then(|a| a.number(name))
+}
+
+pub fn syscall_name(nr: i32) -> Option<&'static str> {
+    Arch::native().and_then(|a| a.name(nr as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::RepeatedField;
+    use protocols::oci::LinuxSyscall;
+
+    // run interprets the filter for a system call of the native
+    // architecture
+    fn run(filter: &Filter, arch: u32, nr: u32, args: [u64; 6]) -> u32 {
+        let mut data = vec![0u8; 64];
+        data[0..4].copy_from_slice(&nr.to_ne_bytes());
+        data[4..8].copy_from_slice(&arch.to_ne_bytes());
+        for (i, a) in args.iter().enumerate() {
+            let offset = DATA_ARGS as usize + 8 * i;
+            data[offset..offset + 8].copy_from_slice(&a.to_ne_bytes());
+        }
+
+        let mut acc: u32 = 0;
+        let mut pc = 0;
+        loop {
+            let insn = filter.insns[pc];
+            pc += 1;
+            match insn.code {
+                c if c == BPF_LD | BPF_W | BPF_ABS => {
+                    let k = insn.k as usize;
+                    let mut word = [0u8; 4];
+                    word.copy_from_slice(&data[k..k + 4]);
+                    acc = u32::from_ne_bytes(word);
+                }
+                c if c == BPF_ALU | BPF_AND | BPF_K => acc &= insn.k,
+                c if c == BPF_JMP | BPF_JA => pc += insn.k as usize,
+                c if c == BPF_RET | BPF_K => return insn.k,
+                c => {
+                    let taken = match c & !BPF_JMP {
+                        BPF_JEQ => acc == insn.k,
+                        BPF_JGT => acc > insn.k,
+                        BPF_JGE => acc >= insn.k,
+                        _ => panic!("unexpected instruction {:?}", insn),
+                    };
+                    pc += if taken { insn.jt } else { insn.jf } as usize;
+                }
+            }
+        }
+    }
+
+    fn syscall(names: &[&str], action: &str, args: &[(u64, &str, u64, u64)]) -> LinuxSyscall {
+        let mut s = LinuxSyscall::new();
+        s.Names = RepeatedField::from_vec(names.iter().map(|n| n.to_string()).collect());
+        s.Action = action.to_string();
+        s.Args = RepeatedField::from_vec(
+            args.iter()
+                .map(|(index, op, value, value_two)| {
+                    let mut a = LinuxSeccompArg::new();
+                    a.Index = *index;
+                    a.Op = op.to_string();
+                    a.Value = *value;
+                    a.ValueTwo = *value_two;
+                    a
+                })
+                .collect(),
+        );
+        s
+    }
+
+    #[test]
+    fn test_compile() {
+        let arch = match Arch::native() {
+            Some(a) => a.audit,
+            None => return,
+        };
+        let errno = SECCOMP_RET_ERRNO | libc::EPERM as u32;
+        let nr = |name| syscall_number(name).unwrap();
+
+        let mut profile = LinuxSeccomp::new();
+        profile.DefaultAction = "SCMP_ACT_ERRNO".to_string();
+        profile.Syscalls = RepeatedField::from_vec(vec![
+            syscall(&["read", "write", "no_such_syscall"], "SCMP_ACT_ALLOW", &[]),
+            // personality(PER_LINUX) or personality(0xffffffff)
+            syscall(
+                &["personality"],
+                "SCMP_ACT_ALLOW",
+                &[(0, "SCMP_CMP_EQ", 0, 0), (0, "SCMP_CMP_EQ", 0xffff_ffff, 0)],
+            ),
+            // clone without CLONE_NEWUSER
+            syscall(
+                &["clone"],
+                "SCMP_ACT_ALLOW",
+                &[(0, "SCMP_CMP_MASKED_EQ", 0x1000_0000, 0)],
+            ),
+            syscall(
+                &["kill"],
+                "SCMP_ACT_ALLOW",
+                &[(1, "SCMP_CMP_LE", 1 << 32, 0)],
+            ),
+            syscall(&["kill"], "SCMP_ACT_LOG", &[]),
+        ]);
+
+        let filter = compile(&profile).unwrap();
+        let allow = SECCOMP_RET_ALLOW;
+        assert!(!filter.needs_listener());
+
+        assert_eq!(run(&filter, arch, nr("read"), [0; 6]), allow);
+        assert_eq!(run(&filter, arch, nr("write"), [0; 6]), allow);
+        assert_eq!(run(&filter, arch, nr("getpid"), [0; 6]), errno);
+        assert_eq!(
+            run(&filter, arch.wrapping_add(1), nr("read"), [0; 6]),
+            SECCOMP_RET_KILL_PROCESS
+        );
+
+        let personality = nr("personality");
+        assert_eq!(run(&filter, arch, personality, [0, 0, 0, 0, 0, 0]), allow);
+        assert_eq!(
+            run(&filter, arch, personality, [0xffff_ffff, 0, 0, 0, 0, 0]),
+            allow
+        );
+        assert_eq!(run(&filter, arch, personality, [8, 0, 0, 0, 0, 0]), errno);
+        assert_eq!(
+            run(&filter, arch, personality, [1 << 32, 0, 0, 0, 0, 0]),
+            errno
+        );
+
+        let clone = nr("clone");
+        assert_eq!(
+            run(&filter, arch, clone, [0x0001_1200, 0, 0, 0, 0, 0]),
+            allow
+        );
+        assert_eq!(
+            run(&filter, arch, clone, [0x1001_1200, 0, 0, 0, 0, 0]),
+            errno
+        );
+
+        // the conditional rule comes before the unconditional one
+        let kill = nr("kill");
+        assert_eq!(run(&filter, arch, kill, [1, 9, 0, 0, 0, 0]), allow);
+        assert_eq!(run(&filter, arch, kill, [1, 1 << 32, 0, 0, 0, 0]), allow);
+        assert_eq!(
+            run(&filter, arch, kill, [1, (1 << 32) + 1, 0, 0, 0, 0]),
+            SECCOMP_RET_LOG
+        );
+        assert_eq!(
+            run(&filter, arch, kill, [1, 2 << 32, 0, 0, 0, 0]),
+            SECCOMP_RET_LOG
+        );
+
+        profile.Syscalls =
+            RepeatedField::from_vec(vec![syscall(&["mount"], "SCMP_ACT_NOTIFY", &[])]);
+        let filter = compile(&profile).unwrap();
+        assert!(filter.needs_listener());
+        assert_eq!(
+            run(&filter, arch, nr("mount"), [0; 6]),
+            SECCOMP_RET_USER_NOTIF
+        );
+        assert_eq!(syscall_name(nr("mount") as i32), Some("mount"));
+
+        profile.DefaultAction = "SCMP_ACT_NOTIFY".to_string();
+        assert!(compile(&profile).is_err());
+    }
+
+    #[test]
+    fn test_compile_comparisons() {
+        let arch = match Arch::native() {
+            Some(a) => a.audit,
+            None => return,
+        };
+        let read = syscall_number("read").unwrap();
+        let values: &[u64] = &[0, 1, 41, 42, 43, 1 << 32, (1 << 32) + 42, u64::max_value()];
+
+        for (op, f) in &[
+            ("SCMP_CMP_NE", (|a, b| a != b) as fn(u64, u64) -> bool),
+            ("SCMP_CMP_LT", |a, b| a < b),
+            ("SCMP_CMP_LE", |a, b| a <= b),
+            ("SCMP_CMP_EQ", |a, b| a == b),
+            ("SCMP_CMP_GE", |a, b| a >= b),
+            ("SCMP_CMP_GT", |a, b| a > b),
+        ] {
+            for value in &[42, (1 << 32) + 42] {
+                let mut profile = LinuxSeccomp::new();
+                profile.DefaultAction = "SCMP_ACT_KILL".to_string();
+                profile.Syscalls = RepeatedField::from_vec(vec![syscall(
+                    &["read"],
+                    "SCMP_ACT_ALLOW",
+                    &[(2, op, *value, 0)],
+                )]);
+                let filter = compile(&profile).unwrap();
+
+                for arg in values {
+                    let expected = if f(*arg, *value) {
+                        SECCOMP_RET_ALLOW
+                    } else {
+                        SECCOMP_RET_KILL_THREAD
+                    };
+                    let result = run(&filter, arch, read, [0, 0, *arg, 0, 0, 0]);
+                    assert_eq!(result, expected, "{} {} {}", arg, op, value);
+                }
+            }
+        }
+
+        let mut profile = LinuxSeccomp::new();
+        profile.DefaultAction = "SCMP_ACT_ALLOW".to_string();
+        for bad in &[
+            syscall(&["read"], "SCMP_ACT_NONE", &[]),
+            syscall(&["read"], "SCMP_ACT_ALLOW", &[(0, "SCMP_CMP_NONE", 0, 0)]),
+            syscall(&["read"], "SCMP_ACT_ALLOW", &[(6, "SCMP_CMP_EQ", 0, 0)]),
+        ] {
+            profile.Syscalls = RepeatedField::from_vec(vec![bad.clone()]);
+            assert!(compile(&profile).is_err());
+        }
+    }
+
+    #[test]
+    fn test_compile_arches() {
+        if Arch::native().is_none() {
+            return;
+        }
+        let errno = SECCOMP_RET_ERRNO | libc::EPERM as u32;
+
+        let mut profile = LinuxSeccomp::new();
+        profile.DefaultAction = "SCMP_ACT_ERRNO".to_string();
+        profile.Architectures =
+            RepeatedField::from_vec(ARCHES.iter().map(|a| a.name.to_string()).collect());
+        // some are only known to a few architectures
+        profile.Syscalls = RepeatedField::from_vec(vec![
+            syscall(&["read", "getrlimit", "renameat"], "SCMP_ACT_ALLOW", &[]),
+            syscall(&["open"], "SCMP_ACT_LOG", &[]),
+        ]);
+        let filter = compile(&profile).unwrap();
+
+        // arm only has ugetrlimit
+        assert!(Arch::find("SCMP_ARCH_AARCH64")
+            .unwrap()
+            .number("getrlimit")
+            .is_some());
+        for arch in ARCHES.iter() {
+            for name in &["read", "getrlimit", "renameat"] {
+                if let Some(nr) = arch.number(name) {
+                    assert_eq!(
+                        run(&filter, arch.audit, nr, [0; 6]),
+                        SECCOMP_RET_ALLOW,
+                        "{} {}",
+                        arch.name,
+                        name
+                    );
+                }
+            }
+            let getpid = arch.number("getpid").unwrap();
+            assert_eq!(run(&filter, arch.audit, getpid, [0; 6]), errno);
+            if let Some(open) = arch.number("open") {
+                assert_eq!(run(&filter, arch.audit, open, [0; 6]), SECCOMP_RET_LOG);
+            }
+        }
+
+        // the architectures which aren't listed are killed, the native one
+        // being always filtered
+        profile.Architectures = RepeatedField::new();
+        let filter = compile(&profile).unwrap();
+        let native = Arch::native().unwrap();
+        let read = native.number("read").unwrap();
+        assert_eq!(run(&filter, native.audit, read, [0; 6]), SECCOMP_RET_ALLOW);
+        for arch in ARCHES.iter().filter(|a| a.name != native.name) {
+            let read = arch.number("read").unwrap();
+            assert_eq!(
+                run(&filter, arch.audit, read, [0; 6]),
+                SECCOMP_RET_KILL_PROCESS,
+                "{}",
+                arch.name
+            );
+        }
+
+        // every system call of every architecture makes a large filter,
+        // which jumps are still resolved
+        profile.Architectures =
+            RepeatedField::from_vec(ARCHES.iter().map(|a| a.name.to_string()).collect());
+        let mut names: Vec<&str> = ARCHES
+            .iter()
+            .flat_map(|a| a.syscalls.iter())
+            .map(|(name, _)| *name)
+            .collect();
+        names.sort();
+        names.dedup();
+        profile.Syscalls = RepeatedField::from_vec(
+            names
+                .iter()
+                .map(|name| syscall(&[name], "SCMP_ACT_ALLOW", &[(0, "SCMP_CMP_NE", 42, 0)]))
+                .collect(),
+        );
+        let filter = compile(&profile).unwrap();
+        for arch in ARCHES.iter() {
+            let (name, _) = arch.syscalls[arch.syscalls.len() - 1];
+            let nr = arch.number(name).unwrap();
+            assert_eq!(run(&filter, arch.audit, nr, [0; 6]), SECCOMP_RET_ALLOW);
+            assert_eq!(run(&filter, arch.audit, nr, [42, 0, 0, 0, 0, 0]), errno);
+        }
+
+        profile.Architectures = RepeatedField::from_vec(vec!["SCMP_ARCH_MIPS".to_string()]);
+        assert!(compile(&profile).is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        if !is_supported() {
+            return;
+        }
+
+        let mut profile = LinuxSeccomp::new();
+        profile.DefaultAction = "SCMP_ACT_ALLOW".to_string();
+        profile.Syscalls =
+            RepeatedField::from_vec(vec![syscall(&["getppid"], "SCMP_ACT_ERRNO", &[])]);
+        let filter = compile(&profile).unwrap();
+
+        // the filter can't be removed, it is loaded in a child
+        match unsafe { libc::fork() } {
+            0 => {
+                let code = match filter.load() {
+                    Ok(None) => {
+                        let ret = unsafe { libc::syscall(libc::SYS_getppid) };
+                        !(ret == -1 && Errno::last() == Errno::EPERM) as i32
+                    }
+                    _ => 1,
+                };
+                unsafe { libc::_exit(code) };
+            }
+            pid => {
+                let mut status = 0;
+                unsafe { libc::waitpid(pid, &mut status, 0) };
+                assert!(libc::WIFEXITED(status));
+                assert_eq!(libc::WEXITSTATUS(status), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_notify() {
+        if !is_supported() {
+            return;
+        }
+
+        let mut profile = LinuxSeccomp::new();
+        profile.DefaultAction = "SCMP_ACT_ALLOW".to_string();
+        profile.Syscalls =
+            RepeatedField::from_vec(vec![syscall(&["getppid"], "SCMP_ACT_NOTIFY", &[])]);
+        let filter = compile(&profile).unwrap();
+
+        // the filter only applies to the thread loading it
+        let (tx, rx) = std::sync::mpsc::channel();
+        let child = std::thread::spawn(move || {
+            tx.send(filter.load().unwrap().unwrap()).unwrap();
+            unsafe { libc::syscall(libc::SYS_getppid) }
+        });
+
+        let listener = rx.recv().unwrap();
+        let req = receive(listener).unwrap();
+        assert_eq!(syscall_name(req.data.nr), Some("getppid"));
+        assert!(is_valid(listener, req.id));
+        respond(listener, req.id, Ok(42)).unwrap();
+
+        assert_eq!(child.join().unwrap(), 42);
+        unsafe { libc::close(listener) };
+    }
+}
@@ -1,5 +1,6 @@
 use crate::container::Config;
 use crate::errors::*;
+use crate::label;
 use lazy_static;
 use nix::errno::Errno;
 use nix::Error;
@@ -88,6 +89,8 @@ fn hostname(oci: &Spec) -> Result<()> {
 
 fn security(oci: &Spec) -> Result<()> {
     let linux = oci.Linux.as_ref().unwrap();
+    labels(oci)?;
+
     if linux.MaskedPaths.len() == 0 && linux.ReadonlyPaths.len() == 0 {
         return Ok(());
     }
@@ -96,7 +99,31 @@ fn security(oci: &Spec) -> Result<()> {
         return Err(ErrorKind::Nix(Error::from_errno(Errno::EINVAL)).into());
     }
 
-    // don't care about selinux at present
+    Ok(())
+}
+
+// a label is only enforced by its LSM
+fn labels(oci: &Spec) -> Result<()> {
+    let linux = oci.Linux.as_ref().unwrap();
+    let (apparmor_profile, selinux_label) = match oci.Process.as_ref() {
+        Some(p) => (p.ApparmorProfile.as_str(), p.SelinuxLabel.as_str()),
+        None => ("", ""),
+    };
+
+    if !apparmor_profile.is_empty() && !label::apparmor_enabled() {
+        return Err(ErrorKind::FailedPrecondition(format!(
+            "AppArmor profile {} requested but AppArmor is not enabled",
+            apparmor_profile
+        ))
+        .into());
+    }
+
+    if (!selinux_label.is_empty() || !linux.MountLabel.is_empty()) && !label::selinux_enabled() {
+        return Err(ErrorKind::FailedPrecondition(
+            "SELinux label requested but SELinux is not enabled".to_string(),
+        )
+        .into());
+    }
 
     Ok(())
 }
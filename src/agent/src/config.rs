@@ -8,20 +8,85 @@ use std::time;
 
 const DEBUG_CONSOLE_FLAG: &str = "agent.debug_console";
 const DEV_MODE_FLAG: &str = "agent.devmode";
+const UNIFIED_CGROUP_HIERARCHY_FLAG: &str = "agent.unified_cgroup_hierarchy";
 const LOG_LEVEL_OPTION: &str = "agent.log";
 const HOTPLUG_TIMOUT_OPTION: &str = "agent.hotplug_timeout";
 const DEBUG_CONSOLE_VPORT_OPTION: &str = "agent.debug_console_vport";
 const LOG_VPORT_OPTION: &str = "agent.log_vport";
 const CONTAINER_PIPE_SIZE_OPTION: &str = "agent.container_pipe_size";
+const TRACE_VPORT_OPTION: &str = "agent.trace_vport";
+const SERVER_ADDR_OPTION: &str = "agent.server_addr";
+const POLICY_FILE_OPTION: &str = "agent.policy_file";
+const GUEST_HOOK_TIMEOUT_OPTION: &str = "agent.guest_hook_timeout";
+const POWEROFF_OPTION: &str = "agent.poweroff";
+
+// command line arguments of the agent binary
+const VERSION_ARG: &str = "--version";
+const STANDALONE_ARG: &str = "--standalone";
+const SERVER_ADDR_ARG: &str = "--server-addr";
 
 const DEFAULT_LOG_LEVEL: slog::Level = slog::Level::Info;
 const DEFAULT_HOTPLUG_TIMEOUT: time::Duration = time::Duration::from_secs(3);
 const DEFAULT_CONTAINER_PIPE_SIZE: i32 = 0;
+// seconds
+pub const DEFAULT_GUEST_HOOK_TIMEOUT: i64 = 30;
+const DEFAULT_TRACE_VPORT: i32 = 10240;
+const DEFAULT_SERVER_ADDR: &str = "vsock://-1:1024";
+
+const VSOCK_SCHEME: &str = "vsock://";
+const UNIX_SCHEME: &str = "unix://";
 
-// FIXME: unused
 const TRACE_MODE_FLAG: &str = "agent.trace";
+// FIXME: unused
 const USE_VSOCK_FLAG: &str = "agent.use_vsock";
 
+// ServerAddr is where the gRPC server listens, either a vsock port, the
+// cid being -1 for any, or a unix socket.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerAddr {
+    Vsock { cid: i32, port: u16 },
+    Unix(String),
+}
+
+impl ServerAddr {
+    pub fn parse(addr: &str) -> Result<ServerAddr> {
+        if addr.starts_with(VSOCK_SCHEME) {
+            let fields: Vec<&str> = addr[VSOCK_SCHEME.len()..].split(':').collect();
+            if fields.len() != 2 {
+                return Err(ErrorKind::InvalidArgument(format!(
+                    "invalid vsock server address {}",
+                    addr
+                ))
+                .into());
+            }
+
+            let cid = fields[0]
+                .parse::<i32>()
+                .chain_err(|| format!("invalid cid in server address {}", addr))?;
+            let port = fields[1]
+                .parse::<u16>()
+                .chain_err(|| format!("invalid port in server address {}", addr))?;
+
+            return Ok(ServerAddr::Vsock { cid, port });
+        }
+
+        if addr.starts_with(UNIX_SCHEME) {
+            let path = &addr[UNIX_SCHEME.len()..];
+            if !path.starts_with('/') {
+                return Err(ErrorKind::InvalidArgument(format!(
+                    "unix server address {} is not an absolute path",
+                    addr
+                ))
+                .into());
+            }
+
+            return Ok(ServerAddr::Unix(path.to_string()));
+        }
+
+        Err(ErrorKind::InvalidArgument(format!("unsupported server address {}", addr)).into())
+    }
+}
+
 #[derive(Debug)]
 pub struct agentConfig {
     pub debug_console: bool,
@@ -31,6 +96,20 @@ pub struct agentConfig {
     pub debug_console_vport: i32,
     pub log_vport: i32,
     pub container_pipe_size: i32,
+    pub tracing: bool,
+    pub trace_vport: i32,
+    pub server_addr: ServerAddr,
+    pub standalone: bool,
+    pub show_version: bool,
+    pub policy_file: Option<String>,
+    pub unified_cgroup_hierarchy: bool,
+    // timeout of the guest hooks, in seconds
+    pub guest_hook_timeout: i64,
+    // whether the agent, as init, powers the guest off once the sandbox
+    // is destroyed
+    pub poweroff: bool,
+    // arguments parse_args didn't know, logged once the logger is set
+    pub ignored_args: Vec<String>,
 }
 
 impl agentConfig {
@@ -43,6 +122,16 @@ impl agentConfig {
             debug_console_vport: 0,
             log_vport: 0,
             container_pipe_size: DEFAULT_CONTAINER_PIPE_SIZE,
+            tracing: false,
+            trace_vport: DEFAULT_TRACE_VPORT,
+            server_addr: ServerAddr::parse(DEFAULT_SERVER_ADDR).unwrap(),
+            standalone: false,
+            show_version: false,
+            policy_file: None,
+            unified_cgroup_hierarchy: false,
+            guest_hook_timeout: DEFAULT_GUEST_HOOK_TIMEOUT,
+            poweroff: true,
+            ignored_args: Vec::new(),
         }
     }
 
@@ -58,6 +147,14 @@ impl agentConfig {
                 self.dev_mode = true;
             }
 
+            if param.eq(&UNIFIED_CGROUP_HIERARCHY_FLAG) {
+                self.unified_cgroup_hierarchy = true;
+            }
+
+            if param.eq(&TRACE_MODE_FLAG) {
+                self.tracing = true;
+            }
+
             if param.starts_with(format!("{}=", LOG_LEVEL_OPTION).as_str()) {
                 let level = get_log_level(param)?;
                 self.log_level = level;
@@ -89,12 +186,88 @@ impl agentConfig {
                 let container_pipe_size = get_container_pipe_size(param)?;
                 self.container_pipe_size = container_pipe_size
             }
+
+            if param.starts_with(format!("{}=", TRACE_VPORT_OPTION).as_str()) {
+                let port = get_vsock_port(param)?;
+                if port > 0 {
+                    self.trace_vport = port;
+                }
+            }
+
+            if param.starts_with(format!("{}=", SERVER_ADDR_OPTION).as_str()) {
+                self.server_addr = get_server_addr(param)?;
+            }
+
+            if param.starts_with(format!("{}=", POLICY_FILE_OPTION).as_str()) {
+                self.policy_file = Some(get_policy_file(param)?);
+            }
+
+            if param.starts_with(format!("{}=", GUEST_HOOK_TIMEOUT_OPTION).as_str()) {
+                let timeout = get_guest_hook_timeout(param)?;
+                // ensure the timeout is a positive value
+                if timeout > 0 {
+                    self.guest_hook_timeout = timeout;
+                }
+            }
+
+            if param.starts_with(format!("{}=", POWEROFF_OPTION).as_str()) {
+                self.poweroff = get_poweroff(param)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // parse_args parses the arguments the agent binary has been started
+    // with, args[0] being the binary name. Those take precedence over the
+    // kernel command line.
+    pub fn parse_args(&mut self, args: &[String]) -> Result<()> {
+        let mut iter = args.iter().skip(1);
+        self.ignored_args.clear();
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                VERSION_ARG => self.show_version = true,
+                STANDALONE_ARG => self.standalone = true,
+                SERVER_ADDR_ARG => {
+                    let addr = iter.next().ok_or_else(|| {
+                        Error::from(ErrorKind::InvalidArgument(format!(
+                            "missing value for {}",
+                            SERVER_ADDR_ARG
+                        )))
+                    })?;
+                    self.server_addr = ServerAddr::parse(addr)?;
+                }
+                _ if arg.starts_with(format!("{}=", SERVER_ADDR_ARG).as_str()) => {
+                    self.server_addr = ServerAddr::parse(&arg[SERVER_ADDR_ARG.len() + 1..])?;
+                }
+                _ => self.ignored_args.push(arg.to_string()),
+            }
         }
 
         Ok(())
     }
 }
 
+fn get_policy_file(param: &str) -> Result<String> {
+    let fields: Vec<&str> = param.splitn(2, '=').collect();
+    if fields.len() != 2 || fields[0] != POLICY_FILE_OPTION || !fields[1].starts_with('/') {
+        return Err(ErrorKind::ErrorCode("invalid policy file parameter".to_string()).into());
+    }
+
+    Ok(fields[1].to_string())
+}
+
+fn get_server_addr(param: &str) -> Result<ServerAddr> {
+    // unix paths may contain '=', only split on the first one
+    let fields: Vec<&str> = param.splitn(2, '=').collect();
+    if fields.len() != 2 || fields[0] != SERVER_ADDR_OPTION {
+        return Err(ErrorKind::ErrorCode("invalid server address parameter".to_string()).into());
+    }
+
+    ServerAddr::parse(fields[1])
+}
+
 fn get_vsock_port(p: &str) -> Result<i32> {
     let fields: Vec<&str> = p.split("=").collect();
     if fields.len() != 2 {
@@ -165,6 +338,46 @@ fn get_hotplug_timeout(param: &str) -> Result<time::Duration> {
     Ok(time::Duration::from_secs(value.unwrap()))
 }
 
+fn get_guest_hook_timeout(param: &str) -> Result<i64> {
+    let fields: Vec<&str> = param.split("=").collect();
+
+    if fields.len() != 2 {
+        return Err(
+            ErrorKind::ErrorCode(String::from("invalid guest hook timeout parameter")).into(),
+        );
+    }
+
+    if fields[0] != GUEST_HOOK_TIMEOUT_OPTION {
+        return Err(
+            ErrorKind::ErrorCode(String::from("invalid guest hook timeout key name")).into(),
+        );
+    }
+
+    match fields[1].parse::<i64>() {
+        Ok(v) => Ok(v),
+        Err(_) => {
+            Err(ErrorKind::ErrorCode(String::from("unable to parse guest hook timeout")).into())
+        }
+    }
+}
+
+fn get_poweroff(param: &str) -> Result<bool> {
+    let fields: Vec<&str> = param.split("=").collect();
+
+    if fields.len() != 2 {
+        return Err(ErrorKind::ErrorCode(String::from("invalid poweroff parameter")).into());
+    }
+
+    if fields[0] != POWEROFF_OPTION {
+        return Err(ErrorKind::ErrorCode(String::from("invalid poweroff key name")).into());
+    }
+
+    match fields[1].parse::<bool>() {
+        Ok(v) => Ok(v),
+        Err(_) => Err(ErrorKind::ErrorCode(String::from("unable to parse poweroff")).into()),
+    }
+}
+
 fn get_container_pipe_size(param: &str) -> Result<i32> {
     let fields: Vec<&str> = param.split("=").collect();
 
@@ -253,8 +466,193 @@ mod tests {
         let config = agentConfig::new();
         assert_eq!(config.debug_console, false);
         assert_eq!(config.dev_mode, false);
+        assert_eq!(config.unified_cgroup_hierarchy, false);
         assert_eq!(config.log_level, DEFAULT_LOG_LEVEL);
         assert_eq!(config.hotplug_timeout, DEFAULT_HOTPLUG_TIMEOUT);
+        assert_eq!(config.tracing, false);
+        assert_eq!(config.trace_vport, DEFAULT_TRACE_VPORT);
+    }
+
+    #[test]
+    fn test_server_addr() {
+        let tests = &[
+            (
+                "vsock://-1:1024",
+                Some(ServerAddr::Vsock {
+                    cid: -1,
+                    port: 1024,
+                }),
+            ),
+            (
+                "vsock://3:5000",
+                Some(ServerAddr::Vsock { cid: 3, port: 5000 }),
+            ),
+            (
+                "unix:///tmp/kata-agent.sock",
+                Some(ServerAddr::Unix("/tmp/kata-agent.sock".to_string())),
+            ),
+            ("vsock://1024", None),
+            ("vsock://x:1024", None),
+            ("vsock://-1:100000", None),
+            ("unix://kata-agent.sock", None),
+            ("tcp://127.0.0.1:1024", None),
+            ("", None),
+        ];
+
+        for (i, (addr, expected)) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, addr);
+            let result = ServerAddr::parse(addr);
+
+            match expected {
+                Some(e) => assert_eq!(*e, result.unwrap(), "{}", msg),
+                None => assert!(result.is_err(), "{}", msg),
+            }
+        }
+
+        let dir = tempdir().expect("failed to create tmpdir");
+        let file_path = dir.path().join("cmdline");
+        let filename = file_path.to_str().expect("failed to create filename");
+
+        fs::write(filename, "agent.server_addr=unix:///run/agent=1.sock").unwrap();
+
+        let mut config = agentConfig::new();
+        config.parse_cmdline(filename).unwrap();
+        assert_eq!(
+            config.server_addr,
+            ServerAddr::Unix("/run/agent=1.sock".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_cmdline_policy_file() {
+        let tests = &[
+            ("agent.devmode", Some(None)),
+            (
+                "agent.policy_file=/etc/policy.json",
+                Some(Some("/etc/policy.json")),
+            ),
+            ("agent.policy_file=policy.json", None),
+            ("agent.policy_file=", None),
+        ];
+
+        let dir = tempdir().expect("failed to create tmpdir");
+        let file_path = dir.path().join("cmdline");
+        let filename = file_path.to_str().expect("failed to create filename");
+
+        for (i, (contents, expected)) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, contents);
+
+            fs::write(filename, contents).expect(&format!("{}: failed to write file", msg));
+
+            let mut config = agentConfig::new();
+            let result = config.parse_cmdline(filename);
+
+            match expected {
+                Some(path) => {
+                    assert!(result.is_ok(), "{}", msg);
+                    assert_eq!(
+                        *path,
+                        config.policy_file.as_ref().map(|p| p.as_str()),
+                        "{}",
+                        msg
+                    );
+                }
+                None => assert!(result.is_err(), "{}", msg),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let to_args = |v: &[&str]| -> Vec<String> { v.iter().map(|s| s.to_string()).collect() };
+
+        let mut config = agentConfig::new();
+        config.parse_args(&to_args(&["kata-agent"])).unwrap();
+        assert_eq!(config.standalone, false);
+        assert_eq!(config.show_version, false);
+        assert_eq!(
+            config.server_addr,
+            ServerAddr::parse(DEFAULT_SERVER_ADDR).unwrap()
+        );
+
+        let mut config = agentConfig::new();
+        config
+            .parse_args(&to_args(&[
+                "kata-agent",
+                "--standalone",
+                "--server-addr",
+                "unix:///tmp/agent.sock",
+            ]))
+            .unwrap();
+        assert_eq!(config.standalone, true);
+        assert_eq!(
+            config.server_addr,
+            ServerAddr::Unix("/tmp/agent.sock".to_string())
+        );
+
+        let mut config = agentConfig::new();
+        config
+            .parse_args(&to_args(&[
+                "kata-agent",
+                "--server-addr=vsock://-1:2048",
+                "--version",
+            ]))
+            .unwrap();
+        assert_eq!(config.show_version, true);
+        assert_eq!(
+            config.server_addr,
+            ServerAddr::Vsock {
+                cid: -1,
+                port: 2048
+            }
+        );
+
+        let mut config = agentConfig::new();
+        assert!(config
+            .parse_args(&to_args(&["kata-agent", "--server-addr"]))
+            .is_err());
+
+        let mut config = agentConfig::new();
+        config
+            .parse_args(&to_args(&["kata-agent", "--unknown", "--standalone", "-x"]))
+            .unwrap();
+        assert_eq!(config.standalone, true);
+        assert_eq!(config.ignored_args, vec!["--unknown", "-x"]);
+
+        // parsing the arguments again doesn't report them twice
+        config
+            .parse_args(&to_args(&["kata-agent", "--unknown"]))
+            .unwrap();
+        assert_eq!(config.ignored_args, vec!["--unknown"]);
+    }
+
+    #[test]
+    fn test_parse_cmdline_trace() {
+        let tests = &[
+            ("agent.devmode", false, DEFAULT_TRACE_VPORT),
+            ("agent.tracex", false, DEFAULT_TRACE_VPORT),
+            ("agent.trace", true, DEFAULT_TRACE_VPORT),
+            ("agent.trace agent.trace_vport=1234", true, 1234),
+            ("agent.trace_vport=1234", false, 1234),
+            ("agent.trace agent.trace_vport=0", true, DEFAULT_TRACE_VPORT),
+        ];
+
+        let dir = tempdir().expect("failed to create tmpdir");
+        let file_path = dir.path().join("cmdline");
+        let filename = file_path.to_str().expect("failed to create filename");
+
+        for (i, (contents, tracing, trace_vport)) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, contents);
+
+            fs::write(filename, contents).expect(&format!("{}: failed to write file", msg));
+
+            let mut config = agentConfig::new();
+            let result = config.parse_cmdline(filename);
+            assert!(result.is_ok(), "{}", msg);
+
+            assert_eq!(*tracing, config.tracing, "{}", msg);
+            assert_eq!(*trace_vport, config.trace_vport, "{}", msg);
+        }
     }
 
     #[test]
@@ -805,6 +1203,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_guest_hook_timeout() {
+        assert_eq!(
+            get_guest_hook_timeout("agent.guest_hook_timeout=60").unwrap(),
+            60
+        );
+        assert!(get_guest_hook_timeout("agent.guest_hook_timeout").is_err());
+        assert!(get_guest_hook_timeout("agent.guest_hook_timeout=foo").is_err());
+        assert!(get_guest_hook_timeout("agent.guest_hook_timeou=1").is_err());
+
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("cmdline");
+        fs::write(&file, "agent.guest_hook_timeout=5").unwrap();
+        let mut config = agentConfig::new();
+        assert_eq!(config.guest_hook_timeout, DEFAULT_GUEST_HOOK_TIMEOUT);
+        config.parse_cmdline(file.to_str().unwrap()).unwrap();
+        assert_eq!(config.guest_hook_timeout, 5);
+
+        // not a valid timeout, the default one is kept
+        fs::write(&file, "agent.guest_hook_timeout=0").unwrap();
+        let mut config = agentConfig::new();
+        config.parse_cmdline(file.to_str().unwrap()).unwrap();
+        assert_eq!(config.guest_hook_timeout, DEFAULT_GUEST_HOOK_TIMEOUT);
+    }
+
+    #[test]
+    fn test_get_poweroff() {
+        assert_eq!(get_poweroff("agent.poweroff=false").unwrap(), false);
+        assert_eq!(get_poweroff("agent.poweroff=true").unwrap(), true);
+        assert!(get_poweroff("agent.poweroff").is_err());
+        assert!(get_poweroff("agent.poweroff=0").is_err());
+        assert!(get_poweroff("agent.powerof=false").is_err());
+
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("cmdline");
+        fs::write(&file, "agent.poweroff=false").unwrap();
+        let mut config = agentConfig::new();
+        assert!(config.poweroff);
+        config.parse_cmdline(file.to_str().unwrap()).unwrap();
+        assert!(!config.poweroff);
+    }
+
     #[test]
     fn test_get_container_pipe_size() {
         #[derive(Debug)]
@@ -13,6 +13,7 @@ use std::sync::{mpsc, Arc, Mutex};
 use crate::linux_abi::*;
 use crate::mount::{DRIVERBLKTYPE, DRIVERMMIOBLKTYPE, DRIVERNVDIMMTYPE, DRIVERSCSITYPE};
 use crate::sandbox::Sandbox;
+use crate::tracer;
 use crate::{AGENT_CONFIG, GLOBAL_DEVICE_WATCHER};
 use protocols::agent::Device;
 use protocols::oci::Spec;
@@ -58,7 +59,7 @@ fn get_pci_device_address(pci_id: &str) -> Result<String> {
     let tokens: Vec<&str> = pci_id.split("/").collect();
 
     if tokens.len() != 2 {
-        return Err(ErrorKind::ErrorCode(format!(
+        return Err(ErrorKind::InvalidArgument(format!(
             "PCI Identifier for device should be of format [bridgeAddr/deviceAddr], got {}",
             pci_id
         ))
@@ -76,13 +77,13 @@ fn get_pci_device_address(pci_id: &str) -> Result<String> {
     let bridge_bus_path = format!("{}/{}/pci_bus/", SYSFS_PCI_BUS_PREFIX, pci_bridge_addr);
 
     let files_slice: Vec<_> = fs::read_dir(&bridge_bus_path)
-        .unwrap()
-        .map(|res| res.unwrap().path())
+        .chain_err(|| format!("cannot read bridge bus {}", bridge_bus_path))?
+        .filter_map(|res| res.ok().map(|e| e.path()))
         .collect();
     let bus_num = files_slice.len();
 
     if bus_num != 1 {
-        return Err(ErrorKind::ErrorCode(format!(
+        return Err(ErrorKind::NotFound(format!(
             "Expected an entry for bus in {}, got {} entries instead",
             bridge_bus_path, bus_num
         ))
@@ -127,12 +128,16 @@ fn get_device_name(sandbox: &Arc<Mutex<Sandbox>>, dev_addr: &str) -> Result<Stri
     drop(w);
 
     info!(sl!(), "Waiting on channel for device notification\n");
+    let mut span = tracer::span("wait_device");
+    span.set_attribute("device", dev_addr);
+
     let hotplug_timeout = AGENT_CONFIG.read().unwrap().hotplug_timeout;
     let dev_name = match rx.recv_timeout(hotplug_timeout) {
         Ok(name) => name,
         Err(_) => {
+            span.set_error("timeout");
             GLOBAL_DEVICE_WATCHER.lock().unwrap().remove_entry(dev_addr);
-            return Err(ErrorKind::ErrorCode(format!(
+            return Err(ErrorKind::DeadlineExceeded(format!(
                 "Timeout reached after {:?} waiting for device {}",
                 hotplug_timeout, dev_addr
             ))
@@ -161,7 +166,7 @@ pub fn get_pci_device_name(sandbox: &Arc<Mutex<Sandbox>>, pci_id: &str) -> Resul
 fn scan_scsi_bus(scsi_addr: &str) -> Result<()> {
     let tokens: Vec<&str> = scsi_addr.split(":").collect();
     if tokens.len() != 2 {
-        return Err(ErrorKind::Msg(format!(
+        return Err(ErrorKind::InvalidArgument(format!(
             "Unexpected format for SCSI Address: {}, expect SCSIID:LUA",
             scsi_addr
         ))
@@ -200,7 +205,7 @@ fn update_spec_device_list(device: &Device, spec: &mut Spec) -> Result<()> {
     // If no container_path is provided, we won't be able to match and
     // update the device in the OCI spec device list. This is an error.
     if device.container_path == "" {
-        return Err(ErrorKind::Msg(format!(
+        return Err(ErrorKind::InvalidArgument(format!(
             "container_path cannot empty for device {:?}",
             device
         ))
@@ -210,14 +215,16 @@ fn update_spec_device_list(device: &Device, spec: &mut Spec) -> Result<()> {
     let linux = match spec.Linux.as_mut() {
         None => {
             return Err(
-                ErrorKind::ErrorCode("Spec didn't container linux field".to_string()).into(),
+                ErrorKind::InvalidArgument("Spec didn't container linux field".to_string()).into(),
             )
         }
         Some(l) => l,
     };
 
     if !Path::new(&device.vm_path).exists() {
-        return Err(ErrorKind::Msg(format!("vm_path:{} doesn't exist", device.vm_path)).into());
+        return Err(
+            ErrorKind::NotFound(format!("vm_path:{} doesn't exist", device.vm_path)).into(),
+        );
     }
 
     let meta = fs::metadata(&device.vm_path)?;
@@ -280,7 +287,10 @@ fn virtiommio_blk_device_handler(
     _sandbox: &Arc<Mutex<Sandbox>>,
 ) -> Result<()> {
     if device.vm_path == "" {
-        return Err(ErrorKind::Msg("Invalid path for virtio mmio blk device".to_string()).into());
+        return Err(ErrorKind::InvalidArgument(
+            "Invalid path for virtio mmio blk device".to_string(),
+        )
+        .into());
     }
 
     update_spec_device_list(device, spec)
@@ -316,7 +326,9 @@ fn virtio_nvdimm_device_handler(
     _sandbox: &Arc<Mutex<Sandbox>>,
 ) -> Result<()> {
     if device.vm_path == "" {
-        return Err(ErrorKind::Msg("Invalid path for nvdimm device".to_string()).into());
+        return Err(
+            ErrorKind::InvalidArgument("Invalid path for nvdimm device".to_string()).into(),
+        );
     }
 
     update_spec_device_list(device, spec)
@@ -340,23 +352,33 @@ fn add_device(device: &Device, spec: &mut Spec, sandbox: &Arc<Mutex<Sandbox>>) -
           device.id, device.field_type, device.vm_path, device.container_path, device.options);
 
     if device.field_type == "" {
-        return Err(ErrorKind::Msg(format!("invalid type for device {:?}", device)).into());
+        return Err(
+            ErrorKind::InvalidArgument(format!("invalid type for device {:?}", device)).into(),
+        );
     }
 
     if device.id == "" && device.vm_path == "" {
-        return Err(
-            ErrorKind::Msg(format!("invalid ID and VM path for device {:?}", device)).into(),
-        );
+        return Err(ErrorKind::InvalidArgument(format!(
+            "invalid ID and VM path for device {:?}",
+            device
+        ))
+        .into());
     }
 
     if device.container_path == "" {
-        return Err(
-            ErrorKind::Msg(format!("invalid container path for device {:?}", device)).into(),
-        );
+        return Err(ErrorKind::InvalidArgument(format!(
+            "invalid container path for device {:?}",
+            device
+        ))
+        .into());
     }
 
     match DEVICEHANDLERLIST.get(device.field_type.as_str()) {
-        None => Err(ErrorKind::Msg(format!("Unknown device type {}", device.field_type)).into()),
+        None => Err(ErrorKind::InvalidArgument(format!(
+            "Unknown device type {}",
+            device.field_type
+        ))
+        .into()),
         Some(dev_handler) => dev_handler(device, spec, sandbox),
     }
 }
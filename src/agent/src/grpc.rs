@@ -5,58 +5,89 @@
 
 use futures::*;
 use grpcio::{EnvBuilder, Server, ServerBuilder};
-use grpcio::{RpcStatus, RpcStatusCode};
+use grpcio::{RpcStatus, RpcStatusCode, UnarySink, WriteFlags};
 use std::sync::{Arc, Mutex};
 
 use protobuf::{RepeatedField, SingularPtrField};
 use protocols::agent::CopyFileRequest;
 use protocols::agent::{
-    AgentDetails, GuestDetailsResponse, ListProcessesResponse, ReadStreamResponse,
+    AgentDetails, GuestDetailsResponse, ListProcessesResponse, MemoryPressureEvent,
+    MemoryPressureLevel, OOMEvent, ReadStreamResponse, RemoveContainerResponse,
     WaitProcessResponse, WriteStreamResponse,
 };
 use protocols::empty::Empty;
 use protocols::health::{HealthCheckResponse, HealthCheckResponse_ServingStatus};
 use protocols::oci::{LinuxNamespace, Spec};
 use rustjail;
-use rustjail::container::{BaseContainer, LinuxContainer};
+use rustjail::cgroups::notifier::{PressureLevel, Subscription};
+use rustjail::cgroups::systemd;
+use rustjail::container::{BaseContainer, Container, LinuxContainer};
+use rustjail::criu::CriuOpts;
 use rustjail::errors::*;
 use rustjail::process::Process;
+use rustjail::seccomp;
 use rustjail::specconv::CreateOpts;
 
 use nix::errno::Errno;
-use nix::sys::signal::Signal;
+use nix::poll::{poll, PollFd, PollFlags};
+use nix::sys::signal::{self, Signal};
 use nix::sys::stat;
 use nix::unistd::{self, Pid};
 use rustjail::process::ProcessOperations;
 
+use crate::config::ServerAddr;
 use crate::device::{add_devices, rescan_pci_bus};
 use crate::linux_abi::*;
 use crate::mount::{add_storages, remove_mounts, STORAGEHANDLERLIST};
 use crate::namespace::{NSTYPEIPC, NSTYPEPID, NSTYPEUTS};
+use crate::policy::Policy;
+use crate::ps;
 use crate::random;
-use crate::sandbox::Sandbox;
+use crate::sandbox::{self, Sandbox};
+use crate::seccomp_notify;
+use crate::tracer;
+use crate::user;
 use crate::version::{AGENT_VERSION, API_VERSION};
 use crate::AGENT_CONFIG;
-use netlink::{RtnlHandle, NETLINK_ROUTE};
 
 use libc::{self, c_ushort, pid_t, winsize, TIOCSWINSZ};
-use serde_json;
+use std::collections::{HashMap, VecDeque};
 use std::convert::TryFrom;
 use std::fs;
 use std::os::unix::io::RawFd;
 use std::os::unix::prelude::PermissionsExt;
-use std::process::{Command, Stdio};
-use std::sync::mpsc;
+use std::sync::mpsc::Sender;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use nix::unistd::{Gid, Uid};
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader};
 use std::os::unix::fs::FileExt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 const CONTAINER_BASE: &str = "/run/kata-containers";
+const SANDBOX_STATE: &str = "/run/kata-containers/sandbox/state.json";
+const SANDBOX_RESOLV_CONF: &str = "/run/kata-containers/sandbox/resolv.conf";
+const SANDBOX_HOSTNAME: &str = "/run/kata-containers/sandbox/hostname";
+const SANDBOX_HOSTS: &str = "/run/kata-containers/sandbox/hosts";
+const RESOLV_CONF_PATH: &str = "/etc/resolv.conf";
+const HOSTNAME_PATH: &str = "/etc/hostname";
+const HOSTS_PATH: &str = "/etc/hosts";
+
+// default chunk size of the stdio streams
+const STREAM_CHUNK_SIZE: usize = 32 * 1024;
+
+// time given to the reaper to collect the exit status of a killed
+// container, in milliseconds
+const EXIT_STATUS_TIMEOUT: u64 = 1000;
+
+// port grpc appends to the path of a unix socket address
+const UNIX_SOCKET_PORT: u16 = 0;
+
+// interval at which a memory pressure subscription checks that its
+// subscriber is still there, in milliseconds
+const PRESSURE_CHECK_INTERVAL: u64 = 1000;
 
 // Convenience macro to obtain the scope logger
 macro_rules! sl {
@@ -65,10 +96,91 @@ macro_rules! sl {
     };
 }
 
+// Opens the span of a call, it's handed over to the reply with traced()
+// so that it lasts until the reply has been sent
+macro_rules! trace_rpc_call {
+    ($name:expr) => {
+        tracer::span($name)
+    };
+}
+
+// traced keeps the span of a call open until its reply has been sent,
+// the spans opened afterwards by the handler thread aren't its children.
+fn traced<F>(mut span: tracer::Span, f: F) -> impl Future<Item = (), Error = ()>
+where
+    F: Future<Item = (), Error = ()>,
+{
+    span.detach();
+    f.then(move |res| {
+        drop(span);
+        res
+    })
+}
+
+// failed records the status a call fails with in its span.
+fn failed(span: &mut tracer::Span, status: RpcStatus) -> RpcStatus {
+    span.set_error(
+        format!(
+            "{:?}: {}",
+            status.status,
+            status.details.as_ref().map_or("", |d| d.as_str())
+        )
+        .as_str(),
+    );
+    status
+}
+
+// StreamCheck checks the requests of a client streaming RPC, which are
+// only read by its handler
+type StreamCheck = Arc<dyn Fn(&str, &dyn protobuf::Message) -> Result<()> + Send + Sync>;
+
+// OomWaiters pairs the GetOOMEvent calls waiting for an event with the
+// events no call was waiting for, in order.
+struct OomWaiters<S> {
+    sinks: VecDeque<S>,
+    events: VecDeque<String>,
+}
+
+impl<S> OomWaiters<S> {
+    fn new() -> Self {
+        OomWaiters {
+            sinks: VecDeque::new(),
+            events: VecDeque::new(),
+        }
+    }
+
+    // wait returns the sink with the oldest pending event, or keeps it
+    // until the next one.
+    fn wait(&mut self, sink: S) -> Option<(S, String)> {
+        match self.events.pop_front() {
+            Some(container_id) => Some((sink, container_id)),
+            None => {
+                self.sinks.push_back(sink);
+                None
+            }
+        }
+    }
+
+    // event returns the oldest waiting sink with the event, or keeps it
+    // until the next call.
+    fn event(&mut self, container_id: String) -> Option<(S, String)> {
+        match self.sinks.pop_front() {
+            Some(sink) => Some((sink, container_id)),
+            None => {
+                self.events.push_back(container_id);
+                None
+            }
+        }
+    }
+}
+
+type OomSinks = Arc<Mutex<OomWaiters<(UnarySink<OOMEvent>, tracer::Span)>>>;
+
 #[derive(Clone)]
 struct agentService {
     sandbox: Arc<Mutex<Sandbox>>,
-    test: u32,
+    stream_check: Option<StreamCheck>,
+    oom_waiters: OomSinks,
 }
 
 impl agentService {
@@ -78,32 +190,74 @@ impl agentService {
 
         let mut oci_spec = req.OCI.clone();
 
-        let sandbox;
-        let mut s;
-
         let oci = match oci_spec.as_mut() {
             Some(spec) => spec,
             None => {
                 error!(sl!(), "no oci spec in the create container request!");
-                return Err(
-                    ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::EINVAL)).into(),
-                );
+                return Err(ErrorKind::InvalidArgument("no oci spec".to_string()).into());
             }
         };
 
         info!(sl!(), "receive createcontainer {}", &cid);
 
-        // re-scan PCI bus
-        // looking for hidden devices
+        // an unknown seccomp handler fails the request before anything
+        // is set up, as long as some system calls are notified
+        if seccomp_notify::is_notified(oci) {
+            seccomp_notify::handlers(&oci.Annotations)?;
+        }
 
-        rescan_pci_bus().chain_err(|| "Could not rescan PCI bus")?;
+        let ctr = self.reserved(&cid, || {
+            let mut ctr = self.new_container(&cid, &req.devices, &req.storages, oci)?;
+
+            let pipe_size = AGENT_CONFIG.read().unwrap().container_pipe_size;
+            let p = if oci.Process.is_some() {
+                let tp = Process::new(&sl!(), oci.get_Process(), eid.as_str(), true, pipe_size)?;
+                tp
+            } else {
+                info!(sl!(), "no process configurations!");
+                return Err(
+                    ErrorKind::InvalidArgument("no process configurations".to_string()).into(),
+                );
+            };
+
+            // the sandbox isn't locked while the container starts, so slow
+            // hooks or mounts don't hold up the other containers
+            tracer::trace("start_container_process", || ctr.start(p))?;
+            Ok(ctr)
+        })?;
+        supervise_seccomp(&ctr, eid.as_str());
+
+        self.add_container(ctr);
+        info!(sl!(), "created container!");
+
+        Ok(())
+    }
+
+    // new_container sets the devices and storages of a container up, and
+    // creates it from its updated spec.
+    fn new_container(
+        &self,
+        cid: &str,
+        devices: &[protocols::agent::Device],
+        storages: &[protocols::agent::Storage],
+        oci: &mut Spec,
+    ) -> Result<LinuxContainer> {
+        // re-scan PCI bus
+        // looking for hidden devices, there's no hotplug when running
+        // standalone and the host bus is none of our business
+        if !AGENT_CONFIG.read().unwrap().standalone {
+            tracer::trace("rescan_pci_bus", rescan_pci_bus)
+                .chain_err(|| "Could not rescan PCI bus")?;
+        }
 
         // Some devices need some extra processing (the ones invoked with
         // --device for instance), and that's what this call is doing. It
         // updates the devices listed in the OCI spec, so that they actually
         // match real devices inside the VM. This step is necessary since we
         // cannot predict everything from the caller.
-        add_devices(&req.devices.to_vec(), oci, &self.sandbox)?;
+        tracer::trace("add_devices", || {
+            add_devices(&devices.to_vec(), oci, &self.sandbox)
+        })?;
 
         // Both rootfs and volumes (invoked with --volume for instance) will
         // be processed the same way. The idea is to always mount any provided
@@ -112,152 +266,159 @@ impl agentService {
         // After all those storages have been processed, no matter the order
         // here, the agent will rely on rustjail (using the oci.Mounts
         // list) to bind mount all of them inside the container.
-        let m = add_storages(sl!(), req.storages.to_vec(), self.sandbox.clone())?;
-        {
-            sandbox = self.sandbox.clone();
-            s = sandbox.lock().unwrap();
-            s.container_mounts.insert(cid.clone(), m);
-        }
-
-        update_container_namespaces(&s, oci)?;
+        let m = tracer::trace("add_storages", || {
+            add_storages(sl!(), storages.to_vec(), self.sandbox.clone())
+        })?;
+        let no_pivot_root = {
+            let mut s = self.sandbox.lock().unwrap();
+            s.container_mounts.insert(cid.to_string(), m);
+
+            update_container_namespaces(&s, oci)?;
+            s.add_guest_hooks(oci);
+            update_container_dns(&s, oci);
+            update_container_hostname(&s, oci);
+
+            s.no_pivot_root
+        };
 
         // write spec to bundle path, hooks might
         // read ocispec
-        let olddir = setup_bundle(oci)?;
-        // restore the cwd for kata-agent process.
-        defer!(unistd::chdir(&olddir).unwrap());
+        tracer::trace("setup_bundle", || setup_bundle(oci))?;
 
         let opts = CreateOpts {
             cgroup_name: "".to_string(),
-            use_systemd_cgroup: false,
-            no_pivot_root: s.no_pivot_root,
+            use_systemd_cgroup: oci
+                .Linux
+                .as_ref()
+                .map_or(false, |l| systemd::is_systemd_cgroup(&l.CgroupsPath)),
+            no_pivot_root,
             no_new_keyring: false,
             spec: Some(oci.clone()),
             rootless_euid: false,
             rootless_cgroup: false,
         };
 
-        let mut ctr: LinuxContainer =
-            LinuxContainer::new(cid.as_str(), CONTAINER_BASE, opts, &sl!())?;
+        tracer::trace("new_container", || {
+            LinuxContainer::new(cid, CONTAINER_BASE, opts, &sl!())
+        })
+    }
+
+    // reserved runs f, which creates the container cid, with that id
+    // reserved in the sandbox so that concurrent requests for it fail. The
+    // id is released if the container can't be created.
+    fn reserved<F>(&self, cid: &str, f: F) -> Result<LinuxContainer>
+    where
+        F: FnOnce() -> Result<LinuxContainer>,
+    {
+        if !self.sandbox.lock().unwrap().reserve_container(cid) {
+            return Err(ErrorKind::AlreadyExists(format!("container {}", cid)).into());
+        }
 
-        let pipe_size = AGENT_CONFIG.read().unwrap().container_pipe_size;
-        let p = if oci.Process.is_some() {
-            let tp = Process::new(&sl!(), oci.get_Process(), eid.as_str(), true, pipe_size)?;
-            tp
-        } else {
-            info!(sl!(), "no process configurations!");
-            return Err(ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::EINVAL)).into());
-        };
+        let res = f();
+        if res.is_err() {
+            self.sandbox.lock().unwrap().release_container(cid);
+        }
+
+        res
+    }
 
-        ctr.start(p)?;
+    // add_container adds a container which init process is running to
+    // the sandbox.
+    fn add_container(&self, mut ctr: LinuxContainer) {
+        let mut s = self.sandbox.lock().unwrap();
+        if let Some(rx) = ctr.oom_events.take() {
+            s.run_oom_event_monitor(rx, ctr.id.clone());
+        }
 
+        save_container(&ctr);
         s.add_container(ctr);
-        info!(sl!(), "created container!");
-
-        Ok(())
+        save_sandbox(&s);
     }
 
     fn do_start_container(&mut self, req: protocols::agent::StartContainerRequest) -> Result<()> {
         let cid = req.container_id.clone();
 
-        let sandbox = self.sandbox.clone();
-        let mut s = sandbox.lock().unwrap();
-
-        let ctr: &mut LinuxContainer = match s.get_container(cid.as_str()) {
-            Some(cr) => cr,
-            None => {
-                return Err(ErrorKind::Nix(nix::Error::from_errno(Errno::EINVAL)).into());
-            }
-        };
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
 
-        ctr.exec()?;
+        let mut ctr = ctr.lock().unwrap();
+        tracer::trace("exec_container_process", || ctr.exec())?;
+        save_container(&ctr);
 
         Ok(())
     }
 
-    fn do_remove_container(&mut self, req: protocols::agent::RemoveContainerRequest) -> Result<()> {
+    fn do_remove_container(
+        &mut self,
+        req: protocols::agent::RemoveContainerRequest,
+    ) -> Result<RemoveContainerResponse> {
         let cid = req.container_id.clone();
-        let mut cmounts: Vec<String> = vec![];
-
-        if req.timeout == 0 {
-            let s = Arc::clone(&self.sandbox);
-            let mut sandbox = s.lock().unwrap();
-            let ctr = sandbox.get_container(cid.as_str()).unwrap();
-
-            ctr.destroy()?;
-
-            // Find the sandbox storage used by this container
-            let mounts = sandbox.container_mounts.get(&cid);
-            if mounts.is_some() {
-                let mounts = mounts.unwrap();
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let mut resp = RemoveContainerResponse::new();
 
-                remove_mounts(&mounts)?;
-
-                for m in mounts.iter() {
-                    if sandbox.storages.get(m).is_some() {
-                        cmounts.push(m.to_string());
-                    }
+        let stop_signal = if req.signal == 0 {
+            Signal::SIGTERM
+        } else {
+            match Signal::try_from(req.signal as i32) {
+                Ok(sig) => sig,
+                Err(_) => {
+                    return Err(ErrorKind::InvalidArgument(format!(
+                        "invalid signal {}",
+                        req.signal
+                    ))
+                    .into())
                 }
             }
+        };
 
-            for m in cmounts.iter() {
-                sandbox.unset_and_remove_sandbox_storage(m)?;
-            }
-
-            sandbox.container_mounts.remove(cid.as_str());
-            sandbox.containers.remove(cid.as_str());
-
-            return Ok(());
-        }
-
-        // timeout != 0
-        let s = Arc::clone(&self.sandbox);
-        let cid2 = cid.clone();
-        let (tx, rx) = mpsc::channel();
-
-        let handle = thread::spawn(move || {
-            let mut sandbox = s.lock().unwrap();
-            let ctr = sandbox.get_container(cid2.as_str()).unwrap();
+        // the exit status of the init process is told by the reaper, none
+        // is left if it was waited for already
+        let exit_status = {
+            let ctr = ctr.lock().unwrap();
+            ctr.processes
+                .get(&ctr.init_process_pid)
+                .map(|p| p.lock().unwrap().watch_exit())
+        };
 
-            ctr.destroy().unwrap();
-            tx.send(1).unwrap();
-        });
+        // a zero timeout kills the container right away
+        let timeout = Duration::from_secs(req.timeout as u64);
+        resp.forced = stop_container(&ctr, stop_signal, timeout, req.force)?;
 
-        if let Err(_) = rx.recv_timeout(Duration::from_secs(req.timeout as u64)) {
-            return Err(ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::ETIME)).into());
-        }
+        resp.exit_status = match exit_status {
+            Some(rx) => rx
+                .recv_timeout(Duration::from_millis(EXIT_STATUS_TIMEOUT))
+                .unwrap_or(-1),
+            None => -1,
+        };
 
-        if let Err(_) = handle.join() {
-            return Err(
-                ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::UnknownErrno)).into(),
-            );
+        if let Some(cgm) = ctr.lock().unwrap().cgroup_manager.as_mut() {
+            cgm.destroy()?;
         }
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
         // Find the sandbox storage used by this container
-        let mounts = sandbox.container_mounts.get(&cid);
-        if mounts.is_some() {
-            let mounts = mounts.unwrap();
+        let mounts = match self.sandbox.lock().unwrap().container_mounts.get(&cid) {
+            Some(m) => m.clone(),
+            None => vec![],
+        };
 
-            remove_mounts(&mounts)?;
+        let mut sandbox = self.sandbox.lock().unwrap();
 
-            for m in mounts.iter() {
-                if sandbox.storages.get(m).is_some() {
-                    cmounts.push(m.to_string());
-                }
-            }
-        }
+        // the sandbox storages are only unmounted once no container uses
+        // them anymore
+        let (shared, own): (Vec<String>, Vec<String>) = mounts
+            .into_iter()
+            .partition(|m| sandbox.storages.get(m).is_some());
 
-        for m in cmounts.iter() {
+        remove_mounts(&own)?;
+
+        for m in shared.iter() {
             sandbox.unset_and_remove_sandbox_storage(m)?;
         }
 
         sandbox.container_mounts.remove(&cid);
-        sandbox.containers.remove(cid.as_str());
+        sandbox.remove_container(cid.as_str());
+        save_sandbox(&sandbox);
 
-        Ok(())
+        Ok(resp)
     }
 
     fn do_exec_process(&mut self, req: protocols::agent::ExecProcessRequest) -> Result<()> {
@@ -266,29 +427,29 @@ impl agentService {
 
         info!(sl!(), "cid: {} eid: {}", cid.clone(), exec_id.clone());
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        // ignore string_user, not sure what it is
-        let ocip = if req.process.is_some() {
-            req.process.as_ref().unwrap()
+        let mut ocip = if req.process.is_some() {
+            req.process.as_ref().unwrap().clone()
         } else {
-            return Err(ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::EINVAL)).into());
+            return Err(ErrorKind::InvalidArgument("no process to exec".to_string()).into());
         };
 
-        let pipe_size = AGENT_CONFIG.read().unwrap().container_pipe_size;
-        let p = Process::new(&sl!(), ocip, exec_id.as_str(), false, pipe_size)?;
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let mut ctr = ctr.lock().unwrap();
 
-        let ctr = match sandbox.get_container(cid.as_str()) {
-            Some(v) => v,
-            None => {
-                return Err(
-                    ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::EINVAL)).into(),
-                );
-            }
-        };
+        if let Some(user) = req.string_user.as_ref() {
+            update_process_user(&ctr, user, &mut ocip)?;
+        }
+
+        let pipe_size = AGENT_CONFIG.read().unwrap().container_pipe_size;
+        let p = Process::new(&sl!(), &ocip, exec_id.as_str(), false, pipe_size)?;
 
         ctr.run(p)?;
+        supervise_seccomp(&ctr, exec_id.as_str());
+        save_container(&ctr);
+
+        let pid = ctr.get_process(exec_id.as_str())?.lock().unwrap().pid;
+        drop(ctr);
+        self.sandbox.lock().unwrap().add_process(pid, cid.as_str());
 
         Ok(())
     }
@@ -296,8 +457,6 @@ impl agentService {
     fn do_signal_process(&mut self, req: protocols::agent::SignalProcessRequest) -> Result<()> {
         let cid = req.container_id.clone();
         let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
 
         info!(
             sl!(),
@@ -305,9 +464,27 @@ impl agentService {
             "container-id" => cid.clone(),
             "exec-id" => eid.clone()
         );
-        let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), true)?;
 
-        let mut signal = Signal::try_from(req.signal as i32).unwrap();
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        if ctr.lock().unwrap().is_paused() {
+            return Err(ErrorKind::FailedPrecondition(format!(
+                "cannot signal process in paused container {}",
+                cid
+            ))
+            .into());
+        }
+
+        let p = find_process(&self.sandbox, cid.as_str(), eid.as_str(), true)?;
+        let p = p.lock().unwrap();
+
+        let mut signal = match Signal::try_from(req.signal as i32) {
+            Ok(sig) => sig,
+            Err(_) => {
+                return Err(
+                    ErrorKind::InvalidArgument(format!("invalid signal {}", req.signal)).into(),
+                )
+            }
+        };
 
         // For container initProcess, if it hasn't installed handler for "SIGTERM" signal,
         // it will ignore the "SIGTERM" signal sent to it, thus send it "SIGKILL" signal
@@ -321,13 +498,158 @@ impl agentService {
         Ok(())
     }
 
+    fn do_list_processes(
+        &mut self,
+        req: protocols::agent::ListProcessesRequest,
+    ) -> Result<protocols::agent::ListProcessesResponse> {
+        let cid = req.container_id.clone();
+        let format = req.format.clone();
+        let mut resp = ListProcessesResponse::new();
+
+        let pids = {
+            let ctr = get_container(&self.sandbox, cid.as_str())?;
+            let ctr = ctr.lock().unwrap();
+
+            // every task in the container cgroup, not only the processes
+            // started by the agent
+            match ctr.cgroup_manager.as_ref() {
+                Some(cgm) => cgm.get_all_pids()?,
+                None => ctr.processes()?,
+            }
+        };
+
+        resp.process_list = match format.as_str() {
+            "table" => {
+                let ignored = ps::ignored_table_args(req.args.as_ref());
+                if !ignored.is_empty() {
+                    warn!(sl!(), "ignoring ps arguments {:?}", ignored);
+                }
+                Vec::from(ps::format_table(&ps::list_processes(&pids)?))
+            }
+            "json" => ps::format_pids(&pids)?,
+            "json-full" => ps::format_json(&ps::list_processes(&pids)?)?,
+            _ => {
+                return Err(
+                    ErrorKind::InvalidArgument(format!("invalid format {}", format)).into(),
+                );
+            }
+        };
+
+        Ok(resp)
+    }
+
+    fn do_update_container(&mut self, req: protocols::agent::UpdateContainerRequest) -> Result<()> {
+        let cid = req.container_id.clone();
+        let res = req.resources.clone();
+
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+
+        if res.is_some() {
+            ctr.lock().unwrap().set(res.unwrap())?;
+        }
+
+        Ok(())
+    }
+
+    fn do_stats_container(
+        &mut self,
+        req: protocols::agent::StatsContainerRequest,
+    ) -> Result<protocols::agent::StatsContainerResponse> {
+        let cid = req.container_id.clone();
+
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let stats = ctr.lock().unwrap().stats();
+
+        stats
+    }
+
+    fn do_pause_container(&mut self, req: protocols::agent::PauseContainerRequest) -> Result<()> {
+        let cid = req.container_id.clone();
+
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+
+        let mut ctr = ctr.lock().unwrap();
+        ctr.pause()?;
+        save_container(&ctr);
+
+        Ok(())
+    }
+
+    fn do_resume_container(&mut self, req: protocols::agent::ResumeContainerRequest) -> Result<()> {
+        let cid = req.container_id.clone();
+
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+
+        let mut ctr = ctr.lock().unwrap();
+        ctr.resume()?;
+        save_container(&ctr);
+
+        Ok(())
+    }
+
+    fn do_checkpoint_container(
+        &mut self,
+        req: protocols::agent::CheckpointContainerRequest,
+    ) -> Result<()> {
+        let cid = req.container_id.clone();
+        let opts = criu_opts(req.get_options())?;
+
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+
+        let mut ctr = ctr.lock().unwrap();
+        ctr.checkpoint(&opts)?;
+        save_container(&ctr);
+
+        Ok(())
+    }
+
+    fn do_restore_container(
+        &mut self,
+        req: protocols::agent::RestoreContainerRequest,
+    ) -> Result<()> {
+        let cid = req.container_id.clone();
+        let eid = req.exec_id.clone();
+        let opts = criu_opts(req.get_options())?;
+
+        let mut oci_spec = req.OCI.clone();
+        let oci = match oci_spec.as_mut() {
+            Some(spec) => spec,
+            None => return Err(ErrorKind::InvalidArgument("no oci spec".to_string()).into()),
+        };
+
+        info!(sl!(), "receive restorecontainer {}", &cid);
+
+        let ctr = self.reserved(&cid, || {
+            let mut ctr = self.new_container(&cid, &req.devices, &req.storages, oci)?;
+
+            // the process only brings the pipes its restored stdio is
+            // rewired to
+            let pipe_size = AGENT_CONFIG.read().unwrap().container_pipe_size;
+            let p = match oci.Process.as_ref() {
+                Some(ocip) => Process::new(&sl!(), ocip, eid.as_str(), true, pipe_size)?,
+                None => {
+                    return Err(
+                        ErrorKind::InvalidArgument("no process configurations".to_string()).into(),
+                    )
+                }
+            };
+
+            tracer::trace("restore_container_process", || ctr.restore(p, &opts))?;
+            Ok(ctr)
+        })?;
+
+        self.add_container(ctr);
+        info!(sl!(), "restored container!");
+
+        Ok(())
+    }
+
     fn do_wait_process(
         &mut self,
         req: protocols::agent::WaitProcessRequest,
     ) -> Result<protocols::agent::WaitProcessResponse> {
         let cid = req.container_id.clone();
         let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
         let mut resp = WaitProcessResponse::new();
         let pid: pid_t;
         let mut exit_pipe_r: RawFd = -1;
@@ -340,10 +662,9 @@ impl agentService {
             "exec-id" => eid.clone()
         );
 
+        let p = find_process(&self.sandbox, cid.as_str(), eid.as_str(), false)?;
         {
-            let mut sandbox = s.lock().unwrap();
-
-            let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
+            let p = p.lock().unwrap();
 
             if p.exit_pipe_r.is_some() {
                 exit_pipe_r = p.exit_pipe_r.unwrap();
@@ -357,10 +678,22 @@ impl agentService {
             let _ = unistd::read(exit_pipe_r, buf.as_mut_slice());
         }
 
-        let mut sandbox = s.lock().unwrap();
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
+        let ctr = get_container(&self.sandbox, cid.as_str())?;
+        let p = {
+            let mut ctr = ctr.lock().unwrap();
+            let p = ctr.processes.remove(&pid);
+            save_container(&ctr);
+            match p {
+                Some(p) => p,
+                None => return Err(ErrorKind::NotFound(format!("process {}", pid)).into()),
+            }
+        };
+        self.sandbox
+            .lock()
+            .unwrap()
+            .remove_process(pid, cid.as_str());
         // need to close all fds
-        let mut p = ctr.processes.get_mut(&pid).unwrap();
+        let mut p = p.lock().unwrap();
 
         if p.parent_stdin.is_some() {
             let _ = unistd::close(p.parent_stdin.unwrap());
@@ -389,8 +722,6 @@ impl agentService {
 
         resp.status = p.exit_code;
 
-        ctr.processes.remove(&pid);
-
         Ok(resp)
     }
 
@@ -408,17 +739,7 @@ impl agentService {
             "exec-id" => eid.clone()
         );
 
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-        let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
-
-        // use ptmx io
-        let fd = if p.term_master.is_some() {
-            p.term_master.unwrap()
-        } else {
-            // use piped io
-            p.parent_stdin.unwrap()
-        };
+        let fd = self.get_write_fd(cid.as_str(), eid.as_str())?;
 
         let mut l = req.data.len();
         match unistd::write(fd, req.data.as_slice()) {
@@ -460,36 +781,293 @@ impl agentService {
         let cid = req.container_id;
         let eid = req.exec_id;
 
-        let mut fd: RawFd = -1;
         info!(sl!(), "read stdout for {}/{}", cid.clone(), eid.clone());
-        {
-            let s = Arc::clone(&self.sandbox);
-            let mut sandbox = s.lock().unwrap();
+        let fd = self.get_read_fd(cid.as_str(), eid.as_str(), stdout)?;
 
-            let p = find_process(&mut sandbox, cid.as_str(), eid.as_str(), false)?;
+        let vector = read_stream(fd, req.len as usize)?;
 
-            if p.term_master.is_some() {
-                fd = p.term_master.unwrap();
-            } else if stdout {
-                if p.parent_stdout.is_some() {
-                    fd = p.parent_stdout.unwrap();
+        let mut resp = ReadStreamResponse::new();
+        resp.set_data(vector);
+
+        Ok(resp)
+    }
+
+    fn get_read_fd(&self, cid: &str, eid: &str, stdout: bool) -> Result<RawFd> {
+        let p = find_process(&self.sandbox, cid, eid, false)?;
+        let p = p.lock().unwrap();
+
+        read_fd(&p, eid, stdout)
+    }
+
+    fn get_write_fd(&self, cid: &str, eid: &str) -> Result<RawFd> {
+        let p = find_process(&self.sandbox, cid, eid, false)?;
+        let p = p.lock().unwrap();
+
+        write_fd(&p, eid)
+    }
+
+    fn do_stream_output(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ReadStreamRequest,
+        sink: ::grpcio::ServerStreamingSink<protocols::agent::ReadStreamResponse>,
+        stdout: bool,
+        mut span: tracer::Span,
+    ) {
+        let cid = req.container_id.clone();
+        let eid = req.exec_id.clone();
+
+        info!(
+            sl!(),
+            "stream output";
+            "container-id" => cid.clone(),
+            "exec-id" => eid.clone(),
+            "stdout" => stdout
+        );
+
+        // the fd is dup'ed while the process is locked, so that it stays
+        // valid once the process is waited for and its stdio closed
+        let fd = find_process(&self.sandbox, cid.as_str(), eid.as_str(), false).and_then(|p| {
+            let p = p.lock().unwrap();
+            let fd = read_fd(&p, eid.as_str(), stdout)?;
+            Ok(unistd::dup(fd)?)
+        });
+        let fd = match fd {
+            Ok(fd) => fd,
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(|_e| error!(sl!(), "stream output failed"));
+                ctx.spawn(traced(span, f));
+                return;
+            }
+        };
+
+        let len = if req.len == 0 {
+            STREAM_CHUNK_SIZE
+        } else {
+            req.len as usize
+        };
+
+        // The reader thread waits for each chunk to be taken by grpc
+        // before reading the next one, so a slow client throttles the
+        // reads instead of having the agent buffer the whole output.
+        span.detach();
+        thread::spawn(move || {
+            defer!({
+                let _ = unistd::close(fd);
+            });
+
+            let mut sink = sink;
+            let res = loop {
+                let data = match read_output(fd, len) {
+                    Ok(Some(data)) => data,
+                    // the process has exited or closed its output
+                    Ok(None) => break future::poll_fn(|| sink.close()).wait(),
+                    Err(e) => break sink.fail(failed(&mut span, rpc_status(&e))).wait(),
+                };
+
+                let mut resp = ReadStreamResponse::new();
+                resp.set_data(data);
+
+                sink = match sink.send((resp, WriteFlags::default())).wait() {
+                    Ok(sink) => sink,
+                    // the client has gone away
+                    Err(e) => break Err(e),
+                };
+            };
+
+            match res {
+                Ok(()) => info!(sl!(), "stream output finished";
+                    "container-id" => cid, "exec-id" => eid),
+                Err(e) => {
+                    span.set_error(format!("{:?}", e).as_str());
+                    error!(sl!(), "stream output failed: {:?}", e;
+                        "container-id" => cid, "exec-id" => eid)
                 }
-            } else {
-                fd = p.parent_stderr.unwrap();
             }
+        });
+    }
+
+    fn do_subscribe_memory_pressure(
+        &mut self,
+        req: protocols::agent::MemoryPressureRequest,
+    ) -> Result<(
+        futures::sync::mpsc::Receiver<Option<MemoryPressureEvent>>,
+        Vec<Subscription>,
+    )> {
+        let cid = req.container_id.clone();
+
+        let mut levels = req.levels.clone();
+        if levels.is_empty() {
+            levels = vec![
+                MemoryPressureLevel::LOW,
+                MemoryPressureLevel::MEDIUM,
+                MemoryPressureLevel::CRITICAL,
+            ];
         }
 
-        if fd == -1 {
-            return Err(ErrorKind::Nix(nix::Error::from_errno(nix::errno::Errno::EINVAL)).into());
+        let mut receivers = Vec::new();
+        let mut subscriptions = Vec::new();
+        {
+            let ctr = get_container(&self.sandbox, cid.as_str())?;
+            let ctr = ctr.lock().unwrap();
+
+            for level in levels.iter() {
+                let lvl = match level {
+                    MemoryPressureLevel::LOW => PressureLevel::Low,
+                    MemoryPressureLevel::MEDIUM => PressureLevel::Medium,
+                    MemoryPressureLevel::CRITICAL => PressureLevel::Critical,
+                };
+                let (notifications, subscription) = ctr.notify_memory_pressure(lvl)?;
+                receivers.push((*level, notifications));
+                subscriptions.push(subscription);
+            }
         }
 
-        let vector = read_stream(fd, req.len as usize)?;
+        // the stream ends once every notifier has gone away, with the
+        // container cgroup or the subscriptions
+        let (tx, rx) = futures::sync::mpsc::channel(levels.len() + 1);
+        let notifiers = Arc::new(());
+        for (level, notifications) in receivers {
+            let tx = tx.clone();
+            let cid = cid.clone();
+            let notifier = notifiers.clone();
+            thread::spawn(move || {
+                let _notifier = notifier;
+                let mut tx = tx;
+                for _ in notifications {
+                    let mut event = MemoryPressureEvent::new();
+                    event.set_container_id(cid.clone());
+                    event.set_level(level);
+
+                    tx = match tx.send(Some(event)).wait() {
+                        Ok(tx) => tx,
+                        // the subscriber has gone away
+                        Err(_) => break,
+                    };
+                }
+            });
+        }
 
-        let mut resp = ReadStreamResponse::new();
-        resp.set_data(vector);
+        // a quiet container sends no event, the empty ones have the call
+        // checked meanwhile, so that the subscriptions of a cancelled call
+        // are released
+        thread::spawn(move || {
+            let mut tx = tx;
+            while Arc::strong_count(&notifiers) > 1 {
+                thread::sleep(Duration::from_millis(PRESSURE_CHECK_INTERVAL));
+                tx = match tx.send(None).wait() {
+                    Ok(tx) => tx,
+                    Err(_) => break,
+                };
+            }
+        });
+
+        Ok((rx, subscriptions))
+    }
+
+    fn do_stream_stdin(
+        &self,
+        stream: ::grpcio::RequestStream<protocols::agent::WriteStreamRequest>,
+    ) -> Result<protocols::agent::WriteStreamResponse> {
+        let mut fd: Option<RawFd> = None;
+        let res = self.stream_to_stdin(stream, &mut fd);
+        if let Some(fd) = fd {
+            let _ = unistd::close(fd);
+        }
+
+        let mut resp = WriteStreamResponse::new();
+        resp.set_len(res? as u32);
 
         Ok(resp)
     }
+
+    // stream_to_stdin writes the stream to the stdin of its process,
+    // through a copy of the fd left in wfd for the caller to close.
+    fn stream_to_stdin(
+        &self,
+        stream: ::grpcio::RequestStream<protocols::agent::WriteStreamRequest>,
+        wfd: &mut Option<RawFd>,
+    ) -> Result<usize> {
+        let mut total: usize = 0;
+
+        for req in stream.wait() {
+            let req = match req {
+                Ok(r) => r,
+                Err(e) => {
+                    return Err(
+                        ErrorKind::ErrorCode(format!("receive stdin failed: {:?}", e)).into(),
+                    )
+                }
+            };
+
+            if let Some(check) = self.stream_check.as_ref() {
+                check("StreamStdin", &req)?;
+            }
+
+            let fd = match *wfd {
+                Some(v) => v,
+                None => {
+                    info!(
+                        sl!(),
+                        "stream stdin";
+                        "container-id" => req.container_id.clone(),
+                        "exec-id" => req.exec_id.clone()
+                    );
+                    // the fd is dup'ed while the process is locked, so
+                    // that closing its stdin doesn't leave a stale fd
+                    let p = find_process(
+                        &self.sandbox,
+                        req.container_id.as_str(),
+                        req.exec_id.as_str(),
+                        false,
+                    )?;
+                    let p = p.lock().unwrap();
+                    let v = unistd::dup(write_fd(&p, req.exec_id.as_str())?)?;
+                    *wfd = Some(v);
+                    v
+                }
+            };
+
+            let data = req.data.as_slice();
+            let mut written = 0;
+            while written < data.len() {
+                match unistd::write(fd, &data[written..]) {
+                    Ok(v) => written += v,
+                    Err(nix::Error::Sys(Errno::EINTR)) => continue,
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            total += written;
+        }
+
+        Ok(total)
+    }
+
+    fn do_update_dns(&self, req: protocols::agent::UpdateDNSRequest) -> Result<()> {
+        let mut s = self.sandbox.lock().unwrap();
+
+        s.network.update_dns(req.dns.to_vec());
+        s.network.setup_dns(SANDBOX_RESOLV_CONF)?;
+        save_sandbox(&s);
+
+        Ok(())
+    }
+
+    fn do_update_hostname(&self, req: protocols::agent::UpdateHostnameRequest) -> Result<()> {
+        let mut s = self.sandbox.lock().unwrap();
+
+        s.update_hostname(
+            req.hostname.as_str(),
+            req.hosts.to_vec(),
+            SANDBOX_HOSTNAME,
+            SANDBOX_HOSTS,
+        )?;
+        save_sandbox(&s);
+
+        Ok(())
+    }
 }
 
 impl protocols::agent_grpc::AgentService for agentService {
@@ -499,21 +1077,20 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::CreateContainerRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        if let Err(_) = self.do_create_container(req) {
+        let mut span = trace_rpc_call!("create_container");
+
+        if let Err(e) = self.do_create_container(req) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("fail to create container".to_string()),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(|_e| error!(sl!(), "container create fail"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
             return;
         } else {
             let resp = Empty::new();
             let f = sink
                 .success(resp)
                 .map_err(move |_e| error!(sl!(), "fail to create container"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
         }
     }
 
@@ -523,14 +1100,13 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::StartContainerRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        if let Err(_) = self.do_start_container(req) {
+        let mut span = trace_rpc_call!("start_container");
+
+        if let Err(e) = self.do_start_container(req) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("fail to find container".to_string()),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(move |_e| error!(sl!(), "get container fail"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
             return;
         }
 
@@ -540,29 +1116,30 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(resp)
             .map_err(move |_e| error!(sl!(), "fail to create container"));
-        ctx.spawn(f);
+        ctx.spawn(traced(span, f));
     }
 
     fn remove_container(
         &mut self,
         ctx: ::grpcio::RpcContext,
         req: protocols::agent::RemoveContainerRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        sink: ::grpcio::UnarySink<protocols::agent::RemoveContainerResponse>,
     ) {
-        if let Err(_) = self.do_remove_container(req) {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to remove container")),
-                ))
-                .map_err(move |_e| error!(sl!(), "remove container failed"));
-            ctx.spawn(f);
-        } else {
-            let resp = Empty::new();
-            let f = sink
-                .success(resp)
-                .map_err(|_e| error!(sl!(), "cannot destroy container"));
-            ctx.spawn(f);
+        let mut span = trace_rpc_call!("remove_container");
+
+        match self.do_remove_container(req) {
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(move |_e| error!(sl!(), "remove container failed"));
+                ctx.spawn(traced(span, f));
+            }
+            Ok(resp) => {
+                let f = sink
+                    .success(resp)
+                    .map_err(|_e| error!(sl!(), "cannot destroy container"));
+                ctx.spawn(traced(span, f));
+            }
         }
     }
     fn exec_process(
@@ -571,20 +1148,19 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ExecProcessRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        if let Err(_) = self.do_exec_process(req) {
+        let mut span = trace_rpc_call!("exec_process");
+
+        if let Err(e) = self.do_exec_process(req) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to exec process!")),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(|_e| error!(sl!(), "fail to exec process!"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
         } else {
             let resp = Empty::new();
             let f = sink
                 .success(resp)
                 .map_err(move |_e| error!(sl!(), "cannot exec process"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
         }
     }
     fn signal_process(
@@ -593,20 +1169,19 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::SignalProcessRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        if let Err(_) = self.do_signal_process(req) {
+        let mut span = trace_rpc_call!("signal_process");
+
+        if let Err(e) = self.do_signal_process(req) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to signal process!")),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(|_e| error!(sl!(), "fail to signal process!"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
         } else {
             let resp = Empty::new();
             let f = sink
                 .success(resp)
                 .map_err(|_e| error!(sl!(), "cannot signal process"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
         }
     }
     fn wait_process(
@@ -615,19 +1190,21 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::WaitProcessRequest,
         sink: ::grpcio::UnarySink<protocols::agent::WaitProcessResponse>,
     ) {
-        if let Ok(resp) = self.do_wait_process(req) {
-            let f = sink
-                .success(resp)
-                .map_err(|_e| error!(sl!(), "cannot wait process"));
-            ctx.spawn(f);
-        } else {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("fail to wait process!")),
-                ))
-                .map_err(|_e| error!(sl!(), "fail to wait process!"));
-            ctx.spawn(f);
+        let mut span = trace_rpc_call!("wait_process");
+
+        match self.do_wait_process(req) {
+            Ok(resp) => {
+                let f = sink
+                    .success(resp)
+                    .map_err(|_e| error!(sl!(), "cannot wait process"));
+                ctx.spawn(traced(span, f));
+            }
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(|_e| error!(sl!(), "fail to wait process!"));
+                ctx.spawn(traced(span, f));
+            }
         }
     }
     fn list_processes(
@@ -636,94 +1213,22 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ListProcessesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::ListProcessesResponse>,
     ) {
-        let cid = req.container_id.clone();
-        let format = req.format.clone();
-        let mut args = req.args.clone().into_vec();
-        let mut resp = ListProcessesResponse::new();
-
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
+        let mut span = trace_rpc_call!("list_processes");
 
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
-        let pids = ctr.processes().unwrap();
-
-        match format.as_str() {
-            "table" => {}
-            "json" => {
-                resp.process_list = serde_json::to_vec(&pids).unwrap();
+        match self.do_list_processes(req) {
+            Ok(resp) => {
                 let f = sink
                     .success(resp)
-                    .map_err(|_e| error!(sl!(), "cannot handle json resp"));
-                ctx.spawn(f);
-                return;
+                    .map_err(|_e| error!(sl!(), "list processes failed"));
+                ctx.spawn(traced(span, f));
             }
-            _ => {
+            Err(e) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::InvalidArgument,
-                        Some(String::from("invalid format")),
-                    ))
-                    .map_err(|_e| error!(sl!(), "invalid format!"));
-                ctx.spawn(f);
-                return;
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(|_e| error!(sl!(), "fail to list processes!"));
+                ctx.spawn(traced(span, f));
             }
         }
-
-        // format "table"
-        if args.len() == 0 {
-            // default argument
-            args = vec!["-ef".to_string()];
-        }
-
-        let output = Command::new("ps")
-            .args(args.as_slice())
-            .stdout(Stdio::piped())
-            .output()
-            .expect("ps failed");
-
-        let out: String = String::from_utf8(output.stdout).unwrap();
-        let mut lines: Vec<String> = out.split('\n').map(|v| v.to_string()).collect();
-
-        let predicate = |v| {
-            if v == "PID" {
-                return true;
-            } else {
-                return false;
-            }
-        };
-
-        let pid_index = lines[0].split_whitespace().position(predicate).unwrap();
-
-        let mut result = String::new();
-        result.push_str(lines[0].as_str());
-
-        lines.remove(0);
-        for line in &lines {
-            if line.trim().is_empty() {
-                continue;
-            }
-
-            let fields: Vec<String> = line.split_whitespace().map(|v| v.to_string()).collect();
-
-            if fields.len() < pid_index + 1 {
-                warn!(sl!(), "corrupted output?");
-                continue;
-            }
-            let pid = fields[pid_index].trim().parse::<i32>().unwrap();
-
-            for p in &pids {
-                if pid == *p {
-                    result.push_str(line.as_str());
-                }
-            }
-        }
-
-        resp.process_list = Vec::from(result);
-
-        let f = sink
-            .success(resp)
-            .map_err(|_e| error!(sl!(), "list processes failed"));
-        ctx.spawn(f);
     }
     fn update_container(
         &mut self,
@@ -731,38 +1236,20 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::UpdateContainerRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let cid = req.container_id.clone();
-        let res = req.resources.clone();
-
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
+        let mut span = trace_rpc_call!("update_container");
 
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
-
-        let resp = Empty::new();
-
-        if res.is_some() {
-            match ctr.set(res.unwrap()) {
-                Err(_e) => {
-                    let f = sink
-                        .fail(RpcStatus::new(
-                            RpcStatusCode::Internal,
-                            Some("internal error".to_string()),
-                        ))
-                        .map_err(|_e| error!(sl!(), "internal error!"));
-                    ctx.spawn(f);
-                    return;
-                }
-
-                Ok(()) => {}
-            }
+        if let Err(e) = self.do_update_container(req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "fail to update container!"));
+            ctx.spawn(traced(span, f));
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "update container failed!"));
+            ctx.spawn(traced(span, f));
         }
-
-        let f = sink
-            .success(resp)
-            .map_err(|_e| error!(sl!(), "update container failed!"));
-
-        ctx.spawn(f);
     }
     fn stats_container(
         &mut self,
@@ -770,45 +1257,106 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::StatsContainerRequest,
         sink: ::grpcio::UnarySink<protocols::agent::StatsContainerResponse>,
     ) {
-        let cid = req.container_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-
-        let ctr = sandbox.get_container(cid.as_str()).unwrap();
+        let mut span = trace_rpc_call!("stats_container");
 
-        let resp = match ctr.stats() {
-            Err(_e) => {
+        match self.do_stats_container(req) {
+            Ok(resp) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("internal error!".to_string()),
-                    ))
-                    .map_err(|_e| error!(sl!(), "internal error!"));
-                ctx.spawn(f);
-                return;
+                    .success(resp)
+                    .map_err(|_e| error!(sl!(), "stats containers failed!"));
+                ctx.spawn(traced(span, f));
+            }
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(|_e| error!(sl!(), "fail to stats container!"));
+                ctx.spawn(traced(span, f));
             }
+        }
+    }
+    fn pause_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::PauseContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let mut span = trace_rpc_call!("pause_container");
 
-            Ok(r) => r,
-        };
+        if let Err(e) = self.do_pause_container(req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "fail to pause container!"));
+            ctx.spawn(traced(span, f));
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "cannot pause container"));
+            ctx.spawn(traced(span, f));
+        }
+    }
+    fn resume_container(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ResumeContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let mut span = trace_rpc_call!("resume_container");
 
-        let f = sink
-            .success(resp)
-            .map_err(|_e| error!(sl!(), "stats containers failed!"));
-        ctx.spawn(f);
+        if let Err(e) = self.do_resume_container(req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "fail to resume container!"));
+            ctx.spawn(traced(span, f));
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "cannot resume container"));
+            ctx.spawn(traced(span, f));
+        }
     }
-    fn pause_container(
+    fn checkpoint_container(
         &mut self,
-        _ctx: ::grpcio::RpcContext,
-        _req: protocols::agent::PauseContainerRequest,
-        _sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::CheckpointContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("checkpoint_container");
+
+        if let Err(e) = self.do_checkpoint_container(req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "fail to checkpoint container!"));
+            ctx.spawn(traced(span, f));
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "cannot checkpoint container"));
+            ctx.spawn(traced(span, f));
+        }
     }
-    fn resume_container(
+    fn restore_container(
         &mut self,
-        _ctx: ::grpcio::RpcContext,
-        _req: protocols::agent::ResumeContainerRequest,
-        _sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::RestoreContainerRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("restore_container");
+
+        if let Err(e) = self.do_restore_container(req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "fail to restore container!"));
+            ctx.spawn(traced(span, f));
+        } else {
+            let resp = Empty::new();
+            let f = sink
+                .success(resp)
+                .map_err(|_e| error!(sl!(), "cannot restore container"));
+            ctx.spawn(traced(span, f));
+        }
     }
     fn write_stdin(
         &mut self,
@@ -816,20 +1364,22 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::WriteStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::WriteStreamResponse>,
     ) {
-        if let Ok(resp) = self.do_write_stream(req) {
-            let f = sink
-                .success(resp)
-                .map_err(|_e| error!(sl!(), "writestream request failed!"));
+        let mut span = trace_rpc_call!("write_stdin");
 
-            ctx.spawn(f);
-        } else {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::InvalidArgument,
-                    Some(String::from("write stream failed")),
-                ))
-                .map_err(move |_e| error!(sl!(), "write stream failed"));
-            ctx.spawn(f);
+        match self.do_write_stream(req) {
+            Ok(resp) => {
+                let f = sink
+                    .success(resp)
+                    .map_err(|_e| error!(sl!(), "writestream request failed!"));
+
+                ctx.spawn(traced(span, f));
+            }
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(move |_e| error!(sl!(), "write stream failed"));
+                ctx.spawn(traced(span, f));
+            }
         }
     }
     fn read_stdout(
@@ -838,20 +1388,22 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReadStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::ReadStreamResponse>,
     ) {
-        if let Ok(resp) = self.do_read_stream(req, true) {
-            let f = sink
-                .success(resp)
-                .map_err(move |_e| error!(sl!(), "read stdout error!"));
+        let mut span = trace_rpc_call!("read_stdout");
 
-            ctx.spawn(f);
-        } else {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("failed to read stdout")),
-                ))
-                .map_err(move |_e| error!(sl!(), "read stdout failed"));
-            ctx.spawn(f);
+        match self.do_read_stream(req, true) {
+            Ok(resp) => {
+                let f = sink
+                    .success(resp)
+                    .map_err(move |_e| error!(sl!(), "read stdout error!"));
+
+                ctx.spawn(traced(span, f));
+            }
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(move |_e| error!(sl!(), "read stdout failed"));
+                ctx.spawn(traced(span, f));
+            }
         }
     }
     fn read_stderr(
@@ -860,46 +1412,90 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReadStreamRequest,
         sink: ::grpcio::UnarySink<protocols::agent::ReadStreamResponse>,
     ) {
-        if let Ok(resp) = self.do_read_stream(req, false) {
-            let f = sink
-                .success(resp)
-                .map_err(move |_e| error!(sl!(), "read stderr error!"));
+        let mut span = trace_rpc_call!("read_stderr");
 
-            ctx.spawn(f);
-        } else {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some(String::from("failed to read stderr")),
-                ))
-                .map_err(move |_e| error!(sl!(), "read stderr failed"));
-            ctx.spawn(f);
+        match self.do_read_stream(req, false) {
+            Ok(resp) => {
+                let f = sink
+                    .success(resp)
+                    .map_err(move |_e| error!(sl!(), "read stderr error!"));
+
+                ctx.spawn(traced(span, f));
+            }
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(move |_e| error!(sl!(), "read stderr failed"));
+                ctx.spawn(traced(span, f));
+            }
         }
     }
+    fn stream_stdout(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ReadStreamRequest,
+        sink: ::grpcio::ServerStreamingSink<protocols::agent::ReadStreamResponse>,
+    ) {
+        let span = trace_rpc_call!("stream_stdout");
+
+        self.do_stream_output(ctx, req, sink, true, span)
+    }
+    fn stream_stderr(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::ReadStreamRequest,
+        sink: ::grpcio::ServerStreamingSink<protocols::agent::ReadStreamResponse>,
+    ) {
+        let span = trace_rpc_call!("stream_stderr");
+
+        self.do_stream_output(ctx, req, sink, false, span)
+    }
+    fn stream_stdin(
+        &mut self,
+        _ctx: ::grpcio::RpcContext,
+        stream: ::grpcio::RequestStream<protocols::agent::WriteStreamRequest>,
+        sink: ::grpcio::ClientStreamingSink<protocols::agent::WriteStreamResponse>,
+    ) {
+        let mut span = trace_rpc_call!("stream_stdin");
+
+        let svc = self.clone();
+
+        // writing to the process stdin may block, keep it away from the
+        // grpc completion queue threads
+        span.detach();
+        thread::spawn(move || {
+            let res = match svc.do_stream_stdin(stream) {
+                Ok(resp) => sink.success(resp).wait(),
+                Err(e) => sink.fail(failed(&mut span, rpc_status(&e))).wait(),
+            };
+
+            if let Err(e) = res {
+                error!(sl!(), "stream stdin failed: {:?}", e);
+            }
+        });
+    }
     fn close_stdin(
         &mut self,
         ctx: ::grpcio::RpcContext,
         req: protocols::agent::CloseStdinRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("close_stdin");
+
         let cid = req.container_id.clone();
         let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
 
-        let p = match find_process(&mut sandbox, cid.as_str(), eid.as_str(), false) {
+        let p = match find_process(&self.sandbox, cid.as_str(), eid.as_str(), false) {
             Ok(v) => v,
-            Err(_) => {
+            Err(e) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::InvalidArgument,
-                        Some(String::from("invalid argument")),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(|_e| error!(sl!(), "invalid argument"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         };
+        let mut p = p.lock().unwrap();
 
         if p.term_master.is_some() {
             let _ = unistd::close(p.term_master.unwrap());
@@ -916,7 +1512,7 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(resp)
             .map_err(|_e| error!(sl!(), "close stdin failed"));
-        ctx.spawn(f);
+        ctx.spawn(traced(span, f));
     }
 
     fn tty_win_resize(
@@ -925,32 +1521,30 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::TtyWinResizeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("tty_win_resize");
+
         let cid = req.container_id.clone();
         let eid = req.exec_id.clone();
-        let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
-        let p = match find_process(&mut sandbox, cid.as_str(), eid.as_str(), false) {
+        let p = match find_process(&self.sandbox, cid.as_str(), eid.as_str(), false) {
             Ok(v) => v,
             Err(e) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::InvalidArgument,
-                        Some(format!("invalid argument: {}", e)),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(|_e| error!(sl!(), "invalid argument"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         };
+        let p = p.lock().unwrap();
 
         if p.term_master.is_none() {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Unavailable,
-                    Some("no tty".to_string()),
+                .fail(failed(
+                    &mut span,
+                    RpcStatus::new(RpcStatusCode::Unavailable, Some("no tty".to_string())),
                 ))
                 .map_err(|_e| error!(sl!(), "tty resize"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
             return;
         }
 
@@ -964,14 +1558,11 @@ impl protocols::agent_grpc::AgentService for agentService {
             };
 
             let err = libc::ioctl(fd, TIOCSWINSZ, &win);
-            if let Err(_) = Errno::result(err).map(drop) {
+            if let Err(e) = Errno::result(err).map(drop) {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("ioctl error".to_string()),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e.into())))
                     .map_err(|_e| error!(sl!(), "ioctl error!"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         }
@@ -980,7 +1571,7 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn update_interface(
         &mut self,
@@ -988,26 +1579,32 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::UpdateInterfaceRequest,
         sink: ::grpcio::UnarySink<protocols::types::Interface>,
     ) {
-        let interface = req.interface.clone();
+        let mut span = trace_rpc_call!("update_interface");
+
+        let interface = match req.interface.as_ref() {
+            Some(v) => v.clone(),
+            None => {
+                let e: Error = ErrorKind::InvalidArgument("no interface".to_string()).into();
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(|_e| error!(sl!(), "update interface"));
+                ctx.spawn(traced(span, f));
+                return;
+            }
+        };
         let s = Arc::clone(&self.sandbox);
         let mut sandbox = s.lock().unwrap();
 
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
-
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
-
-        let iface = match rtnl.update_interface(interface.as_ref().unwrap()) {
+        let iface = match sandbox
+            .rtnl()
+            .and_then(|rtnl| rtnl.update_interface(&interface))
+        {
             Ok(v) => v,
-            Err(_) => {
+            Err(e) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("update interface".to_string()),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(|_e| error!(sl!(), "update interface"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         };
@@ -1015,7 +1612,7 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(iface)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn update_routes(
         &mut self,
@@ -1023,32 +1620,38 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::UpdateRoutesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Routes>,
     ) {
+        let mut span = trace_rpc_call!("update_routes");
+
         let mut routes = protocols::agent::Routes::new();
-        let rs = req.routes.clone().unwrap().Routes.into_vec();
+        let rs = match req.routes.as_ref() {
+            Some(v) => v.Routes.clone().into_vec(),
+            None => {
+                let e: Error = ErrorKind::InvalidArgument("no routes".to_string()).into();
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(|_e| error!(sl!(), "update routes"));
+                ctx.spawn(traced(span, f));
+                return;
+            }
+        };
 
         let s = Arc::clone(&self.sandbox);
         let mut sandbox = s.lock().unwrap();
-
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
-
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
         // get current routes to return when error out
-        let crs = match rtnl.list_routes() {
+        let crs = match sandbox.rtnl().and_then(|rtnl| rtnl.list_routes()) {
             Ok(routes) => routes,
-            Err(_) => {
+            Err(e) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("update routes".to_string()),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(|_e| error!(sl!(), "update routes"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         };
-        let v = match rtnl.update_routes(rs.as_ref()) {
+        let v = match sandbox
+            .rtnl()
+            .and_then(|rtnl| rtnl.update_routes(rs.as_ref()))
+        {
             Ok(value) => value,
             Err(_) => crs,
         };
@@ -1059,7 +1662,7 @@ impl protocols::agent_grpc::AgentService for agentService {
             .success(routes)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
 
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn list_interfaces(
         &mut self,
@@ -1067,25 +1670,18 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ListInterfacesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Interfaces>,
     ) {
+        let mut span = trace_rpc_call!("list_interfaces");
+
         let mut interface = protocols::agent::Interfaces::new();
         let s = Arc::clone(&self.sandbox);
         let mut sandbox = s.lock().unwrap();
-
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
-
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
-        let v = match rtnl.list_interfaces() {
+        let v = match sandbox.rtnl().and_then(|rtnl| rtnl.list_interfaces()) {
             Ok(value) => value,
-            Err(_) => {
+            Err(e) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("list interface".to_string()),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(|_e| error!(sl!(), "list interface"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         };
@@ -1095,7 +1691,7 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(interface)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn list_routes(
         &mut self,
@@ -1103,26 +1699,19 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ListRoutesRequest,
         sink: ::grpcio::UnarySink<protocols::agent::Routes>,
     ) {
+        let mut span = trace_rpc_call!("list_routes");
+
         let mut routes = protocols::agent::Routes::new();
         let s = Arc::clone(&self.sandbox);
         let mut sandbox = s.lock().unwrap();
 
-        if sandbox.rtnl.is_none() {
-            sandbox.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap());
-        }
-
-        let rtnl = sandbox.rtnl.as_mut().unwrap();
-
-        let v = match rtnl.list_routes() {
+        let v = match sandbox.rtnl().and_then(|rtnl| rtnl.list_routes()) {
             Ok(value) => value,
-            Err(_) => {
+            Err(e) => {
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some("list routes".to_string()),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(|_e| error!(sl!(), "list routes"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         };
@@ -1132,7 +1721,28 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(routes)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
+    }
+    fn update_dns(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::UpdateDNSRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let mut span = trace_rpc_call!("update_dns");
+
+        if let Err(e) = self.do_update_dns(req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "update dns failed"));
+            ctx.spawn(traced(span, f));
+            return;
+        }
+
+        let f = sink
+            .success(Empty::new())
+            .map_err(|_e| error!(sl!(), "update dns failed"));
+        ctx.spawn(traced(span, f))
     }
     fn start_tracing(
         &mut self,
@@ -1140,13 +1750,23 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::StartTracingRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        info!(sl!(), "start_tracing {:?} self.test={}", req, self.test);
-        self.test = 2;
+        let mut span = trace_rpc_call!("start_tracing");
+
+        let port = AGENT_CONFIG.read().unwrap().trace_vport as u32;
+
+        if let Err(e) = tracer::start_tracing(port) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            ctx.spawn(traced(span, f));
+            return;
+        }
+
         let empty = protocols::empty::Empty::new();
         let f = sink
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn stop_tracing(
         &mut self,
@@ -1154,11 +1774,21 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::StopTracingRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("stop_tracing");
+
+        if let Err(e) = tracer::stop_tracing() {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            ctx.spawn(traced(span, f));
+            return;
+        }
+
         let empty = protocols::empty::Empty::new();
         let f = sink
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn create_sandbox(
         &mut self,
@@ -1166,7 +1796,9 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::CreateSandboxRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
-        let mut err = "".to_string();
+        let mut span = trace_rpc_call!("create_sandbox");
+
+        let mut err: Option<Error> = None;
 
         {
             let sandbox = self.sandbox.clone();
@@ -1184,15 +1816,50 @@ impl protocols::agent_grpc::AgentService for agentService {
 
             match s.setup_shared_namespaces() {
                 Ok(_) => (),
-                Err(e) => err = e.to_string(),
+                Err(e) => err = Some(e),
+            }
+
+            if err.is_none() && req.sandbox_pidns {
+                if let Err(e) = s.setup_shared_pid_namespace() {
+                    err = Some(e);
+                }
+            }
+
+            // the sandbox runs fine without its guest hooks
+            if err.is_none() && !req.guest_hook_path.is_empty() {
+                let timeout = AGENT_CONFIG.read().unwrap().guest_hook_timeout;
+                if let Err(e) = s.add_hooks(req.guest_hook_path.as_str(), timeout) {
+                    warn!(sl!(), "cannot add the guest hooks";
+                          "path" => req.guest_hook_path.as_str(), "error" => format!("{}", e));
+                }
+            }
+
+            // the file is written even without entries, the containers
+            // bind mount it to see the ones set later on
+            if err.is_none() {
+                s.network.update_dns(req.dns.to_vec());
+                if let Err(e) = s.network.setup_dns(SANDBOX_RESOLV_CONF) {
+                    err = Some(e);
+                }
+            }
+
+            // as for DNS, the files are there for the containers to bind
+            // mount them, whether the hostname is set now or later on
+            if err.is_none() {
+                let res = if s.hostname.is_empty() {
+                    s.write_hostname_files(SANDBOX_HOSTNAME, SANDBOX_HOSTS)
+                } else {
+                    s.setup_hostname(SANDBOX_HOSTNAME, SANDBOX_HOSTS)
+                };
+                if let Err(e) = res {
+                    err = Some(e);
+                }
             }
-            if err.len() != 0 {
-                let rpc_status =
-                    grpcio::RpcStatus::new(grpcio::RpcStatusCode::FailedPrecondition, Some(err));
+            if let Some(e) = err {
                 let f = sink
-                    .fail(rpc_status)
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         }
@@ -1201,18 +1868,17 @@ impl protocols::agent_grpc::AgentService for agentService {
             Ok(m) => {
                 let sandbox = self.sandbox.clone();
                 let mut s = sandbox.lock().unwrap();
-                s.mounts = m
+                s.mounts = m;
+                save_sandbox(&s);
             }
-            Err(e) => err = e.to_string(),
+            Err(e) => err = Some(e),
         };
 
-        if err.len() != 0 {
-            let rpc_status =
-                grpcio::RpcStatus::new(grpcio::RpcStatusCode::FailedPrecondition, Some(err));
+        if let Some(e) = err {
             let f = sink
-                .fail(rpc_status)
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
             return;
         }
 
@@ -1220,7 +1886,28 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
+    }
+    fn update_hostname(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::UpdateHostnameRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let mut span = trace_rpc_call!("update_hostname");
+
+        if let Err(e) = self.do_update_hostname(req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "update hostname failed"));
+            ctx.spawn(traced(span, f));
+            return;
+        }
+
+        let f = sink
+            .success(Empty::new())
+            .map_err(|_e| error!(sl!(), "update hostname failed"));
+        ctx.spawn(traced(span, f))
     }
     fn destroy_sandbox(
         &mut self,
@@ -1228,20 +1915,35 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::DestroySandboxRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("destroy_sandbox");
+
         let s = Arc::clone(&self.sandbox);
-        let mut sandbox = s.lock().unwrap();
         // destroy all containers, clean up, notify agent to exit
         // etc.
-        sandbox.destroy().unwrap();
+        let res = sandbox::destroy(&s).and_then(|_| match fs::remove_dir_all(CONTAINER_BASE) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(Error::from(e)).chain_err(|| format!("cannot remove {}", CONTAINER_BASE))
+            }
+            _ => Ok(()),
+        });
 
-        sandbox.sender.as_ref().unwrap().send(1).unwrap();
-        sandbox.sender = None;
+        if let Err(e) = res {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+            ctx.spawn(traced(span, f));
+            return;
+        }
+
+        if let Some(sender) = s.lock().unwrap().sender.take() {
+            let _ = sender.send(1);
+        }
 
         let empty = protocols::empty::Empty::new();
         let f = sink
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn online_cpu_mem(
         &mut self,
@@ -1249,20 +1951,19 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::OnlineCPUMemRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("online_cpu_mem");
+
         // sleep 5 seconds for debug
         // thread::sleep(Duration::new(5, 0));
         let s = Arc::clone(&self.sandbox);
         let sandbox = s.lock().unwrap();
         let empty = protocols::empty::Empty::new();
 
-        if let Err(_) = sandbox.online_cpu_memory(&req) {
+        if let Err(e) = sandbox.online_cpu_memory(&req) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("Internal error".to_string()),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(|_e| error!(sl!(), "cannot online memory/cpu"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
             return;
         }
 
@@ -1270,7 +1971,7 @@ impl protocols::agent_grpc::AgentService for agentService {
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
 
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn reseed_random_dev(
         &mut self,
@@ -1278,22 +1979,21 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::ReseedRandomDevRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("reseed_random_dev");
+
         let empty = protocols::empty::Empty::new();
-        if let Err(_) = random::reseed_rng(req.data.as_slice()) {
+        if let Err(e) = random::reseed_rng(req.data.as_slice()) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("Internal error".to_string()),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(|_e| error!(sl!(), "fail to reseed rng!"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
             return;
         }
 
         let f = sink
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn get_guest_details(
         &mut self,
@@ -1301,6 +2001,8 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::GuestDetailsRequest,
         sink: ::grpcio::UnarySink<protocols::agent::GuestDetailsResponse>,
     ) {
+        let mut span = trace_rpc_call!("get_guest_details");
+
         info!(sl!(), "get guest details!");
         let mut resp = GuestDetailsResponse::new();
         // to get memory block size
@@ -1310,15 +2012,12 @@ impl protocols::agent_grpc::AgentService for agentService {
                 resp.support_mem_hotplug_probe = v;
             }
 
-            Err(_) => {
+            Err(e) => {
                 info!(sl!(), "fail to get memory info!");
                 let f = sink
-                    .fail(RpcStatus::new(
-                        RpcStatusCode::Internal,
-                        Some(String::from("internal error")),
-                    ))
+                    .fail(failed(&mut span, rpc_status(&e)))
                     .map_err(|_e| error!(sl!(), "cannot get memory info!"));
-                ctx.spawn(f);
+                ctx.spawn(traced(span, f));
                 return;
             }
         }
@@ -1330,7 +2029,7 @@ impl protocols::agent_grpc::AgentService for agentService {
         let f = sink
             .success(resp)
             .map_err(|_e| error!(sl!(), "cannot get guest detail"));
-        ctx.spawn(f);
+        ctx.spawn(traced(span, f));
     }
     fn mem_hotplug_by_probe(
         &mut self,
@@ -1338,23 +2037,22 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::MemHotplugByProbeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("mem_hotplug_by_probe");
+
         let empty = protocols::empty::Empty::new();
 
-        if let Err(_) = do_mem_hotplug_by_probe(&req.memHotplugProbeAddr) {
+        if let Err(e) = do_mem_hotplug_by_probe(&req.memHotplugProbeAddr) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("internal error!".to_string()),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(|_e| error!(sl!(), "cannont mem hotplug by probe!"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
             return;
         }
 
         let f = sink
             .success(empty)
             .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+        ctx.spawn(traced(span, f))
     }
     fn set_guest_date_time(
         &mut self,
@@ -1362,45 +2060,257 @@ impl protocols::agent_grpc::AgentService for agentService {
         req: protocols::agent::SetGuestDateTimeRequest,
         sink: ::grpcio::UnarySink<protocols::empty::Empty>,
     ) {
+        let mut span = trace_rpc_call!("set_guest_date_time");
+
         let empty = protocols::empty::Empty::new();
-        if let Err(_) = do_set_guest_date_time(req.Sec, req.Usec) {
+        if let Err(e) = do_set_guest_date_time(req.Sec, req.Usec) {
             let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("internal error!".to_string()),
-                ))
+                .fail(failed(&mut span, rpc_status(&e)))
                 .map_err(|_e| error!(sl!(), "cannot set guest time!"));
-            ctx.spawn(f);
+            ctx.spawn(traced(span, f));
+            return;
+        }
+
+        let f = sink
+            .success(empty)
+            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+        ctx.spawn(traced(span, f))
+    }
+    fn copy_file(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::CopyFileRequest,
+        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+    ) {
+        let mut span = trace_rpc_call!("copy_file");
+
+        let empty = protocols::empty::Empty::new();
+        if let Err(e) = do_copy_file(&req) {
+            let f = sink
+                .fail(failed(&mut span, rpc_status(&e)))
+                .map_err(|_e| error!(sl!(), "cannot copy file!"));
+            ctx.spawn(traced(span, f));
             return;
         }
 
         let f = sink
-            .success(empty)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+            .success(empty)
+            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
+        ctx.spawn(traced(span, f))
+    }
+
+    fn subscribe_memory_pressure(
+        &mut self,
+        ctx: ::grpcio::RpcContext,
+        req: protocols::agent::MemoryPressureRequest,
+        sink: ::grpcio::ServerStreamingSink<protocols::agent::MemoryPressureEvent>,
+    ) {
+        let mut span = trace_rpc_call!("subscribe_memory_pressure");
+
+        let (rx, subscriptions) = match self.do_subscribe_memory_pressure(req) {
+            Ok(v) => v,
+            Err(e) => {
+                let f = sink
+                    .fail(failed(&mut span, rpc_status(&e)))
+                    .map_err(|_e| error!(sl!(), "subscribe memory pressure failed"));
+                ctx.spawn(traced(span, f));
+                return;
+            }
+        };
+
+        // the sink fails on the first poll after the call is cancelled,
+        // which the empty events make sure happens
+        span.detach();
+        let f = sink
+            .send_all(
+                rx.filter_map(|event| event)
+                    .map(|event| (event, WriteFlags::default()))
+                    .map_err(|_| grpcio::Error::RemoteStopped),
+            )
+            .then(move |res| {
+                // release the notifiers once the subscriber has gone away
+                drop(subscriptions);
+                if let Err(e) = res.as_ref() {
+                    span.set_error(format!("{:?}", e).as_str());
+                }
+                res.map(|_| ())
+            })
+            .map_err(|e| error!(sl!(), "memory pressure stream failed: {:?}", e));
+        ctx.spawn(f);
     }
-    fn copy_file(
+
+    fn get_oom_event(
         &mut self,
         ctx: ::grpcio::RpcContext,
-        req: protocols::agent::CopyFileRequest,
-        sink: ::grpcio::UnarySink<protocols::empty::Empty>,
+        _req: protocols::agent::GetOOMEventRequest,
+        sink: ::grpcio::UnarySink<protocols::agent::OOMEvent>,
     ) {
-        let empty = protocols::empty::Empty::new();
-        if let Err(_) = do_copy_file(&req) {
-            let f = sink
-                .fail(RpcStatus::new(
-                    RpcStatusCode::Internal,
-                    Some("Internal error!".to_string()),
-                ))
-                .map_err(|_e| error!(sl!(), "cannot copy file!"));
-            ctx.spawn(f);
-            return;
+        let mut span = trace_rpc_call!("get_oom_event");
+
+        // long poll: the call waits for the OOM dispatcher unless an
+        // event is already pending
+        span.detach();
+        let pending = self.oom_waiters.lock().unwrap().wait((sink, span));
+        let ((sink, span), container_id) = match pending {
+            Some(w) => w,
+            None => return,
+        };
+
+        let event_tx = self.sandbox.lock().unwrap().event_tx.clone();
+        info!(sl!(), "get_oom_event return {}", &container_id);
+        let mut resp = OOMEvent::new();
+        resp.container_id = container_id.clone();
+        let f = sink.success(resp).then(move |res| {
+            if let Err(e) = res {
+                error!(sl!(), "get OOM event failed: {:?}", e);
+                let _ = event_tx.send(container_id);
+            }
+            Ok(())
+        });
+        ctx.spawn(traced(span, f));
+    }
+}
+
+// policyService enforces the RPC policy in front of agentService, it
+// is the only place where the policy is checked.
+#[derive(Clone)]
+struct policyService {
+    inner: agentService,
+    policy: Arc<Policy>,
+}
+
+impl policyService {
+    fn check(&self, rpc: &str, req: Option<&dyn protobuf::Message>) -> Result<()> {
+        check_policy(&self.policy, rpc, req)
+    }
+}
+
+fn check_policy(policy: &Policy, rpc: &str, req: Option<&dyn protobuf::Message>) -> Result<()> {
+    policy.check(rpc, req).map_err(|e| {
+        warn!(sl!(), "RPC denied"; "rpc" => rpc, "error" => format!("{}", e));
+        e
+    })
+}
+
+macro_rules! impl_policy_service {
+    (
+        unary { $($u_method:ident, $u_rpc:expr, $u_req:ty, $u_resp:ty;)* }
+        server_streaming { $($s_method:ident, $s_rpc:expr, $s_req:ty, $s_resp:ty;)* }
+        client_streaming { $($c_method:ident, $c_rpc:expr, $c_req:ty, $c_resp:ty;)* }
+    ) => {
+        // names of the RPCs, as in agent.proto, that the policy refers to
+        const AGENT_RPCS: &[&str] = &[$($u_rpc,)* $($s_rpc,)* $($c_rpc,)*];
+
+        impl protocols::agent_grpc::AgentService for policyService {
+            $(
+                fn $u_method(
+                    &mut self,
+                    ctx: ::grpcio::RpcContext,
+                    req: $u_req,
+                    sink: ::grpcio::UnarySink<$u_resp>,
+                ) {
+                    if let Err(e) = self.check($u_rpc, Some(&req)) {
+                        let f = sink
+                            .fail(rpc_status(&e))
+                            .map_err(|_e| error!(sl!(), "fail to deny {}", $u_rpc));
+                        ctx.spawn(f);
+                        return;
+                    }
+
+                    self.inner.$u_method(ctx, req, sink)
+                }
+            )*
+
+            $(
+                fn $s_method(
+                    &mut self,
+                    ctx: ::grpcio::RpcContext,
+                    req: $s_req,
+                    sink: ::grpcio::ServerStreamingSink<$s_resp>,
+                ) {
+                    if let Err(e) = self.check($s_rpc, Some(&req)) {
+                        let f = sink
+                            .fail(rpc_status(&e))
+                            .map_err(|_e| error!(sl!(), "fail to deny {}", $s_rpc));
+                        ctx.spawn(f);
+                        return;
+                    }
+
+                    self.inner.$s_method(ctx, req, sink)
+                }
+            )*
+
+            $(
+                // the requests are only read by the handler, which checks
+                // each of them through its stream_check
+                fn $c_method(
+                    &mut self,
+                    ctx: ::grpcio::RpcContext,
+                    stream: ::grpcio::RequestStream<$c_req>,
+                    sink: ::grpcio::ClientStreamingSink<$c_resp>,
+                ) {
+                    self.inner.$c_method(ctx, stream, sink)
+                }
+            )*
         }
+    };
+}
 
-        let f = sink
-            .success(empty)
-            .map_err(move |e| error!(sl!(), "failed to reply {:?}: {:?}", req, e));
-        ctx.spawn(f)
+impl_policy_service! {
+    unary {
+        create_container, "CreateContainer", protocols::agent::CreateContainerRequest, Empty;
+        start_container, "StartContainer", protocols::agent::StartContainerRequest, Empty;
+        remove_container, "RemoveContainer", protocols::agent::RemoveContainerRequest, protocols::agent::RemoveContainerResponse;
+        exec_process, "ExecProcess", protocols::agent::ExecProcessRequest, Empty;
+        signal_process, "SignalProcess", protocols::agent::SignalProcessRequest, Empty;
+        wait_process, "WaitProcess", protocols::agent::WaitProcessRequest, WaitProcessResponse;
+        list_processes, "ListProcesses", protocols::agent::ListProcessesRequest,
+            ListProcessesResponse;
+        update_container, "UpdateContainer", protocols::agent::UpdateContainerRequest, Empty;
+        stats_container, "StatsContainer", protocols::agent::StatsContainerRequest,
+            protocols::agent::StatsContainerResponse;
+        pause_container, "PauseContainer", protocols::agent::PauseContainerRequest, Empty;
+        resume_container, "ResumeContainer", protocols::agent::ResumeContainerRequest, Empty;
+        checkpoint_container, "CheckpointContainer", protocols::agent::CheckpointContainerRequest,
+            Empty;
+        restore_container, "RestoreContainer", protocols::agent::RestoreContainerRequest, Empty;
+        write_stdin, "WriteStdin", protocols::agent::WriteStreamRequest, WriteStreamResponse;
+        read_stdout, "ReadStdout", protocols::agent::ReadStreamRequest, ReadStreamResponse;
+        read_stderr, "ReadStderr", protocols::agent::ReadStreamRequest, ReadStreamResponse;
+        close_stdin, "CloseStdin", protocols::agent::CloseStdinRequest, Empty;
+        tty_win_resize, "TtyWinResize", protocols::agent::TtyWinResizeRequest, Empty;
+        update_interface, "UpdateInterface", protocols::agent::UpdateInterfaceRequest,
+            protocols::types::Interface;
+        update_routes, "UpdateRoutes", protocols::agent::UpdateRoutesRequest,
+            protocols::agent::Routes;
+        list_interfaces, "ListInterfaces", protocols::agent::ListInterfacesRequest,
+            protocols::agent::Interfaces;
+        list_routes, "ListRoutes", protocols::agent::ListRoutesRequest, protocols::agent::Routes;
+        update_dns, "UpdateDNS", protocols::agent::UpdateDNSRequest, Empty;
+        start_tracing, "StartTracing", protocols::agent::StartTracingRequest, Empty;
+        stop_tracing, "StopTracing", protocols::agent::StopTracingRequest, Empty;
+        create_sandbox, "CreateSandbox", protocols::agent::CreateSandboxRequest, Empty;
+        destroy_sandbox, "DestroySandbox", protocols::agent::DestroySandboxRequest, Empty;
+        update_hostname, "UpdateHostname", protocols::agent::UpdateHostnameRequest, Empty;
+        online_cpu_mem, "OnlineCPUMem", protocols::agent::OnlineCPUMemRequest, Empty;
+        reseed_random_dev, "ReseedRandomDev", protocols::agent::ReseedRandomDevRequest, Empty;
+        get_guest_details, "GetGuestDetails", protocols::agent::GuestDetailsRequest,
+            GuestDetailsResponse;
+        mem_hotplug_by_probe, "MemHotplugByProbe", protocols::agent::MemHotplugByProbeRequest,
+            Empty;
+        set_guest_date_time, "SetGuestDateTime", protocols::agent::SetGuestDateTimeRequest,
+            Empty;
+        copy_file, "CopyFile", CopyFileRequest, Empty;
+        get_oom_event, "GetOOMEvent", protocols::agent::GetOOMEventRequest, OOMEvent;
+    }
+    server_streaming {
+        stream_stdout, "StreamStdout", protocols::agent::ReadStreamRequest, ReadStreamResponse;
+        stream_stderr, "StreamStderr", protocols::agent::ReadStreamRequest, ReadStreamResponse;
+        subscribe_memory_pressure, "SubscribeMemoryPressure",
+            protocols::agent::MemoryPressureRequest, MemoryPressureEvent;
+    }
+    client_streaming {
+        stream_stdin, "StreamStdin", protocols::agent::WriteStreamRequest, WriteStreamResponse;
     }
 }
 
@@ -1488,7 +2398,7 @@ fn get_agent_details() -> AgentDetails {
     let mut detail = AgentDetails::new();
 
     detail.set_version(AGENT_VERSION.to_string());
-    detail.set_supports_seccomp(false);
+    detail.set_supports_seccomp(seccomp::is_supported());
     detail.init_daemon = { unistd::getpid() == Pid::from_raw(1) };
 
     detail.device_handlers = RepeatedField::new();
@@ -1531,22 +2441,140 @@ fn read_stream(fd: RawFd, l: usize) -> Result<Vec<u8>> {
     Ok(v)
 }
 
-fn find_process<'a>(
-    sandbox: &'a mut Sandbox,
-    cid: &'a str,
-    eid: &'a str,
-    init: bool,
-) -> Result<&'a mut Process> {
-    let ctr = match sandbox.get_container(cid) {
-        Some(v) => v,
-        None => return Err(ErrorKind::ErrorCode(String::from("Invalid container id")).into()),
+// dispatch_oom_events replies to the waiting GetOOMEvent calls with the
+// OOM events of the sandbox, a single thread waiting for them whatever
+// the number of calls.
+fn dispatch_oom_events(sandbox: &Arc<Mutex<Sandbox>>, waiters: OomSinks) {
+    let (event_rx, event_tx) = {
+        let s = sandbox.lock().unwrap();
+        (s.event_rx.clone(), s.event_tx.clone())
+    };
+
+    thread::spawn(move || {
+        let event_rx = event_rx.lock().unwrap();
+        for container_id in event_rx.iter() {
+            let ((sink, mut span), container_id) = match waiters.lock().unwrap().event(container_id)
+            {
+                Some(w) => w,
+                None => continue,
+            };
+
+            if let Err(e) =
+                send_oom_event(&event_tx, container_id, |resp| sink.success(resp).wait())
+            {
+                span.set_error(format!("{:?}", e).as_str());
+                error!(sl!(), "get OOM event failed: {:?}", e);
+            }
+        }
+    });
+}
+
+// send_oom_event replies with the OOM event of a container, which is
+// requeued if the reply can't be sent, e.g. the client has gone away.
+fn send_oom_event<F>(
+    event_tx: &Sender<String>,
+    container_id: String,
+    reply: F,
+) -> grpcio::Result<()>
+where
+    F: FnOnce(OOMEvent) -> grpcio::Result<()>,
+{
+    info!(sl!(), "get_oom_event return {}", &container_id);
+    let mut resp = OOMEvent::new();
+    resp.container_id = container_id.clone();
+
+    let res = reply(resp);
+    if res.is_err() {
+        let _ = event_tx.send(container_id);
+    }
+
+    res
+}
+
+// read_output waits for the output of a process and reads it, returning
+// None at the end of it.
+fn read_output(fd: RawFd, len: usize) -> Result<Option<Vec<u8>>> {
+    let mut buf = vec![0; len];
+
+    loop {
+        let mut fds = [PollFd::new(fd, PollFlags::POLLIN)];
+        match poll(&mut fds, -1) {
+            Ok(_) => (),
+            Err(nix::Error::Sys(Errno::EINTR)) => continue,
+            Err(e) => return Err(e.into()),
+        }
+
+        match unistd::read(fd, &mut buf) {
+            Ok(0) => return Ok(None),
+            Ok(n) => {
+                buf.truncate(n);
+                return Ok(Some(buf));
+            }
+            Err(nix::Error::Sys(Errno::EAGAIN)) | Err(nix::Error::Sys(Errno::EINTR)) => continue,
+            // a terminal whose other side is closed
+            Err(nix::Error::Sys(Errno::EIO)) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+// read_fd returns the fd of the stdout or stderr of a process, which is
+// its terminal if it has one.
+fn read_fd(p: &Process, eid: &str, stdout: bool) -> Result<RawFd> {
+    let fd = if p.term_master.is_some() {
+        p.term_master
+    } else if stdout {
+        p.parent_stdout
+    } else {
+        p.parent_stderr
+    };
+
+    match fd {
+        Some(fd) => Ok(fd),
+        None => Err(ErrorKind::FailedPrecondition(format!(
+            "{} of process {} is closed",
+            if stdout { "stdout" } else { "stderr" },
+            eid
+        ))
+        .into()),
+    }
+}
+
+fn write_fd(p: &Process, eid: &str) -> Result<RawFd> {
+    // use ptmx io
+    let fd = if p.term_master.is_some() {
+        p.term_master
+    } else {
+        // use piped io
+        p.parent_stdin
     };
 
+    match fd {
+        Some(fd) => Ok(fd),
+        None => {
+            Err(ErrorKind::FailedPrecondition(format!("stdin of process {} is closed", eid)).into())
+        }
+    }
+}
+
+// find_process and get_container lock the sandbox only for the lookup,
+// they must not be called with the sandbox already locked.
+fn find_process(
+    sandbox: &Arc<Mutex<Sandbox>>,
+    cid: &str,
+    eid: &str,
+    init: bool,
+) -> Result<Arc<Mutex<Process>>> {
+    let ctr = get_container(sandbox, cid)?;
+    let ctr = ctr.lock().unwrap();
+
     if init && eid == "" {
-        let p = match ctr.processes.get_mut(&ctr.init_process_pid) {
-            Some(v) => v,
+        let p = match ctr.processes.get(&ctr.init_process_pid) {
+            Some(v) => v.clone(),
             None => {
-                return Err(ErrorKind::ErrorCode(String::from("cannot find init process!")).into())
+                return Err(
+                    ErrorKind::NotFound(format!("init process of container {}", cid)).into(),
+                )
             }
         };
 
@@ -1555,13 +2583,128 @@ fn find_process<'a>(
 
     let p = match ctr.get_process(eid) {
         Ok(v) => v,
-        Err(_) => return Err(ErrorKind::ErrorCode("Invalid exec id".to_string()).into()),
+        Err(_) => return Err(ErrorKind::NotFound(format!("exec id {}", eid)).into()),
     };
 
     Ok(p)
 }
 
-pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16) -> Server {
+fn get_container(sandbox: &Arc<Mutex<Sandbox>>, cid: &str) -> Result<Arc<Mutex<LinuxContainer>>> {
+    match sandbox.lock().unwrap().get_container(cid) {
+        Some(v) => Ok(v),
+        None => Err(ErrorKind::NotFound(format!("container {}", cid)).into()),
+    }
+}
+
+// rpc_status maps an error to the closest gRPC status code, the status
+// message carries the whole error chain.
+fn rpc_status(e: &Error) -> RpcStatus {
+    let msg = e
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<String>>()
+        .join(": ");
+
+    RpcStatus::new(rpc_status_code(e), Some(msg))
+}
+
+fn rpc_status_code(e: &Error) -> RpcStatusCode {
+    let code = match e.kind() {
+        ErrorKind::NotFound(_) => RpcStatusCode::NotFound,
+        ErrorKind::InvalidArgument(_) => RpcStatusCode::InvalidArgument,
+        ErrorKind::AlreadyExists(_) => RpcStatusCode::AlreadyExists,
+        ErrorKind::FailedPrecondition(_) => RpcStatusCode::FailedPrecondition,
+        ErrorKind::DeadlineExceeded(_) => RpcStatusCode::DeadlineExceeded,
+        ErrorKind::Unimplemented(_) => RpcStatusCode::Unimplemented,
+        ErrorKind::PermissionDenied(_) => RpcStatusCode::PermissionDenied,
+        ErrorKind::Nix(nix::Error::Sys(errno)) => errno_to_rpc_status_code(*errno),
+        ErrorKind::Nix(nix::Error::InvalidPath) | ErrorKind::Nix(nix::Error::InvalidUtf8) => {
+            RpcStatusCode::InvalidArgument
+        }
+        ErrorKind::Io(ioe) => io_error_to_rpc_status_code(ioe),
+        ErrorKind::Ffi(_)
+        | ErrorKind::Serde(_)
+        | ErrorKind::UTF8(_)
+        | ErrorKind::Parse(_)
+        | ErrorKind::Scanfmt(_)
+        | ErrorKind::Ip(_) => RpcStatusCode::InvalidArgument,
+        _ => RpcStatusCode::Internal,
+    };
+
+    if code != RpcStatusCode::Internal {
+        return code;
+    }
+
+    // errors wrapped with chain_err() keep the original error around
+    match e.1.next_error.as_ref() {
+        Some(next) => {
+            if let Some(ne) = next.downcast_ref::<Error>() {
+                rpc_status_code(ne)
+            } else if let Some(nix::Error::Sys(errno)) = next.downcast_ref::<nix::Error>() {
+                errno_to_rpc_status_code(*errno)
+            } else if let Some(ioe) = next.downcast_ref::<std::io::Error>() {
+                io_error_to_rpc_status_code(ioe)
+            } else {
+                RpcStatusCode::Internal
+            }
+        }
+        None => RpcStatusCode::Internal,
+    }
+}
+
+fn errno_to_rpc_status_code(errno: Errno) -> RpcStatusCode {
+    match errno {
+        Errno::ENOENT | Errno::ESRCH | Errno::ENODEV | Errno::ENXIO => RpcStatusCode::NotFound,
+        Errno::EINVAL | Errno::E2BIG | Errno::ENAMETOOLONG => RpcStatusCode::InvalidArgument,
+        Errno::EEXIST => RpcStatusCode::AlreadyExists,
+        Errno::EPERM | Errno::EACCES => RpcStatusCode::PermissionDenied,
+        Errno::ETIME | Errno::ETIMEDOUT => RpcStatusCode::DeadlineExceeded,
+        Errno::EBUSY | Errno::ENOTEMPTY | Errno::ENOTDIR | Errno::EISDIR => {
+            RpcStatusCode::FailedPrecondition
+        }
+        Errno::ENOSPC | Errno::ENOMEM | Errno::EMFILE | Errno::ENFILE => {
+            RpcStatusCode::ResourceExhausted
+        }
+        Errno::ENOSYS | Errno::EOPNOTSUPP => RpcStatusCode::Unimplemented,
+        _ => RpcStatusCode::Internal,
+    }
+}
+
+fn io_error_to_rpc_status_code(e: &std::io::Error) -> RpcStatusCode {
+    if let Some(errno) = e.raw_os_error() {
+        return errno_to_rpc_status_code(Errno::from_i32(errno));
+    }
+
+    match e.kind() {
+        std::io::ErrorKind::NotFound => RpcStatusCode::NotFound,
+        std::io::ErrorKind::PermissionDenied => RpcStatusCode::PermissionDenied,
+        std::io::ErrorKind::AlreadyExists => RpcStatusCode::AlreadyExists,
+        std::io::ErrorKind::InvalidInput | std::io::ErrorKind::InvalidData => {
+            RpcStatusCode::InvalidArgument
+        }
+        std::io::ErrorKind::TimedOut => RpcStatusCode::DeadlineExceeded,
+        _ => RpcStatusCode::Internal,
+    }
+}
+
+// start starts the gRPC server on addr, only serving the RPCs allowed
+// by the policy in policy_file, or by the default one if it exists.
+pub fn start(
+    sandbox: Arc<Mutex<Sandbox>>,
+    addr: &ServerAddr,
+    policy_file: Option<&str>,
+) -> Result<Server> {
+    let policy = Policy::load(policy_file, AGENT_RPCS)?;
+
+    let (host, port) = match addr {
+        ServerAddr::Vsock { cid, port } => (format!("vsock://{}", cid), *port),
+        ServerAddr::Unix(path) => {
+            // remove the socket left over by a previous run
+            let _ = fs::remove_file(path);
+            (format!("unix:{}", path), UNIX_SOCKET_PORT)
+        }
+    };
+
     let env = Arc::new(
         EnvBuilder::new()
             .cq_count(1)
@@ -1570,11 +2713,27 @@ pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16)
             .wait_thread_count_max(10)
             .build(),
     );
-    let worker = agentService {
+    let oom_waiters = Arc::new(Mutex::new(OomWaiters::new()));
+    dispatch_oom_events(&sandbox, oom_waiters.clone());
+
+    let mut worker = agentService {
         sandbox: sandbox,
-        test: 1,
+        stream_check: None,
+        oom_waiters,
+    };
+    let service = match policy {
+        Some(p) => {
+            info!(sl!(), "enforcing RPC policy");
+            let policy = Arc::new(p);
+            let p = policy.clone();
+            worker.stream_check = Some(Arc::new(move |rpc, req| check_policy(&p, rpc, Some(req))));
+            protocols::agent_grpc::create_agent_service(policyService {
+                inner: worker,
+                policy,
+            })
+        }
+        None => protocols::agent_grpc::create_agent_service(worker),
     };
-    let service = protocols::agent_grpc::create_agent_service(worker);
     let hservice = protocols::health_grpc::create_health(healthService);
     let mut server = ServerBuilder::new(env)
         .register_service(service)
@@ -1582,15 +2741,22 @@ pub fn start<S: Into<String>>(sandbox: Arc<Mutex<Sandbox>>, host: S, port: u16)
         .requests_slot_per_cq(1024)
         .bind(host, port)
         .build()
-        .unwrap();
+        .map_err(|e| Error::from(ErrorKind::ErrorCode(format!("grpc server: {:?}", e))))?;
+
+    // grpc binds a unix socket at "<path>:<port>", which is moved to
+    // path before any client can look for it
+    if let ServerAddr::Unix(path) = addr {
+        let bound = format!("{}:{}", path, UNIX_SOCKET_PORT);
+        fs::rename(&bound, path).chain_err(|| format!("cannot move {} to {}", bound, path))?;
+    }
     server.start();
+
     info!(sl!(), "gRPC server started");
     for &(ref host, port) in server.bind_addrs() {
-        info!(sl!(), "listening"; "host" => host,
-        "port" => port);
+        info!(sl!(), "listening"; "host" => host, "port" => port);
     }
 
-    server
+    Ok(server)
 }
 
 // This function updates the container namespaces configuration based on the
@@ -1608,7 +2774,7 @@ fn update_container_namespaces(sandbox: &Sandbox, spec: &mut Spec) -> Result<()>
     let linux = match spec.Linux.as_mut() {
         None => {
             return Err(
-                ErrorKind::ErrorCode("Spec didn't container linux field".to_string()).into(),
+                ErrorKind::InvalidArgument("Spec didn't container linux field".to_string()).into(),
             )
         }
         Some(l) => l,
@@ -1620,6 +2786,10 @@ fn update_container_namespaces(sandbox: &Sandbox, spec: &mut Spec) -> Result<()>
     for namespace in namespaces.iter_mut() {
         if namespace.Type == NSTYPEPID {
             pidNs = true;
+            // join the namespace of the sandbox pause process
+            if sandbox.sandbox_pid_ns {
+                namespace.Path = sandbox.shared_pidns.path.clone();
+            }
             continue;
         }
         if namespace.Type == NSTYPEIPC {
@@ -1632,15 +2802,243 @@ fn update_container_namespaces(sandbox: &Sandbox, spec: &mut Spec) -> Result<()>
         }
     }
 
-    if !pidNs && !sandbox.sandbox_pid_ns {
+    if !pidNs {
         let mut pid_ns = LinuxNamespace::new();
         pid_ns.set_Type(NSTYPEPID.to_string());
+        if sandbox.sandbox_pid_ns {
+            pid_ns.Path = sandbox.shared_pidns.path.clone();
+        }
         linux.Namespaces.push(pid_ns);
     }
 
     Ok(())
 }
 
+// update_process_user resolves the user given by names against the
+// passwd and group files of the container, and sets HOME accordingly.
+fn update_process_user(
+    ctr: &LinuxContainer,
+    user: &protocols::agent::StringUser,
+    ocip: &mut protocols::oci::Process,
+) -> Result<()> {
+    // the files seen by the container, which may be bind mounted over
+    // the ones of its rootfs
+    let proc_root = format!("/proc/{}/root", ctr.init_process_pid);
+    let root = if ctr.init_process_pid > 0 && Path::new(&proc_root).exists() {
+        proc_root
+    } else {
+        match ctr.config.spec.as_ref().and_then(|s| s.Root.as_ref()) {
+            Some(r) => r.Path.clone(),
+            None => {
+                return Err(
+                    ErrorKind::FailedPrecondition(format!("no rootfs for {}", ctr.id)).into(),
+                )
+            }
+        }
+    };
+
+    let ocip_user = ocip.mut_User();
+    let defaults = user::ExecUser {
+        uid: ocip_user.UID,
+        gid: ocip_user.GID,
+        additional_gids: ocip_user.AdditionalGids.clone(),
+        home: String::new(),
+    };
+
+    let exec_user = user::resolve_user(root.as_str(), user, &defaults)
+        .chain_err(|| format!("cannot resolve the user of {}", ctr.id))?;
+
+    ocip_user.UID = exec_user.uid;
+    ocip_user.GID = exec_user.gid;
+    ocip_user.AdditionalGids = exec_user.additional_gids;
+
+    if !ocip.Env.iter().any(|e| e.starts_with("HOME=")) {
+        ocip.Env.push(format!("HOME={}", exec_user.home));
+    }
+
+    Ok(())
+}
+
+// update_container_dns bind mounts the sandbox resolv.conf, once written
+// by CreateSandbox, in the container.
+fn update_container_dns(sandbox: &Sandbox, spec: &mut Spec) {
+    if sandbox.running {
+        bind_sandbox_file(spec, SANDBOX_RESOLV_CONF, RESOLV_CONF_PATH);
+    }
+}
+
+// update_container_hostname bind mounts the sandbox hostname and hosts
+// files, once written by CreateSandbox, in the container.
+fn update_container_hostname(sandbox: &Sandbox, spec: &mut Spec) {
+    if sandbox.running {
+        bind_sandbox_file(spec, SANDBOX_HOSTNAME, HOSTNAME_PATH);
+        bind_sandbox_file(spec, SANDBOX_HOSTS, HOSTS_PATH);
+    }
+}
+
+// bind_sandbox_file bind mounts source at destination in the container,
+// unless the container mounts its own file there.
+fn bind_sandbox_file(spec: &mut Spec, source: &str, destination: &str) {
+    if spec.Mounts.iter().any(|m| m.destination == destination) {
+        return;
+    }
+
+    let mut m = protocols::oci::Mount::new();
+    m.destination = destination.to_string();
+    m.source = source.to_string();
+    m.field_type = "bind".to_string();
+    // the file is shared by all the containers
+    m.options = RepeatedField::from_vec(vec!["rbind".to_string(), "ro".to_string()]);
+
+    spec.Mounts.push(m);
+}
+
+fn criu_opts(options: &protocols::agent::CriuOptions) -> Result<CriuOpts> {
+    if options.images_directory.is_empty() {
+        return Err(ErrorKind::InvalidArgument("no images directory".to_string()).into());
+    }
+
+    Ok(CriuOpts {
+        images_directory: options.images_directory.clone(),
+        work_directory: options.work_directory.clone(),
+        leave_running: options.leave_running,
+        tcp_established: options.tcp_established,
+        file_locks: options.file_locks,
+    })
+}
+
+// save_sandbox persists the sandbox, for a restarted agent to pick it up.
+// Failing to do so doesn't fail the request, which was served.
+pub fn save_sandbox(s: &Sandbox) {
+    if let Err(e) = s.save(SANDBOX_STATE) {
+        warn!(sl!(), "cannot save the sandbox state"; "error" => format!("{}", e));
+    }
+}
+
+fn save_container(ctr: &LinuxContainer) {
+    if let Err(e) = ctr.save() {
+        warn!(sl!(), "cannot save the container state";
+              "container-id" => ctr.id.as_str(), "error" => format!("{}", e));
+    }
+}
+
+// supervise_seccomp hands the seccomp listener of a new process, if any,
+// over to a supervisor running the handlers of the container. Without a
+// supervisor, the notified system calls fail with ENOSYS.
+fn supervise_seccomp(ctr: &LinuxContainer, eid: &str) {
+    let listener = match ctr.get_process(eid) {
+        Ok(p) => p.lock().unwrap().seccomp_listener.take(),
+        Err(_) => None,
+    };
+    let listener = match listener {
+        Some(fd) => fd,
+        None => return,
+    };
+
+    let annotations = match ctr.config.spec.as_ref() {
+        Some(spec) => spec.Annotations.clone(),
+        None => HashMap::new(),
+    };
+    match seccomp_notify::handlers(&annotations) {
+        Ok(handlers) => {
+            let logger =
+                sl!().new(o!("container-id" => ctr.id.clone(), "exec-id" => eid.to_string()));
+            seccomp_notify::supervise(&logger, &annotations, handlers, listener);
+        }
+        Err(e) => {
+            warn!(sl!(), "cannot supervise seccomp notifications: {}", e);
+            let _ = unistd::close(listener);
+        }
+    }
+}
+
+// restore picks up the sandbox of a previous agent, if any.
+pub fn restore(sandbox: &Arc<Mutex<Sandbox>>) -> Result<bool> {
+    sandbox
+        .lock()
+        .unwrap()
+        .restore(SANDBOX_STATE, CONTAINER_BASE)
+}
+
+// container_pids returns the tasks of the container cgroup, which include
+// the processes forked by the container processes.
+fn container_pids(ctr: &LinuxContainer) -> Result<Vec<pid_t>> {
+    match ctr.cgroup_manager.as_ref() {
+        Some(cgm) => cgm.get_all_pids(),
+        None => Ok(ctr.processes.keys().cloned().collect()),
+    }
+}
+
+// stop_container sends the stop signal to the tasks of the container
+// cgroup, unless forced or without timeout, and waits for the cgroup to be
+// empty, for at most timeout. The container is then destroyed, killing
+// whatever is still running in the cgroup, and whether some processes had
+// to be killed is returned.
+// The container lock isn't held while waiting, the reaper needs it.
+fn stop_container(
+    ctr: &Arc<Mutex<LinuxContainer>>,
+    sig: Signal,
+    timeout: Duration,
+    force: bool,
+) -> Result<bool> {
+    if !force && timeout > Duration::from_secs(0) {
+        let signaled = {
+            let mut ctr = ctr.lock().unwrap();
+
+            // the init of a pid namespace ignores the signals it doesn't
+            // handle, waiting for it to act on the stop signal is useless
+            let handled = is_signal_handled(ctr.init_process_pid, sig as u32);
+            if handled {
+                // frozen tasks won't act on the stop signal
+                if ctr.is_paused() {
+                    ctr.resume()?;
+                }
+
+                for pid in container_pids(&ctr)? {
+                    match signal::kill(Pid::from_raw(pid), Some(sig)) {
+                        Ok(()) | Err(nix::Error::Sys(Errno::ESRCH)) => (),
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+            }
+
+            handled
+        };
+
+        if signaled {
+            wait_container_exit(ctr, timeout)?;
+        }
+    }
+
+    let mut ctr = ctr.lock().unwrap();
+
+    // whatever is still running in the cgroup is killed
+    let forced = !container_pids(&ctr)?.is_empty();
+    if forced {
+        info!(sl!(), "killing the container processes"; "container-id" => ctr.id.as_str());
+    }
+
+    ctr.destroy()?;
+
+    Ok(forced)
+}
+
+// wait_container_exit waits for the container cgroup to be empty, for at
+// most timeout.
+fn wait_container_exit(ctr: &Arc<Mutex<LinuxContainer>>, timeout: Duration) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+
+    while Instant::now() < deadline {
+        if container_pids(&ctr.lock().unwrap())?.is_empty() {
+            break;
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    Ok(())
+}
+
 // Check is the container process installed the
 // handler for specific signal.
 fn is_signal_handled(pid: pid_t, signum: u32) -> bool {
@@ -1673,7 +3071,7 @@ fn is_signal_handled(pid: pid_t, signum: u32) -> bool {
                 warn!(sl!(), "parse the SigCgt field failed\n");
                 return false;
             }
-            let sig_cgt_str = mask_vec[1];
+            let sig_cgt_str = mask_vec[1].trim();
             let sig_cgt_mask = match u64::from_str_radix(sig_cgt_str, 16) {
                 Ok(h) => h,
                 Err(_) => {
@@ -1713,7 +3111,11 @@ fn do_copy_file(req: &CopyFileRequest) -> Result<()> {
     let path = PathBuf::from(req.path.as_str());
 
     if !path.starts_with(CONTAINER_BASE) {
-        return Err(nix::Error::Sys(Errno::EINVAL).into());
+        return Err(ErrorKind::InvalidArgument(format!(
+            "{:?} is not under {}",
+            path, CONTAINER_BASE
+        ))
+        .into());
     }
 
     let parent = path.parent();
@@ -1764,9 +3166,9 @@ fn do_copy_file(req: &CopyFileRequest) -> Result<()> {
     Ok(())
 }
 
-fn setup_bundle(gspec: &Spec) -> Result<PathBuf> {
+fn setup_bundle(gspec: &Spec) -> Result<()> {
     if gspec.Root.is_none() {
-        return Err(nix::Error::Sys(Errno::EINVAL).into());
+        return Err(ErrorKind::InvalidArgument("no root in the oci spec".to_string()).into());
     }
     let root = gspec.Root.as_ref().unwrap().Path.as_str();
 
@@ -1783,8 +3185,326 @@ fn setup_bundle(gspec: &Spec) -> Result<PathBuf> {
     );
     let _ = oci.save(config.as_str());
 
-    let olddir = unistd::getcwd().chain_err(|| "cannot getcwd")?;
-    unistd::chdir(bundle_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nix::fcntl::OFlag;
+
+    #[test]
+    fn test_rpc_status_code() {
+        let e: Error = ErrorKind::NotFound("container foo".to_string()).into();
+        assert_eq!(rpc_status_code(&e), RpcStatusCode::NotFound);
+
+        let e: Error = ErrorKind::Nix(nix::Error::Sys(Errno::EEXIST)).into();
+        assert_eq!(rpc_status_code(&e), RpcStatusCode::AlreadyExists);
+
+        let e: Error = ErrorKind::PermissionDenied("ExecProcess".to_string()).into();
+        assert_eq!(rpc_status_code(&e), RpcStatusCode::PermissionDenied);
+
+        let e: Error = ErrorKind::ErrorCode("unknown".to_string()).into();
+        assert_eq!(rpc_status_code(&e), RpcStatusCode::Internal);
+
+        // the code of a chained error comes from the error it wraps
+        let r: Result<()> = Err(ErrorKind::Nix(nix::Error::Sys(Errno::ETIMEDOUT)).into());
+        let e = r.chain_err(|| "timeout waiting").unwrap_err();
+        assert_eq!(rpc_status_code(&e), RpcStatusCode::DeadlineExceeded);
+
+        let r: Result<()> = Err(std::io::Error::from_raw_os_error(libc::EACCES).into());
+        let e = r.chain_err(|| "open file").unwrap_err();
+        assert_eq!(rpc_status_code(&e), RpcStatusCode::PermissionDenied);
+    }
+
+    #[test]
+    fn test_update_container_dns() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let mut sandbox = Sandbox::new(&logger).unwrap();
+        let mut spec = Spec::new();
+
+        // no sandbox resolv.conf yet
+        update_container_dns(&sandbox, &mut spec);
+        assert!(spec.Mounts.is_empty());
+
+        // even without DNS entries, which may be updated later on
+        sandbox.running = true;
+        update_container_dns(&sandbox, &mut spec);
+        assert_eq!(spec.Mounts.len(), 1);
+        assert_eq!(spec.Mounts[0].destination, RESOLV_CONF_PATH);
+        assert_eq!(spec.Mounts[0].source, SANDBOX_RESOLV_CONF);
+
+        // the container already has one
+        update_container_dns(&sandbox, &mut spec);
+        assert_eq!(spec.Mounts.len(), 1);
+    }
+
+    // Tasks is the cgroup of a container, which only has the tasks still
+    // running, the zombies being gone
+    #[derive(Debug)]
+    struct Tasks {
+        pids: Vec<pid_t>,
+    }
+
+    impl rustjail::cgroups::Manager for Tasks {
+        fn get_all_pids(&self) -> Result<Vec<pid_t>> {
+            Ok(self
+                .pids
+                .iter()
+                .cloned()
+                .filter(|pid| {
+                    fs::read_to_string(format!("/proc/{}/stat", pid))
+                        .map_or(false, |s| !s.contains(") Z "))
+                })
+                .collect())
+        }
+    }
+
+    fn new_container(base: &str, child: &std::process::Child) -> Arc<Mutex<LinuxContainer>> {
+        let rootfs = format!("{}/rootfs", base);
+        fs::create_dir_all(&rootfs).unwrap();
+        let mut root = protocols::oci::Root::new();
+        root.Path = rootfs;
+        let mut spec = Spec::new();
+        spec.Root = SingularPtrField::some(root);
+        spec.Linux = SingularPtrField::some(protocols::oci::Linux::new());
+
+        let opts = CreateOpts {
+            cgroup_name: "".to_string(),
+            use_systemd_cgroup: false,
+            no_pivot_root: false,
+            no_new_keyring: false,
+            spec: Some(spec),
+            rootless_euid: false,
+            rootless_cgroup: false,
+        };
+        let logger = slog::Logger::root(slog::Discard, o!());
+
+        let mut ctr = LinuxContainer::new("foo", base, opts, &logger).unwrap();
+        ctr.init_process_pid = child.id() as pid_t;
+        ctr.cgroup_manager = Some(Box::new(Tasks {
+            pids: vec![ctr.init_process_pid],
+        }));
+        ctr.status = Some("running".to_string());
 
-    Ok(olddir)
+        Arc::new(Mutex::new(ctr))
+    }
+
+    #[test]
+    fn test_stop_container() {
+        use std::os::unix::process::ExitStatusExt;
+        use std::process::Command;
+
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().to_str().unwrap();
+
+        let handling = |action: &str| {
+            let script = format!("trap '{}' TERM; while true; do sleep 0.05; done", action);
+            let child = Command::new("sh").args(&["-c", &script]).spawn().unwrap();
+            thread::sleep(Duration::from_millis(100));
+            child
+        };
+        let timeout = Duration::from_secs(10);
+
+        // the container exits on the stop signal
+        let mut child = handling("exit 3");
+        let ctr = new_container(base, &child);
+        let forced = stop_container(&ctr, Signal::SIGTERM, timeout, false).unwrap();
+        assert!(!forced);
+        assert_eq!(child.wait().unwrap().code(), Some(3));
+
+        // the container not exiting on it is killed once the timeout expired
+        let mut child = handling("true");
+        let ctr = new_container(base, &child);
+        let start = Instant::now();
+        let short = Duration::from_millis(200);
+        let forced = stop_container(&ctr, Signal::SIGTERM, short, false).unwrap();
+        assert!(forced);
+        assert!(start.elapsed() >= short);
+        assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+
+        // or right away when it doesn't handle it
+        let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+        let ctr = new_container(base, &child);
+        let start = Instant::now();
+        let forced = stop_container(&ctr, Signal::SIGTERM, timeout, false).unwrap();
+        assert!(forced);
+        assert!(start.elapsed() < timeout);
+        assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+
+        // when forced
+        let mut child = handling("exit 3");
+        let ctr = new_container(base, &child);
+        let forced = stop_container(&ctr, Signal::SIGTERM, timeout, true).unwrap();
+        assert!(forced);
+        assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+
+        // or without timeout
+        let mut child = handling("exit 3");
+        let ctr = new_container(base, &child);
+        let forced = stop_container(&ctr, Signal::SIGTERM, Duration::from_secs(0), false).unwrap();
+        assert!(forced);
+        assert_eq!(child.wait().unwrap().signal(), Some(libc::SIGKILL));
+    }
+
+    #[test]
+    fn test_send_oom_event() {
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        send_oom_event(&tx, "foo".to_string(), |resp| {
+            assert_eq!(resp.container_id, "foo");
+            Ok(())
+        })
+        .unwrap();
+        assert!(rx.try_recv().is_err());
+
+        // the event isn't lost when the client has gone away
+        let res = send_oom_event(&tx, "bar".to_string(), |_| {
+            Err(grpcio::Error::RemoteStopped)
+        });
+        assert!(res.is_err());
+        assert_eq!(rx.try_recv().unwrap(), "bar");
+    }
+
+    #[test]
+    fn test_start_unix() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = format!("{}/agent.sock", dir.path().to_str().unwrap());
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let sandbox = Arc::new(Mutex::new(Sandbox::new(&logger).unwrap()));
+
+        let _server = start(sandbox, &ServerAddr::Unix(path.clone()), None).unwrap();
+        assert!(Path::new(&path).exists());
+
+        let env = Arc::new(EnvBuilder::new().cq_count(1).build());
+        let channel = grpcio::ChannelBuilder::new(env).connect(&format!("unix:{}", path));
+        let client = protocols::health_grpc::HealthClient::new(channel);
+        let resp = client
+            .check(&protocols::health::CheckRequest::new())
+            .unwrap();
+        assert_eq!(resp.status, HealthCheckResponse_ServingStatus::SERVING);
+    }
+
+    #[test]
+    fn test_oom_waiters() {
+        let mut waiters = OomWaiters::new();
+
+        assert_eq!(waiters.wait(1), None);
+        assert_eq!(waiters.wait(2), None);
+        assert_eq!(
+            waiters.event("foo".to_string()),
+            Some((1, "foo".to_string()))
+        );
+        assert_eq!(
+            waiters.event("bar".to_string()),
+            Some((2, "bar".to_string()))
+        );
+
+        // the events wait for the next calls
+        assert_eq!(waiters.event("foo".to_string()), None);
+        assert_eq!(waiters.event("bar".to_string()), None);
+        assert_eq!(waiters.wait(3), Some((3, "foo".to_string())));
+        assert_eq!(waiters.wait(4), Some((4, "bar".to_string())));
+        assert_eq!(waiters.wait(5), None);
+    }
+
+    #[test]
+    fn test_read_output() {
+        let (r, w) = unistd::pipe2(OFlag::O_NONBLOCK).unwrap();
+
+        // the reader waits for the output rather than spinning on EAGAIN
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            unistd::write(w, b"foo").unwrap();
+            unistd::close(w).unwrap();
+        });
+        assert_eq!(read_output(r, 2).unwrap(), Some(b"fo".to_vec()));
+        writer.join().unwrap();
+        assert_eq!(read_output(r, 16).unwrap(), Some(b"o".to_vec()));
+        assert_eq!(read_output(r, 16).unwrap(), None);
+        unistd::close(r).unwrap();
+
+        // the other errors are reported
+        let (r, w) = unistd::pipe().unwrap();
+        unistd::close(r).unwrap();
+        assert!(read_output(w, 16).is_err());
+        unistd::close(w).unwrap();
+    }
+
+    #[test]
+    fn test_update_container_namespaces() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let mut sandbox = Sandbox::new(&logger).unwrap();
+        let mut spec = Spec::new();
+        spec.Linux = Some(protocols::oci::Linux::new()).into();
+
+        // each container gets its own pid namespace
+        update_container_namespaces(&sandbox, &mut spec).unwrap();
+        let namespaces = &spec.Linux.as_ref().unwrap().Namespaces;
+        assert_eq!(namespaces.len(), 1);
+        assert_eq!(namespaces[0].Type, NSTYPEPID);
+        assert!(namespaces[0].Path.is_empty());
+
+        // or joins the one of the sandbox
+        sandbox.sandbox_pid_ns = true;
+        sandbox.shared_pidns.path = "/proc/42/ns/pid".to_string();
+        update_container_namespaces(&sandbox, &mut spec).unwrap();
+        let namespaces = &spec.Linux.as_ref().unwrap().Namespaces;
+        assert_eq!(namespaces.len(), 1);
+        assert_eq!(namespaces[0].Path, "/proc/42/ns/pid");
+
+        let mut spec = Spec::new();
+        spec.Linux = Some(protocols::oci::Linux::new()).into();
+        update_container_namespaces(&sandbox, &mut spec).unwrap();
+        let namespaces = &spec.Linux.as_ref().unwrap().Namespaces;
+        assert_eq!(namespaces.len(), 1);
+        assert_eq!(namespaces[0].Path, "/proc/42/ns/pid");
+
+        let e = update_container_namespaces(&sandbox, &mut Spec::new()).unwrap_err();
+        assert_eq!(rpc_status_code(&e), RpcStatusCode::InvalidArgument);
+    }
+
+    #[test]
+    fn test_update_container_hostname() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let mut sandbox = Sandbox::new(&logger).unwrap();
+        let mut spec = Spec::new();
+
+        update_container_hostname(&sandbox, &mut spec);
+        assert!(spec.Mounts.is_empty());
+
+        let mut m = protocols::oci::Mount::new();
+        m.destination = HOSTS_PATH.to_string();
+        m.source = "/foo/hosts".to_string();
+        spec.Mounts.push(m);
+
+        // even without a hostname, which may be set later on
+        sandbox.running = true;
+        update_container_hostname(&sandbox, &mut spec);
+
+        let mounts: Vec<(&str, &str)> = spec
+            .Mounts
+            .iter()
+            .map(|m| (m.destination.as_str(), m.source.as_str()))
+            .collect();
+        assert_eq!(
+            mounts,
+            vec![
+                (HOSTS_PATH, "/foo/hosts"),
+                (HOSTNAME_PATH, SANDBOX_HOSTNAME)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rpc_status_message() {
+        let r: Result<()> = Err(ErrorKind::InvalidArgument("bad route".to_string()).into());
+        let e = r.chain_err(|| "update routes").unwrap_err();
+        let status = rpc_status(&e);
+
+        assert_eq!(status.status, RpcStatusCode::InvalidArgument);
+        let msg = status.details.unwrap();
+        assert!(msg.starts_with("update routes: "));
+        assert!(msg.contains("bad route"));
+    }
 }
@@ -14,7 +14,10 @@ extern crate prctl;
 extern crate protocols;
 extern crate regex;
 extern crate rustjail;
+extern crate serde;
 extern crate serde_json;
+#[macro_use]
+extern crate serde_derive;
 extern crate signal_hook;
 #[macro_use]
 extern crate scan_fmt;
@@ -30,6 +33,7 @@ extern crate netlink;
 
 use futures::*;
 use nix::fcntl::{self, OFlag};
+use nix::sys::reboot::{self, RebootMode};
 use nix::sys::socket::{self, AddressFamily, SockAddr, SockFlag, SockType};
 use nix::sys::wait::{self, WaitStatus};
 use nix::unistd;
@@ -44,6 +48,7 @@ use std::os::unix::io::AsRawFd;
 use std::path::Path;
 use std::sync::mpsc::{self, Sender};
 use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 use std::{io, thread};
 use unistd::Pid;
 
@@ -53,11 +58,16 @@ mod linux_abi;
 mod mount;
 mod namespace;
 mod network;
+mod policy;
+mod ps;
 pub mod random;
 mod sandbox;
+mod seccomp_notify;
 #[cfg(test)]
 mod test_utils;
+mod tracer;
 mod uevent;
+mod user;
 mod version;
 
 use mount::{cgroups_mount, general_mount};
@@ -68,10 +78,10 @@ use uevent::watch_uevents;
 mod grpc;
 
 const NAME: &str = "kata-agent";
-const VSOCK_ADDR: &str = "vsock://-1";
-const VSOCK_PORT: u16 = 1024;
 const KERNEL_CMDLINE_FILE: &str = "/proc/cmdline";
 const CONSOLE_PATH: &str = "/dev/console";
+// milliseconds
+const LOG_COPY_TIMEOUT: u64 = 1000;
 
 lazy_static! {
     static ref GLOBAL_DEVICE_WATCHER: Arc<Mutex<HashMap<String, Sender<String>>>> =
@@ -114,7 +124,15 @@ fn main() -> Result<()> {
 
     let agentConfig = AGENT_CONFIG.clone();
 
-    if unistd::getpid() == Pid::from_raw(1) {
+    // the standalone mode must be known before doing anything to the
+    // system
+    let standalone = {
+        let mut config = agentConfig.write().unwrap();
+        config.parse_args(&args)?;
+        config.standalone
+    };
+
+    if unistd::getpid() == Pid::from_raw(1) && !standalone {
         // Init a temporary logger used by init agent as init process
         // since before do the base mount, it wouldn't access "/proc/cmdline"
         // to get the customzied debug level.
@@ -125,9 +143,13 @@ fn main() -> Result<()> {
     // once parsed cmdline and set the config, release the write lock
     // as soon as possible in case other thread would get read lock on
     // it.
-    {
+    // When standalone, the kernel command line is the one of the host,
+    // which isn't meant for the agent.
+    if !standalone {
         let mut config = agentConfig.write().unwrap();
         config.parse_cmdline(KERNEL_CMDLINE_FILE)?;
+        // options given to the agent binary take precedence
+        config.parse_args(&args)?;
     }
 
     let config = agentConfig.read().unwrap();
@@ -162,7 +184,11 @@ fn main() -> Result<()> {
 
     announce(&logger);
 
-    if args.len() == 2 && args[1] == "--version" {
+    if !config.ignored_args.is_empty() {
+        warn!(logger, "ignoring unknown arguments"; "args" => format!("{:?}", config.ignored_args));
+    }
+
+    if config.show_version {
         // force logger to flush
         drop(logger);
 
@@ -173,6 +199,13 @@ fn main() -> Result<()> {
     // which is required to satisfy the the lifetime constraints of the auto-generated gRPC code.
     let _guard = slog_scope::set_global_logger(logger.new(o!("subsystem" => "grpc")));
 
+    if config.tracing {
+        // Report error, but don't fail
+        if let Err(e) = tracer::start_tracing(config.trace_vport as u32) {
+            warn!(logger, "failed to start tracing"; "error" => format!("{}", e));
+        }
+    }
+
     let shells = SHELLS.clone();
     let debug_console_vport = config.debug_console_vport as u32;
 
@@ -200,20 +233,27 @@ fn main() -> Result<()> {
 
     let sandbox = Arc::new(Mutex::new(s));
 
+    // pick up the sandbox of a previous agent
+    match grpc::restore(&sandbox) {
+        Ok(true) => info!(logger, "restored the sandbox state"),
+        Ok(false) => {}
+        Err(e) => warn!(logger, "cannot restore the sandbox state"; "error" => format!("{}", e)),
+    }
+
     setup_signal_handler(&logger, sandbox.clone()).unwrap();
-    watch_uevents(sandbox.clone());
+    // devices are only hotplugged into the VM
+    if !standalone {
+        watch_uevents(sandbox.clone());
+    }
 
     let (tx, rx) = mpsc::channel::<i32>();
     sandbox.lock().unwrap().sender = Some(tx);
 
-    //vsock:///dev/vsock, port
-    let mut server = grpc::start(sandbox.clone(), VSOCK_ADDR, VSOCK_PORT);
-
-    /*
-        let _ = fs::remove_file("/tmp/testagent");
-        let _ = fs::remove_dir_all("/run/agent");
-        let mut server = grpc::start(sandbox.clone(), "unix:///tmp/testagent", 1);
-    */
+    let mut server = grpc::start(
+        sandbox.clone(),
+        &config.server_addr,
+        config.policy_file.as_ref().map(|p| p.as_str()),
+    )?;
 
     let handle = thread::spawn(move || {
         // info!("Press ENTER to exit...");
@@ -228,20 +268,58 @@ fn main() -> Result<()> {
     // let _ = rx.wait();
 
     handle.join().unwrap();
+
+    if tracer::is_enabled() {
+        let _ = tracer::stop_tracing();
+    }
+
+    // the reply of DestroySandbox is sent before shutting down
+    let _ = server.shutdown().wait();
+
+    if let ServerAddr::Unix(path) = &config.server_addr {
+        let _ = fs::remove_file(path);
+    }
+
+    // init must not exit, the sandbox is gone so is the guest
+    if unistd::getpid() == Pid::from_raw(1) && !standalone && config.poweroff {
+        info!(logger, "powering off the guest");
+        // the logs left in the pipe are lost with the guest
+        drop(_guard);
+        drop(logger);
+        wait_logs_copied(rfd, LOG_COPY_TIMEOUT);
+
+        unistd::sync();
+        reboot::reboot(RebootMode::RB_POWER_OFF)?;
+    }
+
     let _ = log_handle.join();
 
     if config.debug_console {
         shell_handle.join().unwrap();
     }
 
-    let _ = server.shutdown().wait();
-    let _ = fs::remove_file("/tmp/testagent");
-
     Ok(())
 }
 
 use nix::sys::wait::WaitPidFlag;
 
+// wait_logs_copied gives the log thread, which only ends once every
+// logger is gone, at most timeout milliseconds to copy the logs left in
+// the pipe of rfd.
+fn wait_logs_copied(rfd: RawFd, timeout: u64) {
+    let deadline = Instant::now() + Duration::from_millis(timeout);
+
+    while Instant::now() < deadline {
+        // the logs are first written to the pipe by the async drain
+        thread::sleep(Duration::from_millis(10));
+
+        let mut pending: libc::c_int = 0;
+        if unsafe { libc::ioctl(rfd, libc::FIONREAD, &mut pending) } < 0 || pending == 0 {
+            break;
+        }
+    }
+}
+
 fn setup_signal_handler(logger: &Logger, sandbox: Arc<Mutex<Sandbox>>) -> Result<()> {
     let logger = logger.new(o!("subsystem" => "signals"));
 
@@ -291,20 +369,25 @@ fn setup_signal_handler(logger: &Logger, sandbox: Arc<Mutex<Sandbox>>) -> Result
 
                     let logger = logger.new(o!("child-pid" => child_pid));
 
-                    let mut sandbox = s.lock().unwrap();
-                    let process = sandbox.find_process(raw_pid);
+                    if s.lock().unwrap().pause_process_exited(raw_pid) {
+                        error!(logger, "sandbox pid namespace pause process exited";
+                               "child-status" => format!("{:?}", wait_status));
+                        continue 'inner;
+                    }
+
+                    let process = sandbox::find_process(&s, raw_pid);
                     if process.is_none() {
                         info!(logger, "child exited unexpectedly");
                         continue 'inner;
                     }
 
-                    let mut p = process.unwrap();
+                    let process = process.unwrap();
+                    let mut p = process.lock().unwrap();
 
                     if p.exit_pipe_w.is_none() {
                         error!(logger, "the process's exit_pipe_w isn't set");
                         continue 'inner;
                     }
-                    let pipe_write = p.exit_pipe_w.unwrap();
                     let ret: i32;
 
                     match wait_status {
@@ -317,8 +400,7 @@ fn setup_signal_handler(logger: &Logger, sandbox: Arc<Mutex<Sandbox>>) -> Result
                         }
                     }
 
-                    p.exit_code = ret;
-                    let _ = unistd::close(pipe_write);
+                    p.exited(ret);
                 }
             }
         }
@@ -330,7 +412,14 @@ fn setup_signal_handler(logger: &Logger, sandbox: Arc<Mutex<Sandbox>>) -> Result
 // when this agent has been run as the init process.
 fn init_agent_as_init(logger: &Logger) -> Result<()> {
     general_mount(logger)?;
-    cgroups_mount(logger)?;
+
+    // the kernel command line can only be read once /proc is mounted
+    let unified_cgroup_hierarchy = {
+        let mut config = AGENT_CONFIG.write().unwrap();
+        config.parse_cmdline(KERNEL_CMDLINE_FILE)?;
+        config.unified_cgroup_hierarchy
+    };
+    cgroups_mount(logger, unified_cgroup_hierarchy)?;
 
     fs::remove_file(Path::new("/dev/ptmx"))?;
     unixfs::symlink(Path::new("/dev/pts/ptmx"), Path::new("/dev/ptmx"))?;
@@ -362,7 +451,7 @@ lazy_static! {
 // pub static mut LOG_LEVEL: ;
 // pub static mut TRACE_MODE: ;
 
-use crate::config::agentConfig;
+use crate::config::{agentConfig, ServerAddr};
 use nix::sys::stat::Mode;
 use std::os::unix::io::{FromRawFd, RawFd};
 use std::path::PathBuf;
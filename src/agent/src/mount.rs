@@ -25,6 +25,7 @@ use std::io::{BufRead, BufReader};
 use crate::device::{get_pci_device_name, get_scsi_device_name, online_device};
 use crate::linux_abi::*;
 use crate::protocols::agent::Storage;
+use crate::tracer;
 use crate::Sandbox;
 use slog::Logger;
 
@@ -191,11 +192,11 @@ impl<'a> BareMount<'a> {
         let cstr_fs_type: CString;
 
         if self.source.len() == 0 {
-            return Err(ErrorKind::ErrorCode("need mount source".to_string()).into());
+            return Err(ErrorKind::InvalidArgument("need mount source".to_string()).into());
         }
 
         if self.destination.len() == 0 {
-            return Err(ErrorKind::ErrorCode("need mount destination".to_string()).into());
+            return Err(ErrorKind::InvalidArgument("need mount destination".to_string()).into());
         }
 
         cstr_source = CString::new(self.source)?;
@@ -205,7 +206,7 @@ impl<'a> BareMount<'a> {
         dest = cstr_dest.as_ptr();
 
         if self.fs_type.len() == 0 {
-            return Err(ErrorKind::ErrorCode("need mount FS type".to_string()).into());
+            return Err(ErrorKind::InvalidArgument("need mount FS type".to_string()).into());
         }
 
         cstr_fs_type = CString::new(self.fs_type)?;
@@ -332,7 +333,9 @@ fn virtio_blk_storage_handler(
 
         let mode = metadata.permissions().mode();
         if mode & libc::S_IFBLK == 0 {
-            return Err(ErrorKind::ErrorCode(format!("Invalid device {}", &storage.source)).into());
+            return Err(
+                ErrorKind::InvalidArgument(format!("Invalid device {}", &storage.source)).into(),
+            );
         }
     } else {
         let dev_path = get_pci_device_name(&sandbox, &storage.source)?;
@@ -446,7 +449,7 @@ pub fn add_storages(
 
         let handler = match STORAGEHANDLERLIST.get(&handler_name.as_str()) {
             None => {
-                return Err(ErrorKind::ErrorCode(format!(
+                return Err(ErrorKind::InvalidArgument(format!(
                     "Failed to find the storage handler {}",
                     storage.driver.to_owned()
                 ))
@@ -455,9 +458,16 @@ pub fn add_storages(
             Some(f) => f,
         };
 
+        let mut span = tracer::span("storage_handler");
+        span.set_attribute("driver", handler_name.as_str());
+        span.set_attribute("mount-point", storage.mount_point.as_str());
+
         let mount_point = match handler(&logger, &storage, sandbox.clone()) {
             // Todo need to rollback the mounted storage if err met.
-            Err(e) => return Err(e),
+            Err(e) => {
+                span.set_error(format!("{}", e).as_str());
+                return Err(e);
+            }
             Ok(m) => m,
         };
 
@@ -510,7 +520,9 @@ pub fn get_mount_fs_type(mount_point: &str) -> Result<String> {
 // any error ecountered.
 pub fn get_mount_fs_type_from_file(mount_file: &str, mount_point: &str) -> Result<String> {
     if mount_point == "" {
-        return Err(ErrorKind::ErrorCode(format!("Invalid mount point {}", mount_point)).into());
+        return Err(
+            ErrorKind::InvalidArgument(format!("Invalid mount point {}", mount_point)).into(),
+        );
     }
 
     let file = File::open(mount_file)?;
@@ -532,7 +544,7 @@ pub fn get_mount_fs_type_from_file(mount_file: &str, mount_point: &str) -> Resul
         }
     }
 
-    Err(ErrorKind::ErrorCode(format!(
+    Err(ErrorKind::NotFound(format!(
         "failed to find FS type for mount point {}",
         mount_point
     ))
@@ -614,9 +626,21 @@ pub fn get_cgroup_mounts(logger: &Logger, cg_path: &str) -> Result<Vec<INIT_MOUN
     Ok(cg_mounts)
 }
 
-pub fn cgroups_mount(logger: &Logger) -> Result<()> {
+// cgroups_mount mounts the v1 hierarchies, or the unified hierarchy of
+// cgroup v2 when asked to.
+pub fn cgroups_mount(logger: &Logger, unified: bool) -> Result<()> {
     let logger = logger.new(o!("subsystem" => "mount"));
 
+    if unified {
+        let cg = INIT_MOUNT {
+            fstype: "cgroup2",
+            src: "cgroup2",
+            dest: SYSFS_CGROUPPATH,
+            options: vec!["nosuid", "nodev", "noexec", "relatime", "nsdelegate"],
+        };
+        return mount_to_rootfs(&logger, &cg);
+    }
+
     let cgroups = get_cgroup_mounts(&logger, PROC_CGROUPS)?;
 
     for cg in cgroups.iter() {
@@ -3,9 +3,13 @@
 // SPDX-License-Identifier: Apache-2.0
 //
 
+use libc::pid_t;
+use nix::errno::Errno;
 use nix::mount::MsFlags;
-use nix::sched::{unshare, CloneFlags};
-use nix::unistd::{getpid, gettid};
+use nix::sched::{setns, unshare, CloneFlags};
+use nix::sys::signal::{self, SigSet, SigmaskHow, Signal};
+use nix::sys::wait::{self, WaitPidFlag};
+use nix::unistd::{self, fork, getpid, gettid, sethostname, ForkResult, Pid};
 use std::fmt;
 use std::fs;
 use std::fs::File;
@@ -37,6 +41,8 @@ pub struct Namespace {
     pub path: String,
     persistent_ns_dir: String,
     ns_type: NamespaceType,
+    // pid of the process holding a pid namespace
+    pid: Option<pid_t>,
 }
 
 impl Namespace {
@@ -46,6 +52,7 @@ impl Namespace {
             path: String::from(""),
             persistent_ns_dir: String::from(PERSISTENT_NS_DIR),
             ns_type: NamespaceType::IPC,
+            pid: None,
         }
     }
 
@@ -59,14 +66,31 @@ impl Namespace {
         self
     }
 
+    pub fn as_pid(mut self) -> Self {
+        self.ns_type = NamespaceType::PID;
+        self
+    }
+
+    // adopt makes a namespace out of the one a previous agent set up.
+    pub fn adopt(mut self, path: &str, pid: Option<pid_t>) -> Self {
+        self.path = path.to_string();
+        self.pid = pid;
+        self
+    }
+
     pub fn set_root_dir(mut self, dir: &str) -> Self {
         self.persistent_ns_dir = dir.to_string();
         self
     }
 
     // setup_persistent_ns creates persistent namespace without switching to it.
-    // Note, pid namespaces cannot be persisted.
+    // Note, pid namespaces cannot be persisted, they are held by a pause
+    // process instead.
     pub fn setup(mut self) -> Result<Self, String> {
+        if let NamespaceType::PID = self.ns_type {
+            return self.setup_pid_ns();
+        }
+
         if let Err(err) = fs::create_dir_all(&self.persistent_ns_dir) {
             return Err(err.to_string());
         }
@@ -133,6 +157,145 @@ impl Namespace {
 
         Ok(self)
     }
+
+    // setup_pid_ns spawns a pause process in a new pid namespace, which
+    // lives as long as this process does. Being the init of the namespace,
+    // the pause process reaps the orphans of the containers sharing it.
+    fn setup_pid_ns(mut self) -> Result<Self, String> {
+        // unshare() only changes the pid namespace of the children of the
+        // calling thread
+        let new_thread = thread::spawn(move || -> Result<pid_t, String> {
+            unshare(CloneFlags::CLONE_NEWPID).map_err(|e| e.to_string())?;
+
+            match fork().map_err(|e| e.to_string())? {
+                ForkResult::Parent { child } => Ok(child.as_raw()),
+                ForkResult::Child => pause(),
+            }
+        });
+
+        let pid = match new_thread.join() {
+            Ok(r) => r?,
+            Err(err) => return Err(format!("Failed to join thread {:?}!", err)),
+        };
+
+        info!(self.logger, "pid namespace pause process started"; "pid" => pid);
+
+        self.pid = Some(pid);
+        self.path = format!("/proc/{}/ns/{}", pid, self.ns_type.get());
+
+        Ok(self)
+    }
+
+    // pid returns the pid of the pause process of a pid namespace.
+    pub fn pid(&self) -> Option<pid_t> {
+        self.pid
+    }
+
+    // release unmounts and removes a persistent namespace, or stops the
+    // pause process of a pid namespace, which kills the processes left in
+    // it. Releasing a namespace twice is fine.
+    pub fn release(&mut self) -> Result<(), String> {
+        if self.path.is_empty() {
+            return Ok(());
+        }
+
+        if let NamespaceType::PID = self.ns_type {
+            return self.kill_pause();
+        }
+
+        match nix::mount::umount(self.path.as_str()) {
+            Ok(()) | Err(nix::Error::Sys(Errno::EINVAL)) | Err(nix::Error::Sys(Errno::ENOENT)) => {
+                ()
+            }
+            Err(e) => return Err(format!("Failed to umount {}: {:?}", self.path, e)),
+        }
+
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(format!("Failed to remove {}: {:?}", self.path, e))
+            }
+            _ => (),
+        }
+
+        self.path.clear();
+
+        Ok(())
+    }
+
+    fn kill_pause(&mut self) -> Result<(), String> {
+        let pid = match self.pid.take() {
+            Some(p) => Pid::from_raw(p),
+            None => return Ok(()),
+        };
+
+        self.path.clear();
+
+        match signal::kill(pid, Some(Signal::SIGKILL)) {
+            Ok(()) | Err(nix::Error::Sys(Errno::ESRCH)) => (),
+            Err(e) => return Err(format!("Failed to kill pause process {}: {:?}", pid, e)),
+        }
+
+        // the agent reaper may have been faster
+        match wait::waitpid(pid, Some(WaitPidFlag::__WALL)) {
+            Ok(_) | Err(nix::Error::Sys(Errno::ECHILD)) => Ok(()),
+            Err(e) => Err(format!("Failed to wait pause process {}: {:?}", pid, e)),
+        }
+    }
+
+    // set_hostname sets the hostname in a persistent UTS namespace.
+    pub fn set_hostname(&self, hostname: &str) -> Result<(), String> {
+        if self.path.is_empty() {
+            return Err("UTS namespace is not set up".to_string());
+        }
+
+        let path = self.path.clone();
+        let hostname = hostname.to_string();
+
+        // setns() only moves the calling thread to the namespace
+        let new_thread = thread::spawn(move || {
+            let file = File::open(&path).map_err(|e| e.to_string())?;
+
+            setns(file.as_raw_fd(), CloneFlags::CLONE_NEWUTS)
+                .map_err(|e| format!("Failed to join {}: {:?}", path, e))?;
+
+            sethostname(&hostname).map_err(|e| format!("Failed to set hostname: {:?}", e))
+        });
+
+        match new_thread.join() {
+            Ok(r) => r,
+            Err(err) => Err(format!("Failed to join thread {:?}!", err)),
+        }
+    }
+}
+
+// pause is run by the init of a pid namespace, right after fork(). It
+// sticks to async-signal-safe calls since the parent is multi-threaded.
+fn pause() -> ! {
+    // don't hold the descriptors of the agent, e.g. the pipes of the
+    // container processes
+    let max_fd = match unsafe { libc::sysconf(libc::_SC_OPEN_MAX) } {
+        n if n > 0 => n as i32,
+        _ => 1024,
+    };
+    for fd in 0..max_fd {
+        let _ = unistd::close(fd);
+    }
+
+    let mut mask = SigSet::empty();
+    mask.add(Signal::SIGCHLD);
+    if signal::sigprocmask(SigmaskHow::SIG_BLOCK, Some(&mask), None).is_err() {
+        unsafe { libc::_exit(1) };
+    }
+
+    loop {
+        let _ = mask.wait();
+
+        while let Ok(status) = wait::waitpid(None, Some(WaitPidFlag::WNOHANG)) {
+            if status == wait::WaitStatus::StillAlive {
+                break;
+            }
+        }
+    }
 }
 
 /// Represents the Namespace type.
@@ -209,6 +372,81 @@ mod tests {
         assert!(remove_mounts(&vec![ns_uts.unwrap().path]).is_ok());
     }
 
+    #[test]
+    fn test_release_persistent_ns() {
+        skip_if_not_root!();
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let tmpdir = Builder::new().prefix("ipc").tempdir().unwrap();
+
+        let mut ns_ipc = Namespace::new(&logger)
+            .as_ipc()
+            .set_root_dir(tmpdir.path().to_str().unwrap())
+            .setup()
+            .unwrap();
+        let path = ns_ipc.path.clone();
+
+        assert!(ns_ipc.release().is_ok());
+        assert!(ns_ipc.path.is_empty());
+        assert!(!std::path::Path::new(&path).exists());
+
+        assert!(ns_ipc.release().is_ok());
+    }
+
+    #[test]
+    fn test_set_hostname() {
+        skip_if_not_root!();
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let tmpdir = Builder::new().prefix("uts").tempdir().unwrap();
+
+        let ns_uts = Namespace::new(&logger)
+            .as_uts()
+            .set_root_dir(tmpdir.path().to_str().unwrap())
+            .setup()
+            .unwrap();
+
+        let current = nix::unistd::gethostname(&mut [0u8; 64])
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        assert!(ns_uts.set_hostname("kata-test-pod").is_ok());
+
+        // the hostname of the agent is left alone
+        let after = nix::unistd::gethostname(&mut [0u8; 64])
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(current, after);
+
+        assert!(remove_mounts(&vec![ns_uts.path]).is_ok());
+
+        assert!(Namespace::new(&logger).set_hostname("foo").is_err());
+    }
+
+    #[test]
+    fn test_setup_pid_ns() {
+        skip_if_not_root!();
+        let logger = slog::Logger::root(slog::Discard, o!());
+
+        let mut ns_pid = Namespace::new(&logger).as_pid().setup().unwrap();
+
+        let pid = ns_pid.pid().unwrap();
+        assert_eq!(ns_pid.path, format!("/proc/{}/ns/pid", pid));
+
+        // the pause process is the init of a new namespace
+        let ns = std::fs::read_link(&ns_pid.path).unwrap();
+        assert_ne!(ns, std::fs::read_link("/proc/self/ns/pid").unwrap());
+
+        assert!(ns_pid.release().is_ok());
+        assert!(ns_pid.pid().is_none());
+        assert!(!std::path::Path::new(&format!("/proc/{}", pid)).exists());
+
+        // releasing it twice is fine
+        assert!(ns_pid.release().is_ok());
+    }
+
     #[test]
     fn test_namespace_type() {
         let ipc = NamespaceType::IPC;
@@ -4,7 +4,10 @@
 //
 
 use protocols::types::{Interface, Route};
+use rustjail::errors::*;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
 // Network fully describes a sandbox network with its interfaces, routes and dns
 // related information.
@@ -27,4 +30,62 @@ impl Network {
     pub fn set_dns(&mut self, dns: String) {
         self.dns.push(dns);
     }
+
+    pub fn update_dns(&mut self, dns: Vec<String>) {
+        self.dns = dns;
+    }
+
+    pub fn dns(&self) -> &[String] {
+        &self.dns
+    }
+
+    // setup_dns writes the DNS entries, which are resolv.conf lines, to
+    // path. The file is rewritten in place, so that the containers bind
+    // mounting it see the updates.
+    pub fn setup_dns(&self, path: &str) -> Result<()> {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut content = self.dns.join("\n");
+        if !content.is_empty() {
+            content.push('\n');
+        }
+
+        fs::write(path, content).chain_err(|| format!("cannot write {}", path))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::MetadataExt;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_setup_dns() {
+        let dir = tempdir().expect("failed to create tmpdir");
+        let path = dir.path().join("sandbox/resolv.conf");
+        let path = path.to_str().unwrap();
+
+        let mut network = Network::new();
+        network.set_dns("nameserver 10.0.0.1".to_string());
+        network.set_dns("search example.com".to_string());
+        network.setup_dns(path).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            "nameserver 10.0.0.1\nsearch example.com\n"
+        );
+        let ino = fs::metadata(path).unwrap().ino();
+
+        network.update_dns(vec!["nameserver 10.0.0.2".to_string()]);
+        network.setup_dns(path).unwrap();
+
+        assert_eq!(fs::read_to_string(path).unwrap(), "nameserver 10.0.0.2\n");
+        // bind mounts of the file must keep working
+        assert_eq!(fs::metadata(path).unwrap().ino(), ino);
+    }
 }
@@ -0,0 +1,317 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The policy lists the RPCs the agent serves, any other RPC is denied.
+// It's a JSON file such as:
+//
+// {
+//     "allow": {
+//         "CreateContainer": {},
+//         "ExecProcess": { "containers": ["foo"] },
+//         "CopyFile": { "path_prefixes": ["/run/kata-containers/shared/"] }
+//     }
+// }
+//
+// where the RPCs are named as in agent.proto, and the optional
+// constraints are:
+// - containers: the container_id of the request must be one of these.
+// - path_prefixes: the path of the request must be under one of these.
+
+use protobuf::Message;
+use rustjail::errors::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path};
+
+// policy baked into the image, used when none is given on the kernel
+// command line
+pub const DEFAULT_POLICY_FILE: &str = "/etc/kata-agent/policy.json";
+
+const ALLOW_KEY: &str = "allow";
+const CONTAINERS_KEY: &str = "containers";
+const PATH_PREFIXES_KEY: &str = "path_prefixes";
+
+const CONTAINER_ID_FIELD: &str = "container_id";
+const PATH_FIELD: &str = "path";
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Rule {
+    containers: Option<Vec<String>>,
+    path_prefixes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Policy {
+    rules: HashMap<String, Rule>,
+}
+
+impl Policy {
+    // load reads the policy in path, or the default one if path is None.
+    // Having no policy at all is fine, unless it was explicitly asked for.
+    pub fn load(path: Option<&str>, rpcs: &[&str]) -> Result<Option<Policy>> {
+        let path = match path {
+            Some(p) => p,
+            None if Path::new(DEFAULT_POLICY_FILE).exists() => DEFAULT_POLICY_FILE,
+            None => return Ok(None),
+        };
+
+        let content =
+            fs::read_to_string(path).chain_err(|| format!("cannot read policy {}", path))?;
+
+        let policy = Policy::parse(content.as_str(), rpcs)
+            .chain_err(|| format!("invalid policy {}", path))?;
+
+        Ok(Some(policy))
+    }
+
+    // parse parses a policy, rpcs being the names of all the RPCs.
+    pub fn parse(content: &str, rpcs: &[&str]) -> Result<Policy> {
+        let v: Value = serde_json::from_str(content)?;
+
+        let allow = match v.get(ALLOW_KEY).and_then(|a| a.as_object()) {
+            Some(a) => a,
+            None => {
+                return Err(ErrorKind::InvalidArgument(format!("no {} object", ALLOW_KEY)).into())
+            }
+        };
+
+        let mut rules = HashMap::new();
+
+        for (rpc, constraints) in allow.iter() {
+            if !rpcs.contains(&rpc.as_str()) {
+                return Err(ErrorKind::InvalidArgument(format!("unknown RPC {}", rpc)).into());
+            }
+
+            let constraints = match constraints.as_object() {
+                Some(c) => c,
+                None => {
+                    return Err(ErrorKind::InvalidArgument(format!(
+                        "constraints of {} are not an object",
+                        rpc
+                    ))
+                    .into())
+                }
+            };
+
+            let mut rule = Rule::default();
+            for (key, value) in constraints.iter() {
+                let list = get_string_list(value)
+                    .chain_err(|| format!("invalid {} constraint of {}", key, rpc))?;
+
+                match key.as_str() {
+                    CONTAINERS_KEY => rule.containers = Some(list),
+                    PATH_PREFIXES_KEY => {
+                        if let Some(p) = list.iter().find(|p| !is_clean_abs_path(p)) {
+                            return Err(ErrorKind::InvalidArgument(format!(
+                                "path prefix {} of {} is not a clean absolute path",
+                                p, rpc
+                            ))
+                            .into());
+                        }
+                        rule.path_prefixes = Some(list);
+                    }
+                    _ => {
+                        return Err(ErrorKind::InvalidArgument(format!(
+                            "unknown constraint {} for {}",
+                            key, rpc
+                        ))
+                        .into())
+                    }
+                }
+            }
+
+            rules.insert(rpc.clone(), rule);
+        }
+
+        Ok(Policy { rules })
+    }
+
+    // check returns an error when the policy denies rpc, req being the
+    // request message, which is None for client streaming RPCs.
+    pub fn check(&self, rpc: &str, req: Option<&dyn Message>) -> Result<()> {
+        let rule = match self.rules.get(rpc) {
+            Some(r) => r,
+            None => return Err(denied(rpc, "not allowed")),
+        };
+
+        if let Some(containers) = rule.containers.as_ref() {
+            let cid = match req.and_then(|m| get_string_field(m, CONTAINER_ID_FIELD)) {
+                Some(c) => c,
+                None => return Err(denied(rpc, "no container id")),
+            };
+
+            if !containers.iter().any(|c| c == cid) {
+                return Err(denied(rpc, format!("container {}", cid).as_str()));
+            }
+        }
+
+        if let Some(prefixes) = rule.path_prefixes.as_ref() {
+            let path = match req.and_then(|m| get_string_field(m, PATH_FIELD)) {
+                Some(p) => p,
+                None => return Err(denied(rpc, "no path")),
+            };
+
+            // the path is not resolved, refuse anything that could
+            // escape the prefixes
+            if !is_clean_abs_path(path)
+                || !prefixes
+                    .iter()
+                    .any(|prefix| Path::new(path).starts_with(prefix))
+            {
+                return Err(denied(rpc, format!("path {}", path).as_str()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn denied(rpc: &str, reason: &str) -> Error {
+    ErrorKind::PermissionDenied(format!("{} denied by policy: {}", rpc, reason)).into()
+}
+
+fn get_string_list(value: &Value) -> Result<Vec<String>> {
+    let list = match value.as_array() {
+        Some(l) => l,
+        None => return Err(ErrorKind::InvalidArgument("not an array".to_string()).into()),
+    };
+
+    list.iter()
+        .map(|v| match v.as_str() {
+            Some(s) => Ok(s.to_string()),
+            None => Err(ErrorKind::InvalidArgument(format!("{} is not a string", v)).into()),
+        })
+        .collect()
+}
+
+fn get_string_field<'a>(m: &'a dyn Message, name: &str) -> Option<&'a str> {
+    m.descriptor()
+        .fields()
+        .iter()
+        .find(|f| f.name() == name)
+        .map(|f| f.get_str(m))
+}
+
+fn is_clean_abs_path(path: &str) -> bool {
+    let path = Path::new(path);
+
+    path.is_absolute()
+        && path
+            .components()
+            .all(|c| c != Component::ParentDir && c != Component::CurDir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protocols::agent::{
+        CopyFileRequest, ExecProcessRequest, GuestDetailsRequest, WriteStreamRequest,
+    };
+
+    const RPCS: &[&str] = &["CopyFile", "ExecProcess", "GetGuestDetails", "StreamStdin"];
+
+    #[test]
+    fn test_parse() {
+        let policy = Policy::parse(
+            r#"{"allow": {"GetGuestDetails": {}, "ExecProcess": {"containers": ["foo"]}}}"#,
+            RPCS,
+        )
+        .unwrap();
+
+        assert_eq!(policy.rules.len(), 2);
+        assert_eq!(
+            policy.rules.get("GetGuestDetails").unwrap(),
+            &Rule::default()
+        );
+        assert_eq!(
+            policy.rules.get("ExecProcess").unwrap().containers,
+            Some(vec!["foo".to_string()])
+        );
+
+        let invalid = &[
+            "",
+            "{}",
+            r#"{"allow": []}"#,
+            r#"{"allow": {"Unknown": {}}}"#,
+            r#"{"allow": {"ExecProcess": []}}"#,
+            r#"{"allow": {"ExecProcess": {"users": ["root"]}}}"#,
+            r#"{"allow": {"ExecProcess": {"containers": "foo"}}}"#,
+            r#"{"allow": {"ExecProcess": {"containers": [1]}}}"#,
+            r#"{"allow": {"CopyFile": {"path_prefixes": ["run"]}}}"#,
+            r#"{"allow": {"CopyFile": {"path_prefixes": ["/run/../etc"]}}}"#,
+        ];
+
+        for (i, content) in invalid.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, content);
+            assert!(Policy::parse(content, RPCS).is_err(), "{}", msg);
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let policy = Policy::parse(
+            r#"{"allow": {
+                "GetGuestDetails": {},
+                "StreamStdin": {},
+                "ExecProcess": {"containers": ["foo"]},
+                "CopyFile": {"path_prefixes": ["/run/kata-containers/shared"]}
+            }}"#,
+            RPCS,
+        )
+        .unwrap();
+
+        let req = GuestDetailsRequest::new();
+        assert!(policy.check("GetGuestDetails", Some(&req)).is_ok());
+        assert!(policy.check("StreamStdin", None).is_ok());
+
+        let mut req = ExecProcessRequest::new();
+        req.container_id = "foo".to_string();
+        assert!(policy.check("ExecProcess", Some(&req)).is_ok());
+        req.container_id = "bar".to_string();
+        let err = policy.check("ExecProcess", Some(&req)).unwrap_err();
+        match err.kind() {
+            ErrorKind::PermissionDenied(_) => {}
+            _ => panic!("unexpected error {:?}", err),
+        }
+
+        let tests = &[
+            ("/run/kata-containers/shared/foo/rootfs", true),
+            ("/run/kata-containers/shared", true),
+            ("/run/kata-containers/sharedfoo", false),
+            ("/run/kata-containers/shared/../../../etc/passwd", false),
+            ("run/kata-containers/shared/foo", false),
+            ("/etc/passwd", false),
+        ];
+
+        let mut req = CopyFileRequest::new();
+        for (i, (path, allowed)) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, path);
+            req.path = path.to_string();
+            assert_eq!(
+                policy.check("CopyFile", Some(&req)).is_ok(),
+                *allowed,
+                "{}",
+                msg
+            );
+        }
+
+        // not in the policy
+        assert!(policy.check("SetGuestDateTime", None).is_err());
+
+        // the requests of a stream are checked one by one
+        let policy = Policy::parse(
+            r#"{"allow": {"StreamStdin": {"containers": ["foo"]}}}"#,
+            RPCS,
+        )
+        .unwrap();
+        let mut req = WriteStreamRequest::new();
+        req.container_id = "foo".to_string();
+        assert!(policy.check("StreamStdin", Some(&req)).is_ok());
+        req.container_id = "bar".to_string();
+        assert!(policy.check("StreamStdin", Some(&req)).is_err());
+        assert!(policy.check("StreamStdin", None).is_err());
+    }
+}
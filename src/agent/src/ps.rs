@@ -0,0 +1,419 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+use crate::user;
+use libc::pid_t;
+use nix::unistd::{self, SysconfVar};
+use rustjail::errors::*;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const PROC_STAT: &str = "/proc/stat";
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// ProcessInfo is what ListProcesses reports for a single task, it is
+// read from /proc/<pid>/stat, status and cmdline.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProcessInfo {
+    pub pid: pid_t,
+    pub ppid: pid_t,
+    pub uid: u32,
+    pub user: String,
+    pub state: String,
+    // user and system time, in seconds
+    pub cpu_time: u64,
+    // resident set size, in KiB
+    pub rss: u64,
+    // seconds since the epoch
+    pub start_time: u64,
+    pub cmd: String,
+}
+
+// Fields of /proc/<pid>/stat that we care about, counting from the
+// field after the command name, i.e. the state is at index 0.
+struct Stat {
+    comm: String,
+    state: String,
+    ppid: pid_t,
+    utime: u64,
+    stime: u64,
+    starttime: u64,
+}
+
+// list_processes returns the information about the tasks in pids, the
+// tasks that have exited in the meantime are skipped.
+pub fn list_processes(pids: &[pid_t]) -> Result<Vec<ProcessInfo>> {
+    let clk_tck = match unistd::sysconf(SysconfVar::CLK_TCK)? {
+        Some(v) if v > 0 => v as u64,
+        _ => 100,
+    };
+    let btime = get_boot_time()?;
+
+    let mut users: HashMap<String, HashMap<u32, String>> = HashMap::new();
+    let mut list = Vec::new();
+
+    for pid in pids {
+        let mut info = match get_process_info(*pid, clk_tck, btime) {
+            Ok(info) => info,
+            Err(_) => continue,
+        };
+
+        // names are resolved against the passwd file the task sees,
+        // i.e. the container one
+        let root = format!("/proc/{}/root", pid);
+        let names = users
+            .entry(fs::read_link(&root).map_or(root.clone(), |p| p.display().to_string()))
+            .or_insert_with(|| {
+                fs::read_to_string(format!("{}/etc/passwd", root))
+                    .map(|c| parse_passwd(c.as_str()))
+                    .unwrap_or_default()
+            });
+
+        info.user = match names.get(&info.uid) {
+            Some(name) => name.clone(),
+            None => info.uid.to_string(),
+        };
+
+        list.push(info);
+    }
+
+    list.sort_by_key(|p| p.pid);
+
+    Ok(list)
+}
+
+// get_start_time returns the start time of a running process, in clock
+// ticks after boot, which tells it apart from a later one reusing its pid.
+pub fn get_start_time(pid: pid_t) -> Option<u64> {
+    let content = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    let stat = parse_stat(content.as_str()).ok()?;
+
+    if stat.state == "Z" || stat.state == "X" {
+        return None;
+    }
+
+    Some(stat.starttime)
+}
+
+fn get_process_info(pid: pid_t, clk_tck: u64, btime: u64) -> Result<ProcessInfo> {
+    let stat = parse_stat(fs::read_to_string(format!("/proc/{}/stat", pid))?.as_str())?;
+    let status = fs::read_to_string(format!("/proc/{}/status", pid))?;
+    let cmdline = fs::read(format!("/proc/{}/cmdline", pid))?;
+
+    // kernel threads and zombies have no command line
+    let cmd = if cmdline.is_empty() {
+        format!("[{}]", stat.comm)
+    } else {
+        String::from_utf8_lossy(&cmdline)
+            .trim_end_matches('\0')
+            .replace('\0', " ")
+    };
+
+    Ok(ProcessInfo {
+        pid,
+        ppid: stat.ppid,
+        uid: get_status_field(status.as_str(), "Uid:", 1).unwrap_or(0) as u32,
+        user: String::new(),
+        state: stat.state,
+        cpu_time: (stat.utime + stat.stime) / clk_tck,
+        rss: get_status_field(status.as_str(), "VmRSS:", 0).unwrap_or(0),
+        start_time: btime + stat.starttime / clk_tck,
+        cmd,
+    })
+}
+
+fn parse_stat(content: &str) -> Result<Stat> {
+    // the command name is between parentheses and may contain both
+    // spaces and parentheses
+    let (start, end) = match (content.find('('), content.rfind(')')) {
+        (Some(s), Some(e)) if s < e => (s, e),
+        _ => return Err(ErrorKind::ErrorCode(format!("invalid stat {}", content)).into()),
+    };
+
+    let fields: Vec<&str> = content[end + 1..].split_whitespace().collect();
+    if fields.len() < 20 {
+        return Err(ErrorKind::ErrorCode(format!("invalid stat {}", content)).into());
+    }
+
+    Ok(Stat {
+        comm: content[start + 1..end].to_string(),
+        state: fields[0].to_string(),
+        ppid: fields[1].parse::<pid_t>()?,
+        utime: fields[11].parse::<u64>()?,
+        stime: fields[12].parse::<u64>()?,
+        starttime: fields[19].parse::<u64>()?,
+    })
+}
+
+// get_status_field returns the index-th value of the key line of a
+// /proc/<pid>/status file.
+fn get_status_field(content: &str, key: &str, index: usize) -> Option<u64> {
+    content
+        .lines()
+        .find(|l| l.starts_with(key))
+        .and_then(|l| l[key.len()..].split_whitespace().nth(index))
+        .and_then(|v| v.parse::<u64>().ok())
+}
+
+fn get_boot_time() -> Result<u64> {
+    let content = fs::read_to_string(PROC_STAT)?;
+
+    match get_status_field(content.as_str(), "btime", 0) {
+        Some(v) => Ok(v),
+        None => Err(ErrorKind::ErrorCode(format!("no btime in {}", PROC_STAT)).into()),
+    }
+}
+
+fn parse_passwd(content: &str) -> HashMap<u32, String> {
+    let mut names = HashMap::new();
+
+    for entry in user::parse_passwd(content) {
+        names.entry(entry.uid).or_insert(entry.name);
+    }
+
+    names
+}
+
+// format_table formats the processes like "ps -ef" does, with a few
+// more columns.
+pub fn format_table(list: &[ProcessInfo]) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let header = vec!["UID", "PID", "PPID", "STAT", "RSS", "STIME", "TIME", "CMD"];
+    let mut rows: Vec<Vec<String>> = vec![header.iter().map(|h| h.to_string()).collect()];
+
+    for p in list {
+        rows.push(vec![
+            p.user.clone(),
+            p.pid.to_string(),
+            p.ppid.to_string(),
+            p.state.clone(),
+            p.rss.to_string(),
+            format_start_time(p.start_time, now),
+            format_cpu_time(p.cpu_time),
+            p.cmd.clone(),
+        ]);
+    }
+
+    let mut widths = vec![0; header.len()];
+    for row in rows.iter() {
+        for (i, v) in row.iter().enumerate() {
+            widths[i] = widths[i].max(v.len());
+        }
+    }
+
+    let mut out = String::new();
+    for row in rows.iter() {
+        let last = row.len() - 1;
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if i == last {
+                    v.clone()
+                } else {
+                    format!("{:<width$}", v, width = widths[i])
+                }
+            })
+            .collect();
+        out.push_str(line.join(" ").as_str());
+        out.push('\n');
+    }
+
+    out
+}
+
+// ignored_table_args returns the arguments ps used to be run with that
+// the table doesn't follow: it is always what "ps -ef" prints, whatever
+// the runtime asks for.
+pub fn ignored_table_args(args: &[String]) -> Vec<&str> {
+    args.iter()
+        .filter(|arg| {
+            arg.len() < 2
+                || !arg.starts_with('-')
+                || !arg[1..].chars().all(|c| c == 'e' || c == 'f')
+        })
+        .map(|arg| arg.as_str())
+        .collect()
+}
+
+// format_pids is the "json" format, only the pids of the processes.
+pub fn format_pids(pids: &[pid_t]) -> Result<Vec<u8>> {
+    Ok(serde_json::to_vec(pids)?)
+}
+
+// format_json is the "json-full" format, with every column of the table.
+pub fn format_json(list: &[ProcessInfo]) -> Result<Vec<u8>> {
+    let v: Vec<serde_json::Value> = list
+        .iter()
+        .map(|p| {
+            json!({
+                "pid": p.pid,
+                "ppid": p.ppid,
+                "uid": p.uid,
+                "user": p.user,
+                "state": p.state,
+                "cpu_time": p.cpu_time,
+                "rss": p.rss,
+                "start_time": p.start_time,
+                "cmd": p.cmd,
+            })
+        })
+        .collect();
+
+    Ok(serde_json::to_vec(&v)?)
+}
+
+// format_cpu_time formats seconds as [DD-]HH:MM:SS.
+fn format_cpu_time(secs: u64) -> String {
+    let days = secs / SECONDS_PER_DAY;
+    let secs = secs % SECONDS_PER_DAY;
+    let hms = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    );
+
+    if days > 0 {
+        format!("{}-{}", days, hms)
+    } else {
+        hms
+    }
+}
+
+// format_start_time shows HH:MM for the tasks started in the last day,
+// and the date (UTC) of the older ones.
+fn format_start_time(start: u64, now: u64) -> String {
+    if now.saturating_sub(start) < SECONDS_PER_DAY {
+        let secs = start % SECONDS_PER_DAY;
+        return format!("{:02}:{:02}", secs / 3600, (secs % 3600) / 60);
+    }
+
+    let (y, m, d) = civil_from_days((start / SECONDS_PER_DAY) as i64);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+// civil_from_days converts a number of days since the epoch to a
+// (year, month, day) date of the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+
+    (y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stat() {
+        let content = "42 (my (weird) cmd) S 1 42 42 0 -1 4194560 100 0 0 0 \
+                       250 50 0 0 20 0 1 0 1000 1000000 200 18446744073709551615";
+
+        let stat = parse_stat(content).unwrap();
+        assert_eq!(stat.comm, "my (weird) cmd");
+        assert_eq!(stat.state, "S");
+        assert_eq!(stat.ppid, 1);
+        assert_eq!(stat.utime, 250);
+        assert_eq!(stat.stime, 50);
+        assert_eq!(stat.starttime, 1000);
+
+        assert!(parse_stat("42 (cmd) S 1").is_err());
+        assert!(parse_stat("garbage").is_err());
+    }
+
+    #[test]
+    fn test_get_status_field() {
+        let content = "Name:\tsh\nUid:\t0\t1000\t1000\t1000\nVmRSS:\t    1234 kB\n";
+
+        assert_eq!(get_status_field(content, "Uid:", 0), Some(0));
+        assert_eq!(get_status_field(content, "Uid:", 1), Some(1000));
+        assert_eq!(get_status_field(content, "VmRSS:", 0), Some(1234));
+        assert_eq!(get_status_field(content, "VmSwap:", 0), None);
+    }
+
+    #[test]
+    fn test_parse_passwd() {
+        let content =
+            "root:x:0:0:root:/root:/bin/sh\nbad line\nnobody:x:65534:65534::/:/bin/false\n";
+        let names = parse_passwd(content);
+
+        assert_eq!(names.get(&0).unwrap(), "root");
+        assert_eq!(names.get(&65534).unwrap(), "nobody");
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_cpu_time(0), "00:00:00");
+        assert_eq!(format_cpu_time(3661), "01:01:01");
+        assert_eq!(format_cpu_time(SECONDS_PER_DAY + 61), "1-00:01:01");
+
+        // 2020-03-01T10:30:00Z
+        let start = 1583058600;
+        assert_eq!(format_start_time(start, start + 60), "10:30");
+        assert_eq!(
+            format_start_time(start, start + 2 * SECONDS_PER_DAY),
+            "2020-03-01"
+        );
+    }
+
+    #[test]
+    fn test_get_start_time() {
+        let me = unistd::getpid().as_raw();
+
+        assert!(get_start_time(me).is_some());
+        assert_eq!(get_start_time(me), get_start_time(me));
+        assert!(get_start_time(-1).is_none());
+    }
+
+    #[test]
+    fn test_list_processes() {
+        let me = unistd::getpid().as_raw();
+        let list = list_processes(&[me, -1]).unwrap();
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].pid, me);
+        assert!(!list[0].cmd.is_empty());
+
+        let table = format_table(&list);
+        assert!(table.starts_with("UID"));
+        assert_eq!(table.lines().count(), 2);
+
+        let v: serde_json::Value = serde_json::from_slice(&format_json(&list).unwrap()).unwrap();
+        assert_eq!(v[0]["pid"], me);
+
+        let pids: Vec<pid_t> = serde_json::from_slice(&format_pids(&[me, 1]).unwrap()).unwrap();
+        assert_eq!(pids, vec![me, 1]);
+    }
+
+    #[test]
+    fn test_ignored_table_args() {
+        assert!(ignored_table_args(&[]).is_empty());
+        assert!(ignored_table_args(&["-ef".to_string()]).is_empty());
+        assert!(ignored_table_args(&["-e".to_string(), "-f".to_string()]).is_empty());
+
+        assert_eq!(ignored_table_args(&["-".to_string()]), vec!["-"]);
+        assert_eq!(ignored_table_args(&["aux".to_string()]), vec!["aux"]);
+        assert_eq!(
+            ignored_table_args(&["-o".to_string(), "pid,cmd".to_string()]),
+            vec!["-o", "pid,cmd"]
+        );
+    }
+}
@@ -8,65 +8,151 @@ use crate::linux_abi::*;
 use crate::mount::{get_mount_fs_type, remove_mounts, TYPEROOTFS};
 use crate::namespace::Namespace;
 use crate::network::Network;
+use crate::ps;
+use crate::AGENT_CONFIG;
 use libc::pid_t;
 use netlink::{RtnlHandle, NETLINK_ROUTE};
+use nix::errno::Errno;
+use nix::mount;
+use protobuf::RepeatedField;
 use protocols::agent::OnlineCPUMemRequest;
+use protocols::oci::{Hook, Hooks, Spec};
 use regex::Regex;
 use rustjail::cgroups;
 use rustjail::container::BaseContainer;
 use rustjail::container::LinuxContainer;
 use rustjail::errors::*;
-use rustjail::process::Process;
+use rustjail::process::{Process, UNKNOWN_EXIT_CODE};
 use slog::Logger;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::sync::mpsc::Sender;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// subdirectories of the guest hook path, named after the hook types
+const GUEST_HOOK_PRESTART: &str = "prestart";
+const GUEST_HOOK_POSTSTART: &str = "poststart";
+const GUEST_HOOK_POSTSTOP: &str = "poststop";
+
+// version of the sandbox state file, to be bumped on incompatible changes
+const STATE_VERSION: u32 = 1;
+
+// interval at which the processes adopted from a previous agent are
+// checked, they are not its children and cannot be waited for
+const ADOPTED_PROCESS_POLL: Duration = Duration::from_millis(500);
+
+// SandboxState is what a restarted agent needs to pick the sandbox up,
+// the containers having state files of their own.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+struct SandboxState {
+    version: u32,
+    id: String,
+    #[serde(default)]
+    hostname: String,
+    #[serde(default)]
+    hosts: Vec<String>,
+    #[serde(default)]
+    dns: Vec<String>,
+    #[serde(default)]
+    guest_hook_path: String,
+    #[serde(default)]
+    mounts: Vec<String>,
+    #[serde(default)]
+    container_mounts: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    storages: HashMap<String, u32>,
+    #[serde(default)]
+    pci_device_map: HashMap<String, String>,
+    #[serde(default)]
+    shared_ipcns: String,
+    #[serde(default)]
+    shared_utsns: String,
+    // pid and start time of the pause process of the pid namespace
+    #[serde(default)]
+    pause_process: Option<(pid_t, u64)>,
+    #[serde(default)]
+    containers: Vec<String>,
+}
 
 #[derive(Debug)]
 pub struct Sandbox {
     pub logger: Logger,
     pub id: String,
     pub hostname: String,
-    pub containers: HashMap<String, LinuxContainer>,
+    pub hosts: Vec<String>,
+    pub containers: HashMap<String, Arc<Mutex<LinuxContainer>>>,
+    // ids of the containers being created, reserved until they're added
+    pub creating: HashSet<String>,
+    // id of the container of each process, for the reaper to only lock
+    // that container
+    pub pids: HashMap<pid_t, String>,
     pub network: Network,
     pub mounts: Vec<String>,
     pub container_mounts: HashMap<String, Vec<String>>,
     pub pci_device_map: HashMap<String, String>,
     pub shared_utsns: Namespace,
     pub shared_ipcns: Namespace,
+    pub shared_pidns: Namespace,
     pub storages: HashMap<String, u32>,
     pub running: bool,
     pub no_pivot_root: bool,
     pub sandbox_pid_ns: bool,
     pub sender: Option<Sender<i32>>,
     pub rtnl: Option<RtnlHandle>,
+    pub event_rx: Arc<Mutex<Receiver<String>>>,
+    pub event_tx: Sender<String>,
+    pub hooks: Option<Hooks>,
+    pub guest_hook_path: String,
 }
 
 impl Sandbox {
     pub fn new(logger: &Logger) -> Result<Self> {
         let fs_type = get_mount_fs_type("/")?;
         let logger = logger.new(o!("subsystem" => "sandbox"));
+        let (tx, rx) = mpsc::channel::<String>();
 
         Ok(Sandbox {
             logger: logger.clone(),
             id: String::new(),
             hostname: String::new(),
+            hosts: Vec::new(),
             network: Network::new(),
             containers: HashMap::new(),
+            creating: HashSet::new(),
+            pids: HashMap::new(),
             mounts: Vec::new(),
             container_mounts: HashMap::new(),
             pci_device_map: HashMap::new(),
             shared_utsns: Namespace::new(&logger),
             shared_ipcns: Namespace::new(&logger),
+            shared_pidns: Namespace::new(&logger),
             storages: HashMap::new(),
             running: false,
             no_pivot_root: fs_type.eq(TYPEROOTFS),
             sandbox_pid_ns: false,
             sender: None,
-            rtnl: Some(RtnlHandle::new(NETLINK_ROUTE, 0).unwrap()),
+            rtnl: Some(RtnlHandle::new(NETLINK_ROUTE, 0)?),
+            event_rx: Arc::new(Mutex::new(rx)),
+            event_tx: tx,
+            hooks: None,
+            guest_hook_path: String::new(),
         })
     }
 
+    // rtnl returns the netlink handle of the sandbox, opened again if a
+    // previous attempt failed.
+    pub fn rtnl(&mut self) -> Result<&mut RtnlHandle> {
+        if self.rtnl.is_none() {
+            self.rtnl = Some(RtnlHandle::new(NETLINK_ROUTE, 0)?);
+        }
+
+        Ok(self.rtnl.as_mut().unwrap())
+    }
+
     // set_sandbox_storage sets the sandbox level reference
     // counter for the sandbox storage.
     // This method also returns a boolean to let
@@ -157,6 +243,81 @@ impl Sandbox {
         self.hostname = hostname;
     }
 
+    // setup_hostname sets the hostname of the shared UTS namespace, and
+    // writes the hostname and hosts files of the containers. Those are
+    // rewritten in place, so that the containers bind mounting them see
+    // the updates.
+    pub fn setup_hostname(&self, hostname_path: &str, hosts_path: &str) -> Result<()> {
+        if self.hostname.is_empty() {
+            return Err(ErrorKind::InvalidArgument("no hostname".to_string()).into());
+        }
+
+        self.shared_utsns
+            .set_hostname(self.hostname.as_str())
+            .map_err(|e| {
+                Error::from(ErrorKind::ErrorCode(format!(
+                    "Failed to set the hostname of the UTS namespace: {}",
+                    e
+                )))
+            })?;
+
+        self.write_hostname_files(hostname_path, hosts_path)
+    }
+
+    // write_hostname_files writes the hostname and hosts files of the
+    // containers, which exist even before a hostname is set so that the
+    // containers always bind mount them.
+    pub fn write_hostname_files(&self, hostname_path: &str, hosts_path: &str) -> Result<()> {
+        for path in &[hostname_path, hosts_path] {
+            if let Some(dir) = Path::new(path).parent() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        let hostname = if self.hostname.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", self.hostname)
+        };
+
+        fs::write(hostname_path, hostname)
+            .chain_err(|| format!("cannot write {}", hostname_path))?;
+        fs::write(hosts_path, hosts_content(&self.hostname, &self.hosts))
+            .chain_err(|| format!("cannot write {}", hosts_path))?;
+
+        Ok(())
+    }
+
+    // update_hostname sets the hostname, unless empty, and the extra hosts
+    // entries. They're only kept if the files could be updated.
+    pub fn update_hostname(
+        &mut self,
+        hostname: &str,
+        hosts: Vec<String>,
+        hostname_path: &str,
+        hosts_path: &str,
+    ) -> Result<()> {
+        let old_hostname = if hostname.is_empty() {
+            self.hostname.clone()
+        } else {
+            std::mem::replace(&mut self.hostname, hostname.to_string())
+        };
+        let old_hosts = std::mem::replace(&mut self.hosts, hosts);
+
+        let res = if self.hostname.is_empty() {
+            self.write_hostname_files(hostname_path, hosts_path)
+        } else {
+            self.setup_hostname(hostname_path, hosts_path)
+        };
+
+        if res.is_err() {
+            self.hostname = old_hostname;
+            self.hosts = old_hosts;
+        }
+
+        res
+    }
+
     pub fn setup_shared_namespaces(&mut self) -> Result<bool> {
         // Set up shared IPC namespace
         self.shared_ipcns = match Namespace::new(&self.logger).as_ipc().setup() {
@@ -184,31 +345,368 @@ impl Sandbox {
         Ok(true)
     }
 
+    // setup_shared_pid_namespace starts the pause process holding the pid
+    // namespace shared by all the containers of the sandbox.
+    pub fn setup_shared_pid_namespace(&mut self) -> Result<()> {
+        if self.sandbox_pid_ns {
+            return Ok(());
+        }
+
+        self.shared_pidns = match Namespace::new(&self.logger).as_pid().setup() {
+            Ok(ns) => ns,
+            Err(err) => {
+                return Err(ErrorKind::ErrorCode(format!(
+                    "Failed to setup sandbox PID namespace with error: {}",
+                    err
+                ))
+                .into())
+            }
+        };
+        self.sandbox_pid_ns = true;
+
+        Ok(())
+    }
+
+    // pause_process_exited drops the shared pid namespace if pid was its
+    // pause process, so that neither its recycled pid gets killed on
+    // destroy nor new containers join the namespace, which is gone.
+    pub fn pause_process_exited(&mut self, pid: pid_t) -> bool {
+        if self.shared_pidns.pid() != Some(pid) {
+            return false;
+        }
+
+        self.shared_pidns = Namespace::new(&self.logger);
+        self.sandbox_pid_ns = false;
+
+        true
+    }
+
+    // add_hooks looks for the hooks in the prestart, poststart and
+    // poststop subdirectories of dir, which run for every container with
+    // timeout seconds to complete.
+    pub fn add_hooks(&mut self, dir: &str, timeout: i64) -> Result<()> {
+        if !Path::new(dir).is_dir() {
+            return Err(ErrorKind::NotFound(format!("guest hook path {}", dir)).into());
+        }
+
+        let mut hooks = Hooks::new();
+        hooks.Prestart =
+            RepeatedField::from_vec(find_hooks(&self.logger, dir, GUEST_HOOK_PRESTART, timeout)?);
+        hooks.Poststart = RepeatedField::from_vec(find_hooks(
+            &self.logger,
+            dir,
+            GUEST_HOOK_POSTSTART,
+            timeout,
+        )?);
+        hooks.Poststop =
+            RepeatedField::from_vec(find_hooks(&self.logger, dir, GUEST_HOOK_POSTSTOP, timeout)?);
+
+        self.hooks = Some(hooks);
+        self.guest_hook_path = dir.to_string();
+
+        Ok(())
+    }
+
+    // add_guest_hooks appends the guest hooks to the ones of the spec,
+    // which come from the host.
+    pub fn add_guest_hooks(&self, spec: &mut Spec) {
+        let guest_hooks = match self.hooks.as_ref() {
+            Some(h) => h,
+            None => return,
+        };
+
+        let hooks = spec.mut_Hooks();
+        hooks.Prestart.extend(guest_hooks.Prestart.iter().cloned());
+        hooks
+            .Poststart
+            .extend(guest_hooks.Poststart.iter().cloned());
+        hooks.Poststop.extend(guest_hooks.Poststop.iter().cloned());
+    }
+
+    // reserve_container reserves id for a container being created, until
+    // it's either added or released. It fails if the id is already taken.
+    pub fn reserve_container(&mut self, id: &str) -> bool {
+        if self.containers.contains_key(id) {
+            return false;
+        }
+
+        self.creating.insert(id.to_string())
+    }
+
+    pub fn release_container(&mut self, id: &str) {
+        self.creating.remove(id);
+    }
+
     pub fn add_container(&mut self, c: LinuxContainer) {
-        self.containers.insert(c.id.clone(), c);
+        self.creating.remove(&c.id);
+        for pid in c.processes.keys() {
+            self.pids.insert(*pid, c.id.clone());
+        }
+
+        self.containers
+            .insert(c.id.clone(), Arc::new(Mutex::new(c)));
+    }
+
+    pub fn remove_container(&mut self, id: &str) {
+        self.containers.remove(id);
+        self.pids.retain(|_, cid| cid != id);
+    }
+
+    pub fn add_process(&mut self, pid: pid_t, cid: &str) {
+        self.pids.insert(pid, cid.to_string());
+    }
+
+    // remove_process forgets pid, unless it has been reused by a process
+    // of another container meanwhile.
+    pub fn remove_process(&mut self, pid: pid_t, cid: &str) {
+        if self.pids.get(&pid).map_or(false, |id| id == cid) {
+            self.pids.remove(&pid);
+        }
     }
 
-    pub fn get_container(&mut self, id: &str) -> Option<&mut LinuxContainer> {
-        self.containers.get_mut(id)
+    // get_container returns a handle on the container, callers are
+    // expected to release the sandbox lock before locking it.
+    pub fn get_container(&self, id: &str) -> Option<Arc<Mutex<LinuxContainer>>> {
+        self.containers.get(id).cloned()
     }
 
-    pub fn find_process(&mut self, pid: pid_t) -> Option<&mut Process> {
-        for (_, c) in self.containers.iter_mut() {
-            if c.processes.get(&pid).is_some() {
-                return c.processes.get_mut(&pid);
+    // teardown goes on with destroy once the containers were destroyed,
+    // with results being how that went for each of them.
+    fn teardown(&mut self, results: Vec<(String, Result<()>)>) -> Result<()> {
+        let mut errors: Vec<String> = Vec::new();
+
+        // the containers killed and stopped
+        let mut stopped = Vec::new();
+        for (id, res) in results {
+            match res {
+                Ok(()) => stopped.push(id),
+                Err(e) => errors.push(format!("container {}: {}", id, e)),
+            }
+        }
+
+        // a storage is only unmounted once nothing is using it anymore,
+        // i.e. the storages of the containers go first, and the last
+        // mounted first
+        let ids: Vec<String> = self.container_mounts.keys().cloned().collect();
+        for id in ids.iter() {
+            if self.containers.contains_key(id) && !stopped.contains(id) {
+                continue;
+            }
+
+            let mounts = self.container_mounts.remove(id).unwrap_or_default();
+            let failed = self.umount_storages(mounts, &mut errors);
+            if !failed.is_empty() {
+                self.container_mounts.insert(id.clone(), failed);
+            }
+        }
+
+        if self.container_mounts.is_empty() {
+            let mounts = std::mem::replace(&mut self.mounts, Vec::new());
+            self.mounts = self.umount_storages(mounts, &mut errors);
+        }
+
+        for id in stopped.iter() {
+            let ctr = self.containers[id].clone();
+            let mut ctr = ctr.lock().unwrap();
+
+            if let Some(cgm) = ctr.cgroup_manager.as_mut() {
+                if let Err(e) = cgm.destroy() {
+                    errors.push(format!("cgroup of container {}: {}", id, e));
+                    continue;
+                }
+            }
+
+            self.remove_container(id);
+        }
+
+        // the pid namespace goes last, it kills whatever is left in it
+        for ns in &mut [
+            &mut self.shared_ipcns,
+            &mut self.shared_utsns,
+            &mut self.shared_pidns,
+        ] {
+            if let Err(e) = ns.release() {
+                errors.push(e);
             }
         }
+        self.sandbox_pid_ns = !self.shared_pidns.path.is_empty();
 
-        None
+        if !errors.is_empty() {
+            for e in errors.iter() {
+                error!(self.logger, "sandbox teardown failed"; "error" => e.as_str());
+            }
+
+            return Err(ErrorKind::ErrorCode(format!(
+                "failed to destroy sandbox: {}",
+                errors.join("; ")
+            ))
+            .into());
+        }
+
+        self.running = false;
+
+        Ok(())
     }
 
-    pub fn destroy(&mut self) -> Result<()> {
-        for (_, ctr) in &mut self.containers {
-            ctr.destroy()?;
+    // umount_storages unmounts the storages in the reverse order, and
+    // returns the ones that failed.
+    fn umount_storages(&mut self, mounts: Vec<String>, errors: &mut Vec<String>) -> Vec<String> {
+        let mut failed = Vec::new();
+
+        for m in mounts.into_iter().rev() {
+            match mount::umount(m.as_str()) {
+                // already unmounted
+                Ok(())
+                | Err(nix::Error::Sys(Errno::EINVAL))
+                | Err(nix::Error::Sys(Errno::ENOENT)) => {
+                    self.storages.remove(&m);
+                }
+                Err(e) => {
+                    errors.push(format!("umount {}: {}", m, e));
+                    failed.insert(0, m);
+                }
+            }
+        }
+
+        failed
+    }
+
+    // save writes the state of the sandbox to path.
+    pub fn save(&self, path: &str) -> Result<()> {
+        let state = SandboxState {
+            version: STATE_VERSION,
+            id: self.id.clone(),
+            hostname: self.hostname.clone(),
+            hosts: self.hosts.clone(),
+            dns: self.network.dns().to_vec(),
+            guest_hook_path: self.guest_hook_path.clone(),
+            mounts: self.mounts.clone(),
+            container_mounts: self.container_mounts.clone(),
+            storages: self.storages.clone(),
+            pci_device_map: self.pci_device_map.clone(),
+            shared_ipcns: self.shared_ipcns.path.clone(),
+            shared_utsns: self.shared_utsns.path.clone(),
+            pause_process: self
+                .shared_pidns
+                .pid()
+                .and_then(|pid| ps::get_start_time(pid).map(|t| (pid, t))),
+            containers: self.containers.keys().cloned().collect(),
+        };
+
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir)?;
         }
+
+        // the state is replaced at once, a crash leaves the previous one
+        let tmp = format!("{}.tmp", path);
+        fs::write(&tmp, serde_json::to_vec(&state)?)
+            .chain_err(|| format!("cannot write {}", tmp))?;
+        fs::rename(&tmp, path).chain_err(|| format!("cannot rename {}", tmp))?;
+
         Ok(())
     }
 
+    // restore rebuilds the sandbox saved to path by a previous agent, and
+    // its containers from their state files under container_base. It
+    // returns false if there was nothing to restore.
+    pub fn restore(&mut self, path: &str, container_base: &str) -> Result<bool> {
+        let content = match fs::read(path) {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e).chain_err(|| format!("cannot read {}", path)),
+        };
+
+        let state: SandboxState = serde_json::from_slice(&content)?;
+        if state.version != STATE_VERSION {
+            return Err(ErrorKind::InvalidArgument(format!(
+                "unsupported version {} of {}",
+                state.version, path
+            ))
+            .into());
+        }
+
+        self.id = state.id;
+        self.hostname = state.hostname;
+        self.hosts = state.hosts;
+        self.network.update_dns(state.dns);
+        self.mounts = state.mounts;
+        self.container_mounts = state.container_mounts;
+        self.storages = state.storages;
+        self.pci_device_map = state.pci_device_map;
+
+        if !state.guest_hook_path.is_empty() {
+            let timeout = AGENT_CONFIG.read().unwrap().guest_hook_timeout;
+            if let Err(e) = self.add_hooks(state.guest_hook_path.as_str(), timeout) {
+                warn!(self.logger, "cannot restore the guest hooks"; "error" => format!("{}", e));
+            }
+        }
+
+        if !state.shared_ipcns.is_empty() {
+            self.shared_ipcns = Namespace::new(&self.logger)
+                .as_ipc()
+                .adopt(state.shared_ipcns.as_str(), None);
+        }
+        if !state.shared_utsns.is_empty() {
+            self.shared_utsns = Namespace::new(&self.logger)
+                .as_uts()
+                .adopt(state.shared_utsns.as_str(), None);
+        }
+
+        // the pid namespace is gone with its pause process
+        if let Some((pid, start_time)) = state.pause_process {
+            if ps::get_start_time(pid) == Some(start_time) {
+                self.shared_pidns = Namespace::new(&self.logger)
+                    .as_pid()
+                    .adopt(format!("/proc/{}/ns/pid", pid).as_str(), Some(pid));
+                self.sandbox_pid_ns = true;
+            } else {
+                warn!(self.logger, "the sandbox pid namespace is gone"; "pid" => pid);
+            }
+        }
+
+        for id in state.containers.iter() {
+            let mut ctr = match LinuxContainer::load(id.as_str(), container_base, &self.logger) {
+                Ok(c) => c,
+                Err(e) => {
+                    warn!(self.logger, "cannot restore container";
+                          "container-id" => id.as_str(), "error" => format!("{}", e));
+                    continue;
+                }
+            };
+
+            for p in ctr.processes.values() {
+                watch_adopted_process(&self.logger, p.clone());
+            }
+
+            if let Some(rx) = ctr.oom_events.take() {
+                self.run_oom_event_monitor(rx, id.clone());
+            }
+
+            self.add_container(ctr);
+        }
+
+        self.running = true;
+
+        Ok(true)
+    }
+
+    // run_oom_event_monitor forwards the OOM events of a container
+    // to the sandbox event channel, which is drained by GetOOMEvent.
+    pub fn run_oom_event_monitor(&self, rx: Receiver<String>, container_id: String) {
+        let logger = self.logger.clone();
+        let tx = self.event_tx.clone();
+
+        thread::spawn(move || {
+            for event in rx {
+                info!(logger, "got an OOM event {:?}", event);
+                if let Err(e) = tx.send(container_id.clone()) {
+                    error!(logger, "failed to send message: {:?}", e);
+                    break;
+                }
+            }
+        });
+    }
+
     pub fn online_cpu_memory(&self, req: &OnlineCPUMemRequest) -> Result<()> {
         if req.nb_cpus > 0 {
             // online cpus
@@ -220,9 +718,10 @@ impl Sandbox {
             online_memory(&self.logger)?;
         }
 
-        let cpuset = cgroups::fs::get_guest_cpuset()?;
+        let cpuset = cgroups::get_guest_cpuset()?;
 
         for (_, ctr) in self.containers.iter() {
+            let ctr = ctr.lock().unwrap();
             info!(self.logger, "updating {}", ctr.id.as_str());
             ctr.cgroup_manager
                 .as_ref()
@@ -234,6 +733,31 @@ impl Sandbox {
     }
 }
 
+// watch_adopted_process closes the exit pipe of a process started by a
+// previous agent once it is gone, its exit code being unknown.
+fn watch_adopted_process(logger: &Logger, p: Arc<Mutex<Process>>) {
+    let pid = {
+        let p = p.lock().unwrap();
+        if p.exit_pipe_w.is_none() {
+            return;
+        }
+        p.pid
+    };
+
+    let start_time = ps::get_start_time(pid);
+    let logger = logger.new(o!("adopted-pid" => pid));
+
+    thread::spawn(move || {
+        while start_time.is_some() && ps::get_start_time(pid) == start_time {
+            thread::sleep(ADOPTED_PROCESS_POLL);
+        }
+
+        info!(logger, "adopted process exited");
+
+        p.lock().unwrap().exited(UNKNOWN_EXIT_CODE);
+    });
+}
+
 fn online_resources(logger: &Logger, path: &str, pattern: &str, num: i32) -> Result<i32> {
     let mut count = 0;
     let re = Regex::new(pattern)?;
@@ -267,6 +791,69 @@ fn online_resources(logger: &Logger, path: &str, pattern: &str, num: i32) -> Res
     Ok(0)
 }
 
+// hosts_content returns the /etc/hosts of the containers, which resolves
+// the hostname to the loopback address unless one of the extra entries
+// resolves it.
+fn hosts_content(hostname: &str, extra: &[String]) -> String {
+    let mut lines = vec![
+        "127.0.0.1\tlocalhost".to_string(),
+        "::1\tlocalhost ip6-localhost ip6-loopback".to_string(),
+    ];
+
+    let resolved = extra
+        .iter()
+        .any(|l| l.split_whitespace().skip(1).any(|name| name == hostname));
+    if !resolved && !hostname.is_empty() {
+        lines.push(format!("127.0.1.1\t{}", hostname));
+    }
+
+    lines.extend(extra.iter().cloned());
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+// find_hooks returns the executables in the hook_type subdirectory of
+// dir, ordered by name. Each hook is run with the hook type as its only
+// argument.
+fn find_hooks(logger: &Logger, dir: &str, hook_type: &str, timeout: i64) -> Result<Vec<Hook>> {
+    let hook_dir = Path::new(dir).join(hook_type);
+    if !hook_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for e in fs::read_dir(&hook_dir).chain_err(|| format!("cannot read {:?}", &hook_dir))? {
+        entries.push(e?.path());
+    }
+    entries.sort();
+
+    let mut hooks = Vec::new();
+    for path in entries {
+        let executable = fs::metadata(&path)
+            .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false);
+        if !executable {
+            info!(
+                logger,
+                "skip guest hook {:?}, not an executable file", &path
+            );
+            continue;
+        }
+
+        let path = path.to_string_lossy().to_string();
+        let mut hook = Hook::new();
+        hook.Args = RepeatedField::from_vec(vec![hook_type.to_string()]);
+        hook.Path = path;
+        hook.Timeout = timeout;
+
+        hooks.push(hook);
+    }
+
+    Ok(hooks)
+}
+
 fn online_cpus(logger: &Logger, num: i32) -> Result<i32> {
     online_resources(logger, SYSFS_CPU_ONLINE_PATH, r"cpu[0-9]+", num)
 }
@@ -276,16 +863,91 @@ fn online_memory(logger: &Logger) -> Result<()> {
     Ok(())
 }
 
+// destroy tears the sandbox down: it kills the container processes and
+// runs their poststop hooks, unmounts the container and sandbox storages,
+// removes the container cgroups and releases the shared namespaces. Every
+// step runs even if a previous one failed, and what failed is left in
+// place so that destroy can be called again.
+// The containers are destroyed at once, each waiting for its processes
+// to exit, without the sandbox lock, which the caller must not hold.
+pub fn destroy(sandbox: &Arc<Mutex<Sandbox>>) -> Result<()> {
+    let containers: Vec<(String, Arc<Mutex<LinuxContainer>>)> = sandbox
+        .lock()
+        .unwrap()
+        .containers
+        .iter()
+        .map(|(id, ctr)| (id.clone(), ctr.clone()))
+        .collect();
+
+    let handles: Vec<(String, thread::JoinHandle<Result<()>>)> = containers
+        .into_iter()
+        .map(|(id, ctr)| (id, thread::spawn(move || ctr.lock().unwrap().destroy())))
+        .collect();
+
+    let results = handles
+        .into_iter()
+        .map(|(id, h)| {
+            let res = h.join().unwrap_or_else(|_| {
+                Err(ErrorKind::ErrorCode("destroy panicked".to_string()).into())
+            });
+            (id, res)
+        })
+        .collect();
+
+    sandbox.lock().unwrap().teardown(results)
+}
+
+// find_process looks the process of pid up in the container it has been
+// registered with, and only goes through every container if it hasn't
+// been (yet). The sandbox is locked only to get the containers, it
+// must not be locked by the caller.
+pub fn find_process(sandbox: &Arc<Mutex<Sandbox>>, pid: pid_t) -> Option<Arc<Mutex<Process>>> {
+    let owner = {
+        let s = sandbox.lock().unwrap();
+        s.pids.get(&pid).and_then(|id| s.get_container(id))
+    };
+
+    if let Some(ctr) = owner {
+        if let Some(p) = ctr.lock().unwrap().processes.get(&pid) {
+            return Some(p.clone());
+        }
+    }
+
+    let containers: Vec<Arc<Mutex<LinuxContainer>>> = sandbox
+        .lock()
+        .unwrap()
+        .containers
+        .values()
+        .cloned()
+        .collect();
+    for ctr in containers.iter() {
+        if let Some(p) = ctr.lock().unwrap().processes.get(&pid) {
+            return Some(p.clone());
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     //use rustjail::Error;
-    use super::Sandbox;
+    use super::{destroy, find_process, Sandbox};
+    use crate::config::DEFAULT_GUEST_HOOK_TIMEOUT;
+    use crate::namespace::Namespace;
     use crate::{mount::BareMount, skip_if_not_root};
+    use libc::pid_t;
     use nix::mount::MsFlags;
-    use protocols::oci::{Linux, Root, Spec};
+    use protocols::oci::{Hook, Hooks, Linux, Process as OCIProcess, Root, Spec};
     use rustjail::container::LinuxContainer;
+    use rustjail::process::Process;
     use rustjail::specconv::CreateOpts;
     use slog::Logger;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::{mpsc, Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
     use tempfile::Builder;
 
     fn bind_mount(src: &str, dst: &str, logger: &Logger) -> Result<(), rustjail::errors::Error> {
@@ -383,6 +1045,52 @@ mod tests {
         assert!(s.remove_sandbox_storage(destdir_path).is_ok());
     }
 
+    #[test]
+    fn destroy_sandbox() {
+        skip_if_not_root!();
+
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let mut s = Sandbox::new(&logger).unwrap();
+
+        let tmpdir = Builder::new().tempdir().unwrap();
+        let srcdir = tmpdir.path().join("src");
+        let sandbox_dir = tmpdir.path().join("sandbox");
+        let container_dir = sandbox_dir.join("container");
+        fs::create_dir_all(&srcdir).unwrap();
+        fs::create_dir_all(&sandbox_dir).unwrap();
+
+        let srcdir_path = srcdir.to_str().unwrap();
+        let sandbox_path = sandbox_dir.to_str().unwrap().to_string();
+        let container_path = container_dir.to_str().unwrap().to_string();
+
+        // the container storage is mounted on top of the sandbox one
+        assert!(bind_mount(srcdir_path, &sandbox_path, &logger).is_ok());
+        fs::create_dir_all(&container_dir).unwrap();
+        assert!(bind_mount(srcdir_path, &container_path, &logger).is_ok());
+
+        s.mounts = vec![sandbox_path.clone()];
+        s.container_mounts
+            .insert("foo".to_string(), vec![container_path.clone()]);
+        s.set_sandbox_storage(&container_path);
+        s.running = true;
+
+        let s = Arc::new(Mutex::new(s));
+        assert!(destroy(&s).is_ok());
+        {
+            let s = s.lock().unwrap();
+            assert!(s.mounts.is_empty());
+            assert!(s.container_mounts.is_empty());
+            assert!(s.storages.is_empty());
+            assert!(!s.running);
+        }
+
+        let mounts = fs::read_to_string("/proc/self/mountinfo").unwrap();
+        assert!(!mounts.contains(&sandbox_path));
+
+        // destroying it again is fine
+        assert!(destroy(&s).is_ok());
+    }
+
     #[test]
     #[allow(unused_assignments)]
     fn unset_and_remove_sandbox_storage() {
@@ -525,17 +1233,151 @@ mod tests {
         let mut s = Sandbox::new(&logger).unwrap();
         let linux_container = create_linuxcontainer();
 
-        s.containers
-            .insert("testContainerID".to_string(), linux_container);
+        s.containers.insert(
+            "testContainerID".to_string(),
+            Arc::new(Mutex::new(linux_container)),
+        );
         let cnt = s.get_container("testContainerID");
         assert!(cnt.is_some());
     }
 
     #[test]
-    fn get_container_no_entry() {
+    fn add_hooks() {
         let logger = slog::Logger::root(slog::Discard, o!());
         let mut s = Sandbox::new(&logger).unwrap();
 
+        let tmpdir = Builder::new().tempdir().unwrap();
+        let dir = tmpdir.path();
+
+        assert!(s
+            .add_hooks(
+                dir.join("enoent").to_str().unwrap(),
+                DEFAULT_GUEST_HOOK_TIMEOUT
+            )
+            .is_err());
+
+        fs::create_dir(dir.join("prestart")).unwrap();
+        fs::create_dir(dir.join("poststop")).unwrap();
+        for name in &["20-second", "10-first", "30-not-executable"] {
+            let path = dir.join("prestart").join(name);
+            fs::write(&path, "#!/bin/sh\n").unwrap();
+            if !name.ends_with("not-executable") {
+                fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+            }
+        }
+
+        s.add_hooks(dir.to_str().unwrap(), DEFAULT_GUEST_HOOK_TIMEOUT)
+            .unwrap();
+
+        let hooks = s.hooks.as_ref().unwrap();
+        let prestart: Vec<&str> = hooks.Prestart.iter().map(|h| h.Path.as_str()).collect();
+        assert_eq!(
+            prestart,
+            vec![
+                dir.join("prestart/10-first").to_str().unwrap(),
+                dir.join("prestart/20-second").to_str().unwrap(),
+            ]
+        );
+        assert_eq!(hooks.Prestart[0].Args.to_vec(), vec!["prestart"]);
+        assert_eq!(hooks.Prestart[0].Timeout, DEFAULT_GUEST_HOOK_TIMEOUT);
+        assert!(hooks.Poststart.is_empty());
+        assert!(hooks.Poststop.is_empty());
+
+        // guest hooks run after the ones from the host
+        let mut host_hook = Hook::new();
+        host_hook.Path = "/usr/bin/host-hook".to_string();
+        let mut spec_hooks = Hooks::new();
+        spec_hooks.Prestart.push(host_hook);
+        let mut spec = Spec::new();
+        spec.Hooks = Some(spec_hooks).into();
+
+        s.add_guest_hooks(&mut spec);
+
+        let prestart = &spec.Hooks.as_ref().unwrap().Prestart;
+        assert_eq!(prestart.len(), 3);
+        assert_eq!(prestart[0].Path, "/usr/bin/host-hook");
+        assert_eq!(prestart[1].Path, hooks.Prestart[0].Path);
+    }
+
+    #[test]
+    fn hosts_content() {
+        let content = super::hosts_content("foo", &[]);
+        assert!(content.starts_with("127.0.0.1\tlocalhost\n"));
+        assert!(content.ends_with("127.0.1.1\tfoo\n"));
+
+        let extra = vec!["10.0.0.2 foo foo.example.com".to_string()];
+        let content = super::hosts_content("foo", &extra);
+        assert!(!content.contains("127.0.1.1"));
+        assert!(content.ends_with("10.0.0.2 foo foo.example.com\n"));
+    }
+
+    #[test]
+    fn setup_hostname_without_hostname() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let s = Sandbox::new(&logger).unwrap();
+
+        assert!(s.setup_hostname("/nonexistent", "/nonexistent").is_err());
+    }
+
+    #[test]
+    fn pause_process_exited() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let mut s = Sandbox::new(&logger).unwrap();
+
+        s.shared_pidns = Namespace::new(&logger)
+            .as_pid()
+            .adopt("/proc/42/ns/pid", Some(42));
+        s.sandbox_pid_ns = true;
+
+        assert!(!s.pause_process_exited(43));
+        assert!(s.sandbox_pid_ns);
+
+        assert!(s.pause_process_exited(42));
+        assert!(!s.sandbox_pid_ns);
+        assert_eq!(s.shared_pidns.pid(), None);
+        assert!(s.shared_pidns.path.is_empty());
+    }
+
+    #[test]
+    fn update_hostname() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let mut s = Sandbox::new(&logger).unwrap();
+        let tmpdir = Builder::new().tempdir().unwrap();
+        let hostname = tmpdir.path().join("hostname");
+        let hostname = hostname.to_str().unwrap();
+        let hosts = tmpdir.path().join("hosts");
+        let hosts = hosts.to_str().unwrap();
+
+        // the files exist before the hostname is set
+        s.write_hostname_files(hostname, hosts).unwrap();
+        assert_eq!(fs::read_to_string(hostname).unwrap(), "");
+        assert!(!fs::read_to_string(hosts).unwrap().contains("127.0.1.1"));
+
+        let extra = vec!["10.0.0.2 bar".to_string()];
+        s.update_hostname("", extra.clone(), hostname, hosts)
+            .unwrap();
+        assert_eq!(s.hosts, extra);
+        assert!(fs::read_to_string(hosts)
+            .unwrap()
+            .ends_with("10.0.0.2 bar\n"));
+
+        // the hosts entries are kept as long as the files aren't updated
+        let invalid = tmpdir.path().join("hosts/hosts");
+        let res = s.update_hostname(
+            "",
+            vec!["10.0.0.3 baz".to_string()],
+            hostname,
+            invalid.to_str().unwrap(),
+        );
+        assert!(res.is_err());
+        assert_eq!(s.hosts, extra);
+    }
+
+    #[test]
+    fn get_container_no_entry() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let s = Sandbox::new(&logger).unwrap();
+
         let cnt = s.get_container("testContainerID");
         assert!(cnt.is_none());
     }
@@ -550,4 +1392,105 @@ mod tests {
         s.add_container(linux_container);
         assert!(s.get_container("some_id").is_some());
     }
+
+    #[test]
+    fn reserve_container() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let s = Arc::new(Mutex::new(Sandbox::new(&logger).unwrap()));
+
+        // only one of concurrent creations of a container goes through
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let s = s.clone();
+                thread::spawn(move || s.lock().unwrap().reserve_container("foo"))
+            })
+            .collect();
+        let reserved = handles
+            .into_iter()
+            .filter_map(|h| h.join().ok())
+            .filter(|r| *r)
+            .count();
+        assert_eq!(reserved, 1);
+
+        let mut s = s.lock().unwrap();
+        s.release_container("foo");
+        assert!(s.reserve_container("foo"));
+    }
+
+    #[test]
+    fn find_process_locks_owner() {
+        skip_if_not_root!();
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let s = Arc::new(Mutex::new(Sandbox::new(&logger).unwrap()));
+
+        let new_container = |id: &str, pid: pid_t| {
+            let mut ctr =
+                LinuxContainer::new(id, "/run/agent", create_dummy_opts(), &logger).unwrap();
+            let mut p = Process::new(&logger, &OCIProcess::new(), id, true, 0).unwrap();
+            p.pid = pid;
+            ctr.processes.insert(pid, Arc::new(Mutex::new(p)));
+            ctr
+        };
+        s.lock().unwrap().add_container(new_container("foo", 1001));
+        s.lock().unwrap().add_container(new_container("bar", 1002));
+
+        // the reaper isn't held up by another container
+        let bar = s.lock().unwrap().get_container("bar").unwrap();
+        let bar = bar.lock().unwrap();
+        let (tx, rx) = mpsc::channel();
+        let sandbox = s.clone();
+        thread::spawn(move || {
+            let _ = tx.send(find_process(&sandbox, 1001).map(|p| p.lock().unwrap().pid));
+        });
+        assert_eq!(rx.recv_timeout(Duration::from_secs(5)).unwrap(), Some(1001));
+        drop(bar);
+
+        // a process not registered yet is still found
+        s.lock().unwrap().pids.clear();
+        assert!(find_process(&s, 1002).is_some());
+        assert!(find_process(&s, 1003).is_none());
+
+        s.lock().unwrap().remove_container("foo");
+        assert!(!s.lock().unwrap().pids.contains_key(&1001));
+    }
+
+    #[test]
+    fn save_and_restore_sandbox() {
+        let logger = slog::Logger::root(slog::Discard, o!());
+        let tmpdir = Builder::new().tempdir().unwrap();
+        let path = tmpdir.path().join("sandbox/state.json");
+        let path = path.to_str().unwrap();
+
+        // nothing to restore
+        let mut s = Sandbox::new(&logger).unwrap();
+        assert_eq!(s.restore(path, "/nonexistent").unwrap(), false);
+        assert!(!s.running);
+
+        s.id = "sandbox".to_string();
+        s.hostname = "host".to_string();
+        s.hosts = vec!["127.0.0.1 host".to_string()];
+        s.mounts = vec!["/run/kata-containers/sandbox/shm".to_string()];
+        s.storages
+            .insert("/run/kata-containers/shared".to_string(), 2);
+        s.pci_device_map
+            .insert("02/01".to_string(), "/dev/vda".to_string());
+        s.save(path).unwrap();
+
+        let mut restored = Sandbox::new(&logger).unwrap();
+        assert_eq!(restored.restore(path, "/nonexistent").unwrap(), true);
+        assert!(restored.running);
+        assert_eq!(restored.id, s.id);
+        assert_eq!(restored.hostname, s.hostname);
+        assert_eq!(restored.hosts, s.hosts);
+        assert_eq!(restored.mounts, s.mounts);
+        assert_eq!(restored.storages, s.storages);
+        assert_eq!(restored.pci_device_map, s.pci_device_map);
+        assert!(restored.containers.is_empty());
+
+        // unknown versions are refused
+        let content = fs::read_to_string(path).unwrap();
+        fs::write(path, content.replace("\"version\":1", "\"version\":42")).unwrap();
+        let mut s = Sandbox::new(&logger).unwrap();
+        assert!(s.restore(path, "/nonexistent").is_err());
+    }
 }
@@ -0,0 +1,380 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The system calls of a container which seccomp profile has
+// SCMP_ACT_NOTIFY rules are handed over to the agent, which runs the
+// handlers listed by the container annotations until one of them takes
+// the call. The calls that no handler takes fail with EPERM.
+
+use nix::errno::Errno;
+use nix::mount::{self, MsFlags};
+use nix::poll::{poll, PollFd, PollFlags};
+use nix::sched::{self, CloneFlags};
+use nix::unistd;
+use protocols::oci::Spec;
+use rustjail::errors::*;
+use rustjail::seccomp::{self, Notification};
+use slog::Logger;
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::fs::File;
+use std::os::unix::fs::FileExt;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::thread;
+
+// comma separated handlers of the notified system calls, in order
+pub const HANDLERS_ANNOTATION: &str = "io.katacontainers.seccomp.handlers";
+// comma separated filesystem types the mount handler mounts
+pub const MOUNT_FSTYPES_ANNOTATION: &str = "io.katacontainers.seccomp.mount.fstypes";
+
+const DEFAULT_HANDLERS: &str = "log";
+
+// longest string read from the memory of a process
+const MAX_STRING: usize = 4096;
+
+pub struct HandlerContext {
+    pub logger: Logger,
+    pub annotations: HashMap<String, String>,
+    pub listener: RawFd,
+}
+
+// SeccompHandler returns the result of the notified system call, or None
+// to leave it to the next handler.
+pub type SeccompHandler =
+    fn(&HandlerContext, &Notification) -> Option<std::result::Result<i64, Errno>>;
+
+// SeccompHandlerList lists the supported handlers.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+lazy_static! {
+    pub static ref SECCOMPHANDLERLIST: HashMap<&'static str, SeccompHandler> = {
+        let mut m = HashMap::new();
+        let mount: SeccompHandler = mount_handler;
+        m.insert("mount", mount);
+        let log: SeccompHandler = log_handler;
+        m.insert("log", log);
+        m
+    };
+}
+
+// handlers returns the handlers listed by the annotations of a container.
+pub fn handlers(annotations: &HashMap<String, String>) -> Result<Vec<SeccompHandler>> {
+    let names = annotations
+        .get(HANDLERS_ANNOTATION)
+        .map_or(DEFAULT_HANDLERS, |h| h.as_str());
+
+    names
+        .split(',')
+        .map(|n| n.trim())
+        .filter(|n| !n.is_empty())
+        .map(|n| match SECCOMPHANDLERLIST.get(n) {
+            Some(h) => Ok(*h),
+            None => {
+                Err(ErrorKind::InvalidArgument(format!("unknown seccomp handler {}", n)).into())
+            }
+        })
+        .collect()
+}
+
+// is_notified tells whether the seccomp profile of a container has
+// SCMP_ACT_NOTIFY rules.
+pub fn is_notified(spec: &Spec) -> bool {
+    spec.get_Linux()
+        .get_Seccomp()
+        .Syscalls
+        .iter()
+        .any(|s| s.Action == "SCMP_ACT_NOTIFY")
+}
+
+// supervise handles the notifications of a listener until the processes
+// of its filter are all gone, and closes it.
+pub fn supervise(
+    logger: &Logger,
+    annotations: &HashMap<String, String>,
+    handlers: Vec<SeccompHandler>,
+    listener: RawFd,
+) {
+    let ctx = HandlerContext {
+        logger: logger.new(o!("subsystem" => "seccomp")),
+        annotations: annotations.clone(),
+        listener,
+    };
+
+    thread::spawn(move || {
+        loop {
+            let mut fds = [PollFd::new(listener, PollFlags::POLLIN)];
+            match poll(&mut fds, -1) {
+                Ok(_) => (),
+                Err(nix::Error::Sys(Errno::EINTR)) => continue,
+                Err(e) => {
+                    warn!(ctx.logger, "cannot poll the seccomp listener: {}", e);
+                    break;
+                }
+            }
+
+            // the listener hangs up once the filter has no process left
+            let revents = fds[0].revents().unwrap_or(PollFlags::empty());
+            if !revents.contains(PollFlags::POLLIN) {
+                break;
+            }
+
+            let req = match seccomp::receive(listener) {
+                Ok(req) => req,
+                // the process was killed while waiting
+                Err(nix::Error::Sys(Errno::ENOENT)) | Err(nix::Error::Sys(Errno::EINTR)) => {
+                    continue
+                }
+                Err(e) => {
+                    warn!(ctx.logger, "cannot receive a seccomp notification: {}", e);
+                    break;
+                }
+            };
+
+            let result = handlers
+                .iter()
+                .find_map(|h| h(&ctx, &req))
+                .unwrap_or(Err(Errno::EPERM));
+
+            if let Err(e) = seccomp::respond(listener, req.id, result) {
+                debug!(ctx.logger, "cannot respond to pid {}: {}", req.pid, e);
+            }
+        }
+
+        debug!(ctx.logger, "seccomp supervisor exited");
+        let _ = unistd::close(listener);
+    });
+}
+
+fn errno(e: std::io::Error) -> Errno {
+    Errno::from_i32(e.raw_os_error().unwrap_or(libc::EIO))
+}
+
+// read_string reads a nul terminated string at addr in the memory of a
+// process, a null pointer being None.
+fn read_string(pid: u32, addr: u64) -> std::result::Result<Option<CString>, Errno> {
+    if addr == 0 {
+        return Ok(None);
+    }
+
+    let mem = File::open(format!("/proc/{}/mem", pid)).map_err(errno)?;
+    let mut s: Vec<u8> = Vec::new();
+    let mut buf = [0u8; 256];
+
+    while s.len() < MAX_STRING {
+        let n = mem
+            .read_at(&mut buf, addr + s.len() as u64)
+            .map_err(errno)?;
+        if n == 0 {
+            return Err(Errno::EFAULT);
+        }
+
+        if let Some(end) = buf[..n].iter().position(|b| *b == 0) {
+            s.extend_from_slice(&buf[..end]);
+            return Ok(Some(CString::new(s).unwrap()));
+        }
+        s.extend_from_slice(&buf[..n]);
+    }
+
+    Err(Errno::ENAMETOOLONG)
+}
+
+// mount_handler mounts the filesystems of the approved types for the
+// container, without the flags that could expose the guest.
+fn mount_handler(
+    ctx: &HandlerContext,
+    req: &Notification,
+) -> Option<std::result::Result<i64, Errno>> {
+    if seccomp::syscall_number("mount") != Some(req.data.nr as u32) {
+        return None;
+    }
+
+    let args = req.data.args;
+    let fstype = match read_string(req.pid, args[2]) {
+        Ok(Some(t)) => t,
+        Ok(None) => return None,
+        Err(e) => return Some(Err(e)),
+    };
+
+    let approved = ctx
+        .annotations
+        .get(MOUNT_FSTYPES_ANNOTATION)
+        .map_or(false, |types| {
+            types
+                .split(',')
+                .any(|t| t.trim().as_bytes() == fstype.as_bytes())
+        });
+    if !approved {
+        return None;
+    }
+
+    // binds, moves and remounts could expose or change the mounts of the
+    // guest, and so could a propagation change
+    let denied = MsFlags::MS_BIND
+        | MsFlags::MS_MOVE
+        | MsFlags::MS_REMOUNT
+        | MsFlags::MS_SHARED
+        | MsFlags::MS_PRIVATE
+        | MsFlags::MS_SLAVE
+        | MsFlags::MS_UNBINDABLE;
+    let flags = MsFlags::from_bits_truncate(args[3]);
+    if flags.intersects(denied) {
+        info!(ctx.logger, "denied mount flags {:?}", flags; "pid" => req.pid);
+        return Some(Err(Errno::EPERM));
+    }
+
+    let strings = read_string(req.pid, args[0]).and_then(|source| {
+        let target = read_string(req.pid, args[1])?.ok_or(Errno::EFAULT)?;
+        let data = read_string(req.pid, args[4])?;
+        Ok((source, target, data))
+    });
+    let (source, target, data) = match strings {
+        Ok(s) => s,
+        Err(e) => return Some(Err(e)),
+    };
+
+    // the strings may come from another process reusing the pid
+    if !seccomp::is_valid(ctx.listener, req.id) {
+        return Some(Err(Errno::EPERM));
+    }
+
+    let result = mount_in(
+        req.pid,
+        source,
+        target.clone(),
+        fstype.clone(),
+        flags | MsFlags::MS_NOSUID | MsFlags::MS_NODEV,
+        data,
+    );
+    info!(ctx.logger, "mount {:?} on {:?}: {:?}", fstype, target, result; "pid" => req.pid);
+
+    Some(result.map(|_| 0))
+}
+
+// mount_in mounts in the mount namespace of a process, relative to its
+// root and working directories.
+fn mount_in(
+    pid: u32,
+    source: Option<CString>,
+    target: CString,
+    fstype: CString,
+    flags: MsFlags,
+    data: Option<CString>,
+) -> std::result::Result<(), Errno> {
+    let ns = File::open(format!("/proc/{}/ns/mnt", pid)).map_err(errno)?;
+    let root = File::open(format!("/proc/{}/root", pid)).map_err(errno)?;
+    let cwd = File::open(format!("/proc/{}/cwd", pid)).map_err(errno)?;
+
+    let mount_thread = thread::spawn(move || -> nix::Result<()> {
+        // a thread can only change its mount namespace once its
+        // filesystem attributes aren't shared anymore
+        sched::unshare(CloneFlags::CLONE_FS)?;
+        sched::setns(ns.as_raw_fd(), CloneFlags::CLONE_NEWNS)?;
+        unistd::fchdir(root.as_raw_fd())?;
+        unistd::chroot(".")?;
+        unistd::fchdir(cwd.as_raw_fd())?;
+
+        mount::mount(
+            source.as_ref().map(|s| s.as_c_str()),
+            target.as_c_str(),
+            Some(fstype.as_c_str()),
+            flags,
+            data.as_ref().map(|d| d.as_c_str()),
+        )
+    });
+
+    match mount_thread.join() {
+        Ok(r) => r.map_err(|e| e.as_errno().unwrap_or(Errno::EIO)),
+        Err(_) => Err(Errno::EIO),
+    }
+}
+
+// log_handler denies the system call, logging it.
+fn log_handler(
+    ctx: &HandlerContext,
+    req: &Notification,
+) -> Option<std::result::Result<i64, Errno>> {
+    let name = seccomp::syscall_name(req.data.nr).unwrap_or("unknown");
+    warn!(ctx.logger, "denied system call {}", name;
+        "pid" => req.pid, "nr" => req.data.nr);
+
+    Some(Err(Errno::EPERM))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protocols::oci::{LinuxSeccomp, LinuxSyscall};
+
+    #[test]
+    fn test_read_string() {
+        let s = CString::new("tmpfs").unwrap();
+        let pid = std::process::id();
+
+        let read = read_string(pid, s.as_ptr() as u64).unwrap();
+        assert_eq!(read.as_ref().map(|r| r.as_c_str()), Some(s.as_c_str()));
+        assert_eq!(read_string(pid, 0).unwrap(), None);
+
+        // longer than the read buffer
+        let long = CString::new(vec![b'a'; 1000]).unwrap();
+        let read = read_string(pid, long.as_ptr() as u64).unwrap();
+        assert_eq!(read.unwrap(), long);
+    }
+
+    #[test]
+    fn test_handlers() {
+        let mut annotations = HashMap::new();
+        assert_eq!(handlers(&annotations).unwrap().len(), 1);
+
+        annotations.insert(HANDLERS_ANNOTATION.to_string(), "mount, log".to_string());
+        assert_eq!(handlers(&annotations).unwrap().len(), 2);
+
+        annotations.insert(HANDLERS_ANNOTATION.to_string(), "mount,chown".to_string());
+        assert!(handlers(&annotations).is_err());
+    }
+
+    #[test]
+    fn test_is_notified() {
+        let mut spec = Spec::new();
+        assert!(!is_notified(&spec));
+
+        let mut syscall = LinuxSyscall::new();
+        syscall.Action = "SCMP_ACT_ALLOW".to_string();
+        let mut profile = LinuxSeccomp::new();
+        profile.Syscalls.push(syscall.clone());
+        spec.mut_Linux().set_Seccomp(profile.clone());
+        assert!(!is_notified(&spec));
+
+        syscall.Action = "SCMP_ACT_NOTIFY".to_string();
+        profile.Syscalls.push(syscall);
+        spec.mut_Linux().set_Seccomp(profile);
+        assert!(is_notified(&spec));
+    }
+
+    #[test]
+    fn test_mount_handler() {
+        let mut annotations = HashMap::new();
+        annotations.insert(MOUNT_FSTYPES_ANNOTATION.to_string(), "tmpfs".to_string());
+        let ctx = HandlerContext {
+            logger: slog::Logger::root(slog::Discard, o!()),
+            annotations,
+            listener: -1,
+        };
+
+        let fstype = CString::new("ext4").unwrap();
+        let mut req = Notification::default();
+        req.pid = std::process::id();
+        req.data.nr = seccomp::syscall_number("getpid").unwrap() as i32;
+        assert!(mount_handler(&ctx, &req).is_none());
+
+        // the type isn't approved
+        req.data.nr = seccomp::syscall_number("mount").unwrap() as i32;
+        req.data.args[2] = fstype.as_ptr() as u64;
+        assert!(mount_handler(&ctx, &req).is_none());
+
+        let fstype = CString::new("tmpfs").unwrap();
+        req.data.args[2] = fstype.as_ptr() as u64;
+        req.data.args[3] = MsFlags::MS_BIND.bits();
+        assert_eq!(mount_handler(&ctx, &req), Some(Err(Errno::EPERM)));
+    }
+}
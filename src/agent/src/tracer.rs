@@ -0,0 +1,561 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The agent traces are made of spans following the OpenTelemetry data
+// model. Finished spans are batched and exported with OTLP/HTTP to a
+// collector on the host over vsock, each batch being an OTLP/JSON
+// ExportTraceServiceRequest posted to /v1/traces on a kept alive
+// connection. The host only relays the vsock port to the OTLP/HTTP
+// receiver of the collector.
+
+use nix::sys::socket::{self, AddressFamily, SockAddr, SockFlag, SockType};
+use nix::unistd;
+use rustjail::errors::*;
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::os::unix::io::FromRawFd;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::version::AGENT_VERSION;
+
+const SERVICE_NAME: &str = "kata-agent";
+const ROOT_SPAN_NAME: &str = "root_span";
+const EXPORT_BATCH_SIZE: usize = 64;
+const EXPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+// OTLP/HTTP endpoint of the traces
+const OTLP_TRACES_PATH: &str = "/v1/traces";
+const OTLP_HOST: &str = "localhost";
+
+// OTLP span kind and status codes
+const SPAN_KIND_INTERNAL: u32 = 1;
+const STATUS_CODE_UNSET: u32 = 0;
+const STATUS_CODE_ERROR: u32 = 2;
+
+// Convenience macro to obtain the scope logger
+macro_rules! sl {
+    () => {
+        slog_scope::logger().new(o!("subsystem" => "tracer"))
+    };
+}
+
+lazy_static! {
+    static ref TRACER: Mutex<Option<Tracer>> = Mutex::new(None);
+    static ref ID_SEED: u64 = get_id_seed();
+}
+
+static ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    // ids of the spans opened by the current thread, innermost last
+    static CURRENT_SPANS: RefCell<Vec<[u8; 8]>> = RefCell::new(Vec::new());
+}
+
+struct Tracer {
+    trace_id: [u8; 16],
+    root: SpanData,
+    tx: Sender<SpanData>,
+    exporter: JoinHandle<()>,
+}
+
+#[derive(Debug, Clone)]
+struct SpanData {
+    trace_id: [u8; 16],
+    span_id: [u8; 8],
+    parent_span_id: Option<[u8; 8]>,
+    name: String,
+    start: u64,
+    end: u64,
+    attributes: Vec<(String, String)>,
+    error: Option<String>,
+}
+
+// Span measures the time until it is dropped. Spans opened while
+// another span is open in the same thread are its children, the other
+// spans are children of the root span of the tracing session.
+pub struct Span {
+    data: Option<SpanData>,
+}
+
+impl Span {
+    pub fn set_attribute(&mut self, key: &str, value: &str) {
+        if let Some(data) = self.data.as_mut() {
+            data.attributes.push((key.to_string(), value.to_string()));
+        }
+    }
+
+    pub fn set_error(&mut self, msg: &str) {
+        if let Some(data) = self.data.as_mut() {
+            data.error = Some(msg.to_string());
+        }
+    }
+
+    // detach lets the span outlive the function that opened it, e.g.
+    // until a reply has been sent from another thread: it stops being the
+    // parent of the spans opened by the current thread.
+    pub fn detach(&mut self) {
+        let id = match self.data.as_ref() {
+            Some(d) => d.span_id,
+            None => return,
+        };
+
+        CURRENT_SPANS.with(|spans| {
+            let mut spans = spans.borrow_mut();
+            if let Some(i) = spans.iter().rposition(|s| *s == id) {
+                spans.remove(i);
+            }
+        });
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let mut data = match self.data.take() {
+            Some(d) => d,
+            None => return,
+        };
+
+        CURRENT_SPANS.with(|spans| {
+            let mut spans = spans.borrow_mut();
+            if let Some(i) = spans.iter().rposition(|id| *id == data.span_id) {
+                spans.remove(i);
+            }
+        });
+
+        data.end = now_nanos();
+
+        if let Some(tracer) = TRACER.lock().unwrap().as_ref() {
+            let _ = tracer.tx.send(data);
+        }
+    }
+}
+
+// span opens a new span, which is a no-op when tracing is disabled.
+pub fn span(name: &str) -> Span {
+    let tracer = TRACER.lock().unwrap();
+    let tracer = match tracer.as_ref() {
+        Some(t) => t,
+        None => return Span { data: None },
+    };
+
+    let span_id = new_span_id();
+    let parent = CURRENT_SPANS.with(|spans| {
+        let mut spans = spans.borrow_mut();
+        let parent = spans.last().cloned();
+        spans.push(span_id);
+        parent
+    });
+
+    Span {
+        data: Some(SpanData {
+            trace_id: tracer.trace_id,
+            span_id,
+            parent_span_id: Some(parent.unwrap_or(tracer.root.span_id)),
+            name: name.to_string(),
+            start: now_nanos(),
+            end: 0,
+            attributes: Vec::new(),
+            error: None,
+        }),
+    }
+}
+
+// trace runs f in a span named name, which is marked as failed when f
+// returns an error.
+pub fn trace<T, F>(name: &str, f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T>,
+{
+    let mut span = span(name);
+    let res = f();
+
+    if let Err(e) = res.as_ref() {
+        span.set_error(format!("{}", e).as_str());
+    }
+
+    res
+}
+
+pub fn is_enabled() -> bool {
+    TRACER.lock().unwrap().is_some()
+}
+
+// start_tracing starts a new trace, exported to the collector listening
+// on the vsock port of the host.
+pub fn start_tracing(port: u32) -> Result<()> {
+    let mut tracer = TRACER.lock().unwrap();
+    if tracer.is_some() {
+        return Err(ErrorKind::FailedPrecondition("tracing is already enabled".to_string()).into());
+    }
+
+    let mut trace_id = [0u8; 16];
+    trace_id[..8].copy_from_slice(&new_span_id());
+    trace_id[8..].copy_from_slice(&new_span_id());
+
+    let (tx, rx) = mpsc::channel();
+    let exporter = thread::spawn(move || export_spans(rx, port));
+
+    *tracer = Some(Tracer {
+        trace_id,
+        root: SpanData {
+            trace_id,
+            span_id: new_span_id(),
+            parent_span_id: None,
+            name: ROOT_SPAN_NAME.to_string(),
+            start: now_nanos(),
+            end: 0,
+            attributes: vec![("vsock-port".to_string(), port.to_string())],
+            error: None,
+        },
+        tx,
+        exporter,
+    });
+
+    info!(sl!(), "tracing started"; "vsock-port" => port);
+
+    Ok(())
+}
+
+// stop_tracing ends the root span and waits until all the spans have
+// been exported.
+pub fn stop_tracing() -> Result<()> {
+    let tracer = match TRACER.lock().unwrap().take() {
+        Some(t) => t,
+        None => {
+            return Err(ErrorKind::FailedPrecondition("tracing is not enabled".to_string()).into())
+        }
+    };
+
+    let mut root = tracer.root;
+    root.end = now_nanos();
+    let _ = tracer.tx.send(root);
+
+    // the exporter flushes and exits once the channel is closed
+    drop(tracer.tx);
+    if let Err(e) = tracer.exporter.join() {
+        warn!(sl!(), "trace exporter failed: {:?}", e);
+    }
+
+    info!(sl!(), "tracing stopped");
+
+    Ok(())
+}
+
+fn export_spans(rx: Receiver<SpanData>, port: u32) {
+    let mut conn: Option<File> = None;
+    let mut batch = Vec::new();
+
+    loop {
+        let done = match rx.recv_timeout(EXPORT_INTERVAL) {
+            Ok(span) => {
+                batch.push(span);
+                if batch.len() < EXPORT_BATCH_SIZE {
+                    continue;
+                }
+                false
+            }
+            Err(RecvTimeoutError::Timeout) => false,
+            Err(RecvTimeoutError::Disconnected) => true,
+        };
+
+        if !batch.is_empty() {
+            // spans are dropped if the collector cannot be reached,
+            // tracing must not get in the way of the agent
+            if let Err(e) = export_batch(&mut conn, port, &batch) {
+                warn!(sl!(), "failed to export {} spans: {}", batch.len(), e);
+                conn = None;
+            }
+            batch.clear();
+        }
+
+        if done {
+            break;
+        }
+    }
+}
+
+fn export_batch(conn: &mut Option<File>, port: u32, batch: &[SpanData]) -> Result<()> {
+    if conn.is_none() {
+        *conn = Some(connect(port)?);
+    }
+
+    let payload = serde_json::to_vec(&encode_spans(batch))?;
+    let stream = conn.as_mut().unwrap();
+
+    stream.write_all(&http_request(&payload))?;
+    read_response(stream)
+}
+
+// http_request is the OTLP/HTTP request exporting a JSON payload.
+fn http_request(payload: &[u8]) -> Vec<u8> {
+    let mut req = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n",
+        OTLP_TRACES_PATH,
+        OTLP_HOST,
+        payload.len()
+    )
+    .into_bytes();
+    req.extend_from_slice(payload);
+    req
+}
+
+// read_response reads the reply of the collector, up to the end of its
+// body so that the connection can be used for the next batch, and fails
+// unless the spans were accepted.
+fn read_response<R: Read>(r: &mut R) -> Result<()> {
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        if r.read(&mut byte)? == 0 {
+            return Err(
+                ErrorKind::ErrorCode("trace collector closed the connection".to_string()).into(),
+            );
+        }
+        head.push(byte[0]);
+    }
+
+    let head = String::from_utf8_lossy(&head);
+    let mut lines = head.split("\r\n");
+    let status = lines.next().unwrap_or_default();
+    let mut len = 0;
+    for l in lines {
+        let mut kv = l.splitn(2, ':');
+        let key = kv.next().unwrap_or_default().trim();
+        if key.eq_ignore_ascii_case("content-length") {
+            len = kv.next().unwrap_or_default().trim().parse::<usize>()?;
+        }
+    }
+
+    let mut body = vec![0u8; len];
+    r.read_exact(&mut body)?;
+
+    match status.split_whitespace().nth(1) {
+        Some(code) if code.starts_with('2') => Ok(()),
+        _ => Err(ErrorKind::ErrorCode(format!(
+            "trace collector replied {}: {}",
+            status,
+            String::from_utf8_lossy(&body)
+        ))
+        .into()),
+    }
+}
+
+fn connect(port: u32) -> Result<File> {
+    let fd = socket::socket(
+        AddressFamily::Vsock,
+        SockType::Stream,
+        SockFlag::SOCK_CLOEXEC,
+        None,
+    )?;
+
+    let addr = SockAddr::new_vsock(libc::VMADDR_CID_HOST, port);
+    if let Err(e) = socket::connect(fd, &addr) {
+        let _ = unistd::close(fd);
+        return Err(e).chain_err(|| format!("cannot connect to trace collector on port {}", port));
+    }
+
+    Ok(unsafe { File::from_raw_fd(fd) })
+}
+
+// encode_spans builds an OTLP/JSON ExportTraceServiceRequest.
+fn encode_spans(batch: &[SpanData]) -> Value {
+    let spans: Vec<Value> = batch.iter().map(encode_span).collect();
+
+    json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [encode_attribute("service.name", SERVICE_NAME)],
+            },
+            "scopeSpans": [{
+                "scope": {
+                    "name": SERVICE_NAME,
+                    "version": AGENT_VERSION,
+                },
+                "spans": spans,
+            }],
+        }],
+    })
+}
+
+fn encode_span(span: &SpanData) -> Value {
+    let status = match span.error.as_ref() {
+        Some(msg) => json!({ "code": STATUS_CODE_ERROR, "message": msg }),
+        None => json!({ "code": STATUS_CODE_UNSET }),
+    };
+
+    let mut v = json!({
+        "traceId": to_hex(&span.trace_id),
+        "spanId": to_hex(&span.span_id),
+        "name": span.name,
+        "kind": SPAN_KIND_INTERNAL,
+        // 64 bit integers are strings in the JSON encoding of protobuf
+        "startTimeUnixNano": span.start.to_string(),
+        "endTimeUnixNano": span.end.to_string(),
+        "attributes": span
+            .attributes
+            .iter()
+            .map(|(k, v)| encode_attribute(k, v))
+            .collect::<Vec<Value>>(),
+        "status": status,
+    });
+
+    if let Some(parent) = span.parent_span_id.as_ref() {
+        v["parentSpanId"] = Value::String(to_hex(parent));
+    }
+
+    v
+}
+
+fn encode_attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn now_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+// new_span_id returns a random, non zero, id: splitmix64 over a counter
+// seeded once from the kernel random pool.
+fn new_span_id() -> [u8; 8] {
+    let mut z = ID_SEED.wrapping_add(
+        ID_COUNTER
+            .fetch_add(1, Ordering::Relaxed)
+            .wrapping_mul(0x9e3779b97f4a7c15),
+    );
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^= z >> 31;
+
+    if z == 0 {
+        return new_span_id();
+    }
+
+    z.to_be_bytes()
+}
+
+fn get_id_seed() -> u64 {
+    let mut buf = [0u8; 8];
+
+    match File::open("/dev/urandom").and_then(|mut f| f.read_exact(&mut buf)) {
+        Ok(_) => u64::from_ne_bytes(buf),
+        Err(_) => now_nanos(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_span_id() {
+        let a = new_span_id();
+        let b = new_span_id();
+
+        assert_ne!(a, [0u8; 8]);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_span_disabled() {
+        // no tracer has been started
+        let mut span = span("test");
+        span.set_attribute("key", "value");
+        assert!(span.data.is_none());
+    }
+
+    #[test]
+    fn test_span_detach() {
+        let data = SpanData {
+            trace_id: [1u8; 16],
+            span_id: [2u8; 8],
+            parent_span_id: None,
+            name: "stream_stdout".to_string(),
+            start: 1000,
+            end: 0,
+            attributes: Vec::new(),
+            error: None,
+        };
+        CURRENT_SPANS.with(|spans| spans.borrow_mut().push(data.span_id));
+        let mut span = Span { data: Some(data) };
+
+        // the span stays open, but isn't the parent of the next ones
+        span.detach();
+        assert!(CURRENT_SPANS.with(|spans| spans.borrow().is_empty()));
+        assert!(span.data.is_some());
+
+        // a successful call leaves the status unset
+        let v = encode_span(span.data.as_ref().unwrap());
+        assert_eq!(v["status"]["code"], STATUS_CODE_UNSET);
+    }
+
+    #[test]
+    fn test_http_request() {
+        let req = http_request(b"{}");
+        assert_eq!(
+            String::from_utf8(req).unwrap(),
+            "POST /v1/traces HTTP/1.1\r\nHost: localhost\r\n\
+             Content-Type: application/json\r\nContent-Length: 2\r\n\r\n{}"
+        );
+    }
+
+    #[test]
+    fn test_read_response() {
+        // the body is read up to the next response
+        let mut r: &[u8] =
+            b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}HTTP/1.1 400 Bad Request\r\n\
+              content-length: 3\r\n\r\nbad";
+        read_response(&mut r).unwrap();
+        let e = read_response(&mut r).unwrap_err();
+        assert!(format!("{}", e).contains("400 Bad Request: bad"));
+        assert!(r.is_empty());
+
+        let mut r: &[u8] = b"HTTP/1.1 200 OK\r\n";
+        assert!(read_response(&mut r).is_err());
+    }
+
+    #[test]
+    fn test_encode_spans() {
+        let span = SpanData {
+            trace_id: [1u8; 16],
+            span_id: [2u8; 8],
+            parent_span_id: Some([3u8; 8]),
+            name: "create_container".to_string(),
+            start: 1000,
+            end: 2000,
+            attributes: vec![("container_id".to_string(), "foo".to_string())],
+            error: Some("failed".to_string()),
+        };
+
+        let v = encode_spans(&[span]);
+        let s = &v["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+
+        assert_eq!(s["traceId"], "01010101010101010101010101010101");
+        assert_eq!(s["spanId"], "0202020202020202");
+        assert_eq!(s["parentSpanId"], "0303030303030303");
+        assert_eq!(s["name"], "create_container");
+        assert_eq!(s["startTimeUnixNano"], "1000");
+        assert_eq!(s["endTimeUnixNano"], "2000");
+        assert_eq!(s["attributes"][0]["key"], "container_id");
+        assert_eq!(s["attributes"][0]["value"]["stringValue"], "foo");
+        assert_eq!(s["status"]["code"], STATUS_CODE_ERROR);
+        assert_eq!(
+            v["resourceSpans"][0]["resource"]["attributes"][0]["value"]["stringValue"],
+            SERVICE_NAME
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //
 
 use crate::device::online_device;
+use crate::grpc::save_sandbox;
 use crate::linux_abi::*;
 use crate::sandbox::Sandbox;
 use crate::GLOBAL_DEVICE_WATCHER;
@@ -62,6 +63,8 @@ impl Uevent {
         // Add the device node name to the pci device map.
         sb.pci_device_map
             .insert(self.devpath.clone(), self.devname.clone());
+        // a restarted agent still knows about the device
+        save_sandbox(&sb);
 
         // Notify watchers that are interested in the udev event.
         // Close the channel after watcher has been notified.
@@ -0,0 +1,330 @@
+// Copyright (c) 2020 Ant Financial
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+
+// The user of the processes started with a StringUser is given by names
+// or numbers, the names being resolved against the /etc/passwd and
+// /etc/group files of the container.
+
+use protocols::agent::StringUser;
+use rustjail::errors::*;
+use std::fs;
+use std::io::ErrorKind as IoErrorKind;
+
+const PASSWD_FILE: &str = "/etc/passwd";
+const GROUP_FILE: &str = "/etc/group";
+
+// home of the users that have no passwd entry
+const DEFAULT_HOME: &str = "/";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswdEntry {
+    pub name: String,
+    pub uid: u32,
+    pub gid: u32,
+    pub home: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupEntry {
+    pub name: String,
+    pub gid: u32,
+    pub members: Vec<String>,
+}
+
+// ExecUser is the resolved user of a process.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExecUser {
+    pub uid: u32,
+    pub gid: u32,
+    pub additional_gids: Vec<u32>,
+    pub home: String,
+}
+
+// parse_passwd parses a passwd file, skipping the malformed lines.
+pub fn parse_passwd(content: &str) -> Vec<PasswdEntry> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let fields: Vec<&str> = l.split(':').collect();
+            if fields.len() < 3 {
+                return None;
+            }
+
+            Some(PasswdEntry {
+                name: fields[0].to_string(),
+                uid: fields[2].parse::<u32>().ok()?,
+                gid: fields.get(3).and_then(|g| g.parse::<u32>().ok())?,
+                home: fields.get(5).map_or(String::new(), |h| h.to_string()),
+            })
+        })
+        .collect()
+}
+
+// parse_group parses a group file, skipping the malformed lines.
+pub fn parse_group(content: &str) -> Vec<GroupEntry> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .filter_map(|l| {
+            let fields: Vec<&str> = l.split(':').collect();
+            if fields.len() < 3 {
+                return None;
+            }
+
+            Some(GroupEntry {
+                name: fields[0].to_string(),
+                gid: fields[2].parse::<u32>().ok()?,
+                members: fields.get(3).map_or(Vec::new(), |m| {
+                    m.split(',')
+                        .filter(|m| !m.is_empty())
+                        .map(|m| m.to_string())
+                        .collect()
+                }),
+            })
+        })
+        .collect()
+}
+
+// read_file returns the content of a file of the rootfs, a missing file
+// being the same as an empty one.
+fn read_file(root: &str, path: &str) -> Result<String> {
+    let path = format!("{}{}", root.trim_end_matches('/'), path);
+
+    match fs::read_to_string(&path) {
+        Ok(c) => Ok(c),
+        Err(e) if e.kind() == IoErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(Error::from(e)).chain_err(|| format!("cannot read {}", path)),
+    }
+}
+
+// resolve_user resolves user against the passwd and group files of the
+// root directory, defaults being the values used for what is not set.
+pub fn resolve_user(root: &str, user: &StringUser, defaults: &ExecUser) -> Result<ExecUser> {
+    let passwd = parse_passwd(read_file(root, PASSWD_FILE)?.as_str());
+    let groups = parse_group(read_file(root, GROUP_FILE)?.as_str());
+
+    resolve(&passwd, &groups, user, defaults)
+}
+
+fn resolve(
+    passwd: &[PasswdEntry],
+    groups: &[GroupEntry],
+    user: &StringUser,
+    defaults: &ExecUser,
+) -> Result<ExecUser> {
+    let mut exec_user = defaults.clone();
+
+    // a numeric uid may have no passwd entry
+    let entry = match user.uid.parse::<u32>() {
+        Ok(uid) => {
+            exec_user.uid = uid;
+            passwd.iter().find(|p| p.uid == uid)
+        }
+        Err(_) if user.uid.is_empty() => passwd.iter().find(|p| p.uid == exec_user.uid),
+        Err(_) => match passwd.iter().find(|p| p.name == user.uid) {
+            Some(p) => {
+                exec_user.uid = p.uid;
+                Some(p)
+            }
+            None => {
+                return Err(ErrorKind::NotFound(format!(
+                    "user {} not found in {}",
+                    user.uid, PASSWD_FILE
+                ))
+                .into())
+            }
+        },
+    };
+
+    exec_user.gid = if !user.gid.is_empty() {
+        resolve_group(groups, user.gid.as_str())?
+    } else if let Some(p) = entry {
+        p.gid
+    } else {
+        exec_user.gid
+    };
+
+    exec_user.home = match entry {
+        Some(p) if !p.home.is_empty() => p.home.clone(),
+        _ => DEFAULT_HOME.to_string(),
+    };
+
+    // the supplementary groups of the user come first
+    let mut gids: Vec<u32> = Vec::new();
+    if let Some(p) = entry {
+        gids.extend(
+            groups
+                .iter()
+                .filter(|g| g.members.contains(&p.name))
+                .map(|g| g.gid),
+        );
+    }
+
+    for gid in user.additionalGids.iter() {
+        gids.push(resolve_group(groups, gid.as_str())?);
+    }
+
+    gids.extend(defaults.additional_gids.iter());
+
+    exec_user.additional_gids.clear();
+    for gid in gids {
+        if !exec_user.additional_gids.contains(&gid) {
+            exec_user.additional_gids.push(gid);
+        }
+    }
+
+    Ok(exec_user)
+}
+
+fn resolve_group(groups: &[GroupEntry], group: &str) -> Result<u32> {
+    if let Ok(gid) = group.parse::<u32>() {
+        return Ok(gid);
+    }
+
+    match groups.iter().find(|g| g.name == group) {
+        Some(g) => Ok(g.gid),
+        None => {
+            Err(ErrorKind::NotFound(format!("group {} not found in {}", group, GROUP_FILE)).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::RepeatedField;
+    use tempfile::tempdir;
+
+    const PASSWD: &str = "root:x:0:0:root:/root:/bin/sh\n\
+                          # comment\n\
+                          bad line\n\
+                          nginx:x:101:101:nginx:/var/lib/nginx:/sbin/nologin\n\
+                          nobody:x:65534:65534:nobody::/bin/false\n";
+
+    const GROUP: &str = "root:x:0:root\n\
+                         wheel:x:10:root,nginx\n\
+                         nginx:x:101:\n\
+                         www-data:x:33:nginx\n";
+
+    fn string_user(uid: &str, gid: &str, additional: &[&str]) -> StringUser {
+        let mut user = StringUser::new();
+        user.uid = uid.to_string();
+        user.gid = gid.to_string();
+        user.additionalGids =
+            RepeatedField::from_vec(additional.iter().map(|g| g.to_string()).collect());
+        user
+    }
+
+    #[test]
+    fn test_parse() {
+        let passwd = parse_passwd(PASSWD);
+        assert_eq!(passwd.len(), 3);
+        assert_eq!(
+            passwd[1],
+            PasswdEntry {
+                name: "nginx".to_string(),
+                uid: 101,
+                gid: 101,
+                home: "/var/lib/nginx".to_string(),
+            }
+        );
+
+        let groups = parse_group(GROUP);
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups[1].members, vec!["root", "nginx"]);
+        assert!(groups[2].members.is_empty());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let passwd = parse_passwd(PASSWD);
+        let groups = parse_group(GROUP);
+        let defaults = ExecUser {
+            uid: 0,
+            gid: 0,
+            additional_gids: vec![],
+            home: String::new(),
+        };
+
+        let tests = &[
+            (
+                string_user("nginx", "", &[]),
+                ExecUser {
+                    uid: 101,
+                    gid: 101,
+                    additional_gids: vec![10, 33],
+                    home: "/var/lib/nginx".to_string(),
+                },
+            ),
+            (
+                string_user("101", "root", &["www-data", "42"]),
+                ExecUser {
+                    uid: 101,
+                    gid: 0,
+                    additional_gids: vec![10, 33, 42],
+                    home: "/var/lib/nginx".to_string(),
+                },
+            ),
+            // no passwd entry
+            (
+                string_user("1000", "1000", &[]),
+                ExecUser {
+                    uid: 1000,
+                    gid: 1000,
+                    additional_gids: vec![],
+                    home: "/".to_string(),
+                },
+            ),
+            (
+                string_user("nobody", "", &[]),
+                ExecUser {
+                    uid: 65534,
+                    gid: 65534,
+                    additional_gids: vec![],
+                    home: "/".to_string(),
+                },
+            ),
+        ];
+
+        for (i, (user, expected)) in tests.iter().enumerate() {
+            let msg = format!("test[{}]: {:?}", i, user);
+            let result = resolve(&passwd, &groups, user, &defaults).unwrap();
+            assert_eq!(&result, expected, "{}", msg);
+        }
+
+        for user in &[
+            string_user("apache", "", &[]),
+            string_user("nginx", "apache", &[]),
+            string_user("nginx", "", &["apache"]),
+        ] {
+            let err = resolve(&passwd, &groups, user, &defaults).unwrap_err();
+            match err.kind() {
+                ErrorKind::NotFound(_) => {}
+                _ => panic!("unexpected error {:?}", err),
+            }
+        }
+    }
+
+    #[test]
+    fn test_resolve_user() {
+        let root = tempdir().unwrap();
+        let root_path = root.path().to_str().unwrap();
+        let defaults = ExecUser::default();
+
+        // numeric ids need no passwd file
+        let user = resolve_user(root_path, &string_user("1000", "", &[]), &defaults).unwrap();
+        assert_eq!(user.uid, 1000);
+        assert!(resolve_user(root_path, &string_user("nginx", "", &[]), &defaults).is_err());
+
+        fs::create_dir(root.path().join("etc")).unwrap();
+        fs::write(root.path().join("etc/passwd"), PASSWD).unwrap();
+        fs::write(root.path().join("etc/group"), GROUP).unwrap();
+
+        let user = resolve_user(root_path, &string_user("nginx", "", &[]), &defaults).unwrap();
+        assert_eq!(user.uid, 101);
+        assert_eq!(user.additional_gids, vec![10, 33]);
+    }
+}